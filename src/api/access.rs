@@ -0,0 +1,936 @@
+//! Cloudflare Access related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/cloudflare-one/policies/access/)
+use std::time::Duration;
+
+use crate::{CfReqAuth, CfReqMeta, Result, SecretString};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// A rule matching users in the include, exclude or require lists of a policy
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessRule {
+    /// Users with this email
+    Email {
+        /// Email of the user
+        email: String,
+    },
+    /// Users with an email of this domain
+    EmailDomain {
+        /// Domain of the email (e.g `example.com`)
+        domain: String,
+    },
+    /// Requests from this IP range
+    Ip {
+        /// IP range in CIDR notation
+        ip: String,
+    },
+    /// Members of an [AccessGroup]
+    Group {
+        /// ID of the group
+        id: String,
+    },
+    /// Requests authenticated with this service token
+    ServiceToken {
+        /// ID of the service token
+        token_id: String,
+    },
+    /// Requests authenticated with any service token of the account
+    AnyValidServiceToken {},
+    /// Everyone
+    Everyone {},
+    /// A rule without typed support, as returned by cloudflare
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+impl AccessRule {
+    /// Users with the email `email`
+    pub fn email(email: impl Into<String>) -> Self {
+        Self::Email {
+            email: email.into(),
+        }
+    }
+
+    /// Users with an email of `domain`
+    pub fn email_domain(domain: impl Into<String>) -> Self {
+        Self::EmailDomain {
+            domain: domain.into(),
+        }
+    }
+
+    /// Requests from the IP range `ip` (e.g `10.0.0.0/8`)
+    pub fn ip(ip: impl Into<String>) -> Self {
+        Self::Ip { ip: ip.into() }
+    }
+
+    /// Members of the group `group`
+    pub fn group(group: impl AsRef<str>) -> Self {
+        Self::Group {
+            id: group.as_ref().to_string(),
+        }
+    }
+
+    /// Requests authenticated with the service token `token_id`
+    pub fn service_token(token_id: impl Into<String>) -> Self {
+        Self::ServiceToken {
+            token_id: token_id.into(),
+        }
+    }
+
+    /// Requests authenticated with any service token of the account
+    pub fn any_valid_service_token() -> Self {
+        Self::AnyValidServiceToken {}
+    }
+
+    /// Everyone
+    pub fn everyone() -> Self {
+        Self::Everyone {}
+    }
+}
+
+/// Type of an Access application
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessAppType {
+    /// Application hosted behind cloudflare
+    SelfHosted,
+    /// SaaS application
+    Saas,
+    /// SSH browser rendering
+    Ssh,
+    /// VNC browser rendering
+    Vnc,
+    /// App Launcher
+    AppLauncher,
+    /// WARP device enrollment
+    Warp,
+    /// Browser isolation
+    Biso,
+    /// Bookmark in the App Launcher
+    Bookmark,
+    /// A type unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// An Access application
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccessApp {
+    /// Application ID
+    pub id: String,
+    /// Name of the application
+    pub name: String,
+    /// Domain protected by the application
+    pub domain: Option<String>,
+    /// Type of the application
+    #[serde(rename = "type")]
+    pub app_type: AccessAppType,
+    /// Audience tag, the `aud` claim of the application's JWTs
+    pub aud: Option<String>,
+    /// Lifetime of a session (e.g `24h`)
+    pub session_duration: Option<String>,
+    /// IDs of the identity providers users can log in with
+    #[serde(default)]
+    pub allowed_idps: Vec<String>,
+    /// Whether users are redirected to the only identity provider
+    #[serde(default)]
+    pub auto_redirect_to_identity: bool,
+    /// Whether the application is shown in the App Launcher
+    #[serde(default)]
+    pub app_launcher_visible: bool,
+    /// Creation time
+    pub created_at: String,
+    /// Last update time
+    pub updated_at: String,
+}
+
+/// Settings of an application, used by [CreateAccessApp] and [UpdateAccessApp]
+#[derive(Serialize, Clone, Debug)]
+pub struct AccessAppParams {
+    name: String,
+    #[serde(rename = "type")]
+    app_type: AccessAppType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_duration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_idps: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_redirect_to_identity: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    app_launcher_visible: Option<bool>,
+}
+
+impl AccessAppParams {
+    /// Application `name` of `app_type`
+    pub fn new(name: impl Into<String>, app_type: AccessAppType) -> Self {
+        Self {
+            name: name.into(),
+            app_type,
+            domain: None,
+            session_duration: None,
+            allowed_idps: None,
+            auto_redirect_to_identity: None,
+            app_launcher_visible: None,
+        }
+    }
+
+    /// Self hosted application `name` protecting `domain` (e.g `app.example.com/admin`)
+    pub fn self_hosted(name: impl Into<String>, domain: impl Into<String>) -> Self {
+        Self::new(name, AccessAppType::SelfHosted).domain(domain)
+    }
+
+    /// Domain protected by the application
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Lifetime of a session (e.g `24h`)
+    pub fn session_duration(mut self, session_duration: impl Into<String>) -> Self {
+        self.session_duration = Some(session_duration.into());
+        self
+    }
+
+    /// Add an identity provider users can log in with
+    pub fn allowed_idp(mut self, idp_id: impl Into<String>) -> Self {
+        self.allowed_idps
+            .get_or_insert_with(Vec::new)
+            .push(idp_id.into());
+        self
+    }
+
+    /// Whether users are redirected to the only identity provider
+    pub fn auto_redirect_to_identity(mut self, auto_redirect: bool) -> Self {
+        self.auto_redirect_to_identity = Some(auto_redirect);
+        self
+    }
+
+    /// Whether the application is shown in the App Launcher
+    pub fn app_launcher_visible(mut self, visible: bool) -> Self {
+        self.app_launcher_visible = Some(visible);
+        self
+    }
+}
+
+/// [Add an Access application](https://developers.cloudflare.com/api/operations/access-applications-add-an-application) API
+#[derive(Serialize)]
+pub struct CreateAccessApp {
+    #[serde(flatten)]
+    params: AccessAppParams,
+}
+
+impl CreateAccessApp {
+    /// Create an application
+    pub fn new(params: AccessAppParams) -> Self {
+        Self { params }
+    }
+}
+
+/// Success response from the [Add an Access application](https://developers.cloudflare.com/api/operations/access-applications-add-an-application#Responses) API
+pub type CreateAccessAppRes = AccessApp;
+
+impl CfReqMeta for CreateAccessApp {
+    const METHOD: Method = Method::POST;
+    type Response = CreateAccessAppRes;
+}
+
+impl CfReqAuth for CreateAccessApp {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/access/apps")
+    }
+}
+
+/// [List Access applications](https://developers.cloudflare.com/api/operations/access-applications-list-access-applications) API
+#[derive(Serialize, Default)]
+pub struct ListAccessApps {}
+
+/// Success response from the [List Access applications](https://developers.cloudflare.com/api/operations/access-applications-list-access-applications#Responses) API
+pub type ListAccessAppsRes = Vec<AccessApp>;
+
+impl CfReqMeta for ListAccessApps {
+    const METHOD: Method = Method::GET;
+    type Response = ListAccessAppsRes;
+}
+
+impl CfReqAuth for ListAccessApps {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/access/apps")
+    }
+}
+
+/// [Get an Access application](https://developers.cloudflare.com/api/operations/access-applications-get-an-access-application) API
+#[derive(Serialize)]
+pub struct GetAccessApp {
+    #[serde(skip)]
+    app_id: String,
+}
+
+impl GetAccessApp {
+    /// Get the application `app_id`
+    pub fn new(app_id: impl Into<String>) -> Self {
+        Self {
+            app_id: app_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get an Access application](https://developers.cloudflare.com/api/operations/access-applications-get-an-access-application#Responses) API
+pub type GetAccessAppRes = AccessApp;
+
+impl CfReqMeta for GetAccessApp {
+    const METHOD: Method = Method::GET;
+    type Response = GetAccessAppRes;
+}
+
+impl CfReqAuth for GetAccessApp {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/access/apps/{}", self.app_id)
+    }
+}
+
+/// [Update an Access application](https://developers.cloudflare.com/api/operations/access-applications-update-an-access-application) API
+/// All the settings of the application are replaced
+#[derive(Serialize)]
+pub struct UpdateAccessApp {
+    #[serde(skip)]
+    app_id: String,
+    #[serde(flatten)]
+    params: AccessAppParams,
+}
+
+impl UpdateAccessApp {
+    /// Replace the settings of the application `app_id`
+    pub fn new(app_id: impl Into<String>, params: AccessAppParams) -> Self {
+        Self {
+            app_id: app_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Update an Access application](https://developers.cloudflare.com/api/operations/access-applications-update-an-access-application#Responses) API
+pub type UpdateAccessAppRes = AccessApp;
+
+impl CfReqMeta for UpdateAccessApp {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateAccessAppRes;
+}
+
+impl CfReqAuth for UpdateAccessApp {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/access/apps/{}", self.app_id)
+    }
+}
+
+/// [Delete an Access application](https://developers.cloudflare.com/api/operations/access-applications-delete-an-access-application) API
+#[derive(Serialize)]
+pub struct DeleteAccessApp {
+    #[serde(skip)]
+    app_id: String,
+}
+
+impl DeleteAccessApp {
+    /// Delete the application `app_id` and its policies
+    pub fn new(app_id: impl Into<String>) -> Self {
+        Self {
+            app_id: app_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeleteAccessApp {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqAuth for DeleteAccessApp {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/access/apps/{}", self.app_id)
+    }
+}
+
+/// Decision of an Access policy
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyDecision {
+    /// Allow matching users after login
+    Allow,
+    /// Deny matching users
+    Deny,
+    /// Allow matching requests without login (e.g service tokens)
+    NonIdentity,
+    /// Bypass Access for matching requests
+    Bypass,
+}
+
+/// A policy of an Access application
+/// Users match the policy if they match any `include` rule,
+/// no `exclude` rule and every `require` rule
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccessPolicy {
+    /// Policy ID
+    pub id: String,
+    /// Name of the policy
+    pub name: String,
+    /// Decision applied to matching users
+    pub decision: PolicyDecision,
+    /// Rules including users
+    #[serde(default)]
+    pub include: Vec<AccessRule>,
+    /// Rules excluding users
+    #[serde(default)]
+    pub exclude: Vec<AccessRule>,
+    /// Rules users must all match
+    #[serde(default)]
+    pub require: Vec<AccessRule>,
+    /// Order in which policies are evaluated, lowest first
+    pub precedence: Option<u32>,
+    /// Lifetime of a session, overriding the application's
+    pub session_duration: Option<String>,
+    /// Creation time
+    pub created_at: String,
+    /// Last update time
+    pub updated_at: String,
+}
+
+/// Settings of a policy, used by [CreateAccessPolicy] and [UpdateAccessPolicy]
+#[derive(Serialize, Clone, Debug)]
+pub struct AccessPolicyParams {
+    name: String,
+    decision: PolicyDecision,
+    include: Vec<AccessRule>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    exclude: Vec<AccessRule>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    require: Vec<AccessRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    precedence: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_duration: Option<String>,
+}
+
+impl AccessPolicyParams {
+    /// Policy `name` applying `decision`, at least one include rule is needed
+    pub fn new(name: impl Into<String>, decision: PolicyDecision) -> Self {
+        Self {
+            name: name.into(),
+            decision,
+            include: vec![],
+            exclude: vec![],
+            require: vec![],
+            precedence: None,
+            session_duration: None,
+        }
+    }
+
+    /// Include users matching `rule`
+    pub fn include(mut self, rule: AccessRule) -> Self {
+        self.include.push(rule);
+        self
+    }
+
+    /// Exclude users matching `rule`
+    pub fn exclude(mut self, rule: AccessRule) -> Self {
+        self.exclude.push(rule);
+        self
+    }
+
+    /// Require users to match `rule`
+    pub fn require(mut self, rule: AccessRule) -> Self {
+        self.require.push(rule);
+        self
+    }
+
+    /// Order in which policies are evaluated, lowest first
+    pub fn precedence(mut self, precedence: u32) -> Self {
+        self.precedence = Some(precedence);
+        self
+    }
+
+    /// Lifetime of a session (e.g `24h`), overriding the application's
+    pub fn session_duration(mut self, session_duration: impl Into<String>) -> Self {
+        self.session_duration = Some(session_duration.into());
+        self
+    }
+}
+
+/// [Create an Access application policy](https://developers.cloudflare.com/api/operations/access-policies-create-an-access-policy) API
+#[derive(Serialize)]
+pub struct CreateAccessPolicy {
+    #[serde(skip)]
+    app_id: String,
+    #[serde(flatten)]
+    params: AccessPolicyParams,
+}
+
+impl CreateAccessPolicy {
+    /// Add a policy to the application `app_id`
+    pub fn new(app_id: impl Into<String>, params: AccessPolicyParams) -> Self {
+        Self {
+            app_id: app_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Create an Access application policy](https://developers.cloudflare.com/api/operations/access-policies-create-an-access-policy#Responses) API
+pub type CreateAccessPolicyRes = AccessPolicy;
+
+impl CfReqMeta for CreateAccessPolicy {
+    const METHOD: Method = Method::POST;
+    type Response = CreateAccessPolicyRes;
+}
+
+impl CfReqAuth for CreateAccessPolicy {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/access/apps/{}/policies", self.app_id)
+    }
+}
+
+/// [List Access application policies](https://developers.cloudflare.com/api/operations/access-policies-list-access-policies) API
+#[derive(Serialize)]
+pub struct ListAccessPolicies {
+    #[serde(skip)]
+    app_id: String,
+}
+
+impl ListAccessPolicies {
+    /// List the policies of the application `app_id`
+    pub fn new(app_id: impl Into<String>) -> Self {
+        Self {
+            app_id: app_id.into(),
+        }
+    }
+}
+
+/// Success response from the [List Access application policies](https://developers.cloudflare.com/api/operations/access-policies-list-access-policies#Responses) API
+pub type ListAccessPoliciesRes = Vec<AccessPolicy>;
+
+impl CfReqMeta for ListAccessPolicies {
+    const METHOD: Method = Method::GET;
+    type Response = ListAccessPoliciesRes;
+}
+
+impl CfReqAuth for ListAccessPolicies {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/access/apps/{}/policies", self.app_id)
+    }
+}
+
+/// [Get an Access application policy](https://developers.cloudflare.com/api/operations/access-policies-get-an-access-policy) API
+#[derive(Serialize)]
+pub struct GetAccessPolicy {
+    #[serde(skip)]
+    app_id: String,
+    #[serde(skip)]
+    policy_id: String,
+}
+
+impl GetAccessPolicy {
+    /// Get the policy `policy_id` of the application `app_id`
+    pub fn new(app_id: impl Into<String>, policy_id: impl Into<String>) -> Self {
+        Self {
+            app_id: app_id.into(),
+            policy_id: policy_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get an Access application policy](https://developers.cloudflare.com/api/operations/access-policies-get-an-access-policy#Responses) API
+pub type GetAccessPolicyRes = AccessPolicy;
+
+impl CfReqMeta for GetAccessPolicy {
+    const METHOD: Method = Method::GET;
+    type Response = GetAccessPolicyRes;
+}
+
+impl CfReqAuth for GetAccessPolicy {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/access/apps/{}/policies/{}",
+            self.app_id, self.policy_id
+        )
+    }
+}
+
+/// [Update an Access application policy](https://developers.cloudflare.com/api/operations/access-policies-update-an-access-policy) API
+/// All the settings of the policy are replaced
+#[derive(Serialize)]
+pub struct UpdateAccessPolicy {
+    #[serde(skip)]
+    app_id: String,
+    #[serde(skip)]
+    policy_id: String,
+    #[serde(flatten)]
+    params: AccessPolicyParams,
+}
+
+impl UpdateAccessPolicy {
+    /// Replace the settings of the policy `policy_id` of the application `app_id`
+    pub fn new(
+        app_id: impl Into<String>,
+        policy_id: impl Into<String>,
+        params: AccessPolicyParams,
+    ) -> Self {
+        Self {
+            app_id: app_id.into(),
+            policy_id: policy_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Update an Access application policy](https://developers.cloudflare.com/api/operations/access-policies-update-an-access-policy#Responses) API
+pub type UpdateAccessPolicyRes = AccessPolicy;
+
+impl CfReqMeta for UpdateAccessPolicy {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateAccessPolicyRes;
+}
+
+impl CfReqAuth for UpdateAccessPolicy {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/access/apps/{}/policies/{}",
+            self.app_id, self.policy_id
+        )
+    }
+}
+
+/// [Delete an Access application policy](https://developers.cloudflare.com/api/operations/access-policies-delete-an-access-policy) API
+#[derive(Serialize)]
+pub struct DeleteAccessPolicy {
+    #[serde(skip)]
+    app_id: String,
+    #[serde(skip)]
+    policy_id: String,
+}
+
+impl DeleteAccessPolicy {
+    /// Delete the policy `policy_id` of the application `app_id`
+    pub fn new(app_id: impl Into<String>, policy_id: impl Into<String>) -> Self {
+        Self {
+            app_id: app_id.into(),
+            policy_id: policy_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeleteAccessPolicy {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqAuth for DeleteAccessPolicy {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/access/apps/{}/policies/{}",
+            self.app_id, self.policy_id
+        )
+    }
+}
+
+/// A reusable group of users, referenced with [AccessRule::group]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccessGroup {
+    /// Group ID
+    pub id: String,
+    /// Name of the group
+    pub name: String,
+    /// Rules including users
+    #[serde(default)]
+    pub include: Vec<AccessRule>,
+    /// Rules excluding users
+    #[serde(default)]
+    pub exclude: Vec<AccessRule>,
+    /// Rules users must all match
+    #[serde(default)]
+    pub require: Vec<AccessRule>,
+    /// Creation time
+    pub created_at: String,
+    /// Last update time
+    pub updated_at: String,
+}
+
+/// Groups can be given wherever a group ID is expected
+impl AsRef<str> for AccessGroup {
+    fn as_ref(&self) -> &str {
+        &self.id
+    }
+}
+
+/// [List Access groups](https://developers.cloudflare.com/api/operations/access-groups-list-access-groups) API
+#[derive(Serialize, Default)]
+pub struct ListAccessGroups {}
+
+/// Success response from the [List Access groups](https://developers.cloudflare.com/api/operations/access-groups-list-access-groups#Responses) API
+pub type ListAccessGroupsRes = Vec<AccessGroup>;
+
+impl CfReqMeta for ListAccessGroups {
+    const METHOD: Method = Method::GET;
+    type Response = ListAccessGroupsRes;
+}
+
+impl CfReqAuth for ListAccessGroups {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/access/groups")
+    }
+}
+
+/// An Access service token, without its client secret
+/// Requests authenticate with the `CF-Access-Client-Id` and `CF-Access-Client-Secret` headers
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ServiceToken {
+    /// Service token ID, used by [AccessRule::service_token]
+    pub id: String,
+    /// Name of the service token
+    pub name: String,
+    /// Client ID of the service token
+    pub client_id: String,
+    /// Lifetime of the service token (e.g `8760h`)
+    pub duration: Option<String>,
+    /// Expiry time
+    pub expires_at: Option<String>,
+    /// Last use time
+    pub last_seen_at: Option<String>,
+    /// Creation time
+    pub created_at: String,
+    /// Last update time
+    pub updated_at: String,
+}
+
+/// A service token along with its client secret,
+/// the secret is only returned when creating or rotating the token
+#[derive(Deserialize, Clone, Debug)]
+pub struct ServiceTokenWithSecret {
+    /// The service token
+    #[serde(flatten)]
+    pub token: ServiceToken,
+    /// Client secret of the service token
+    pub client_secret: SecretString,
+}
+
+fn go_duration(duration: Duration) -> String {
+    format!("{}s", duration.as_secs())
+}
+
+/// [Create a service token](https://developers.cloudflare.com/api/operations/access-service-tokens-create-a-service-token) API
+#[derive(Serialize)]
+pub struct CreateServiceToken {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<String>,
+}
+
+impl CreateServiceToken {
+    /// Create the service token `name`, valid for a year by default
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            duration: None,
+        }
+    }
+
+    /// Lifetime of the service token, with second precision
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(go_duration(duration));
+        self
+    }
+}
+
+/// Success response from the [Create a service token](https://developers.cloudflare.com/api/operations/access-service-tokens-create-a-service-token#Responses) API
+pub type CreateServiceTokenRes = ServiceTokenWithSecret;
+
+impl CfReqMeta for CreateServiceToken {
+    const METHOD: Method = Method::POST;
+    type Response = CreateServiceTokenRes;
+}
+
+impl CfReqAuth for CreateServiceToken {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/access/service_tokens")
+    }
+}
+
+/// [List service tokens](https://developers.cloudflare.com/api/operations/access-service-tokens-list-service-tokens) API
+#[derive(Serialize, Default)]
+pub struct ListServiceTokens {}
+
+/// Success response from the [List service tokens](https://developers.cloudflare.com/api/operations/access-service-tokens-list-service-tokens#Responses) API
+pub type ListServiceTokensRes = Vec<ServiceToken>;
+
+impl CfReqMeta for ListServiceTokens {
+    const METHOD: Method = Method::GET;
+    type Response = ListServiceTokensRes;
+}
+
+impl CfReqAuth for ListServiceTokens {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/access/service_tokens")
+    }
+}
+
+/// [Update a service token](https://developers.cloudflare.com/api/operations/access-service-tokens-update-a-service-token) API
+/// Only the given settings are changed
+#[derive(Serialize)]
+pub struct UpdateServiceToken {
+    #[serde(skip)]
+    token_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<String>,
+}
+
+impl UpdateServiceToken {
+    /// Change the settings of the service token `token_id`
+    pub fn new(token_id: impl Into<String>) -> Self {
+        Self {
+            token_id: token_id.into(),
+            name: None,
+            duration: None,
+        }
+    }
+
+    /// Name of the service token
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Lifetime of the service token, with second precision
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(go_duration(duration));
+        self
+    }
+}
+
+/// Success response from the [Update a service token](https://developers.cloudflare.com/api/operations/access-service-tokens-update-a-service-token#Responses) API
+pub type UpdateServiceTokenRes = ServiceToken;
+
+impl CfReqMeta for UpdateServiceToken {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateServiceTokenRes;
+}
+
+impl CfReqAuth for UpdateServiceToken {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/access/service_tokens/{}",
+            self.token_id
+        )
+    }
+}
+
+/// [Delete a service token](https://developers.cloudflare.com/api/operations/access-service-tokens-delete-a-service-token) API
+#[derive(Serialize)]
+pub struct DeleteServiceToken {
+    #[serde(skip)]
+    token_id: String,
+}
+
+impl DeleteServiceToken {
+    /// Delete the service token `token_id`
+    pub fn new(token_id: impl Into<String>) -> Self {
+        Self {
+            token_id: token_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete a service token](https://developers.cloudflare.com/api/operations/access-service-tokens-delete-a-service-token#Responses) API
+pub type DeleteServiceTokenRes = ServiceToken;
+
+impl CfReqMeta for DeleteServiceToken {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteServiceTokenRes;
+}
+
+impl CfReqAuth for DeleteServiceToken {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/access/service_tokens/{}",
+            self.token_id
+        )
+    }
+}
+
+/// [Rotate a service token](https://developers.cloudflare.com/api/operations/access-service-tokens-rotate-a-service-token) API
+/// The previous client secret stops working immediately
+#[derive(Serialize)]
+pub struct RotateServiceToken {
+    #[serde(skip)]
+    token_id: String,
+}
+
+impl RotateServiceToken {
+    /// Generate a new client secret for the service token `token_id`
+    pub fn new(token_id: impl Into<String>) -> Self {
+        Self {
+            token_id: token_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Rotate a service token](https://developers.cloudflare.com/api/operations/access-service-tokens-rotate-a-service-token#Responses) API
+pub type RotateServiceTokenRes = ServiceTokenWithSecret;
+
+impl CfReqMeta for RotateServiceToken {
+    const METHOD: Method = Method::POST;
+    type Response = RotateServiceTokenRes;
+}
+
+impl CfReqAuth for RotateServiceToken {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/access/service_tokens/{}/rotate",
+            self.token_id
+        )
+    }
+}
@@ -0,0 +1,471 @@
+//! Cloudflare Accounts and User related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/fundamentals/setup/account/)
+use crate::{CfReqAuth, CfReqMeta, CfSuccessResWithInfo, PageInfo, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Type of an account
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountType {
+    /// Standard account
+    Standard,
+    /// Enterprise account
+    Enterprise,
+    /// A type unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Settings of an account
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct AccountSettings {
+    /// Whether members must use two-factor authentication
+    #[serde(default)]
+    pub enforce_twofactor: bool,
+    /// Whether new zones use the account's custom nameservers by default
+    #[serde(default)]
+    pub use_account_custom_ns_by_default: bool,
+}
+
+/// A Cloudflare account
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Account {
+    /// Account ID
+    pub id: String,
+    /// Name of the account
+    pub name: String,
+    /// Type of the account
+    #[serde(rename = "type")]
+    pub account_type: Option<AccountType>,
+    /// Settings of the account
+    #[serde(default)]
+    pub settings: AccountSettings,
+    /// Creation time
+    pub created_on: Option<String>,
+}
+
+/// [Account Details](https://developers.cloudflare.com/api/operations/accounts-account-details) API
+/// Gets the client's account
+#[derive(Serialize, Default)]
+pub struct GetAccount {}
+
+/// Success response from the [Account Details](https://developers.cloudflare.com/api/operations/accounts-account-details#Responses) API
+pub type GetAccountRes = Account;
+
+impl CfReqMeta for GetAccount {
+    const METHOD: Method = Method::GET;
+    type Response = GetAccountRes;
+}
+
+impl CfReqAuth for GetAccount {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}")
+    }
+}
+
+/// [List Accounts](https://developers.cloudflare.com/api/operations/accounts-list-accounts) API
+/// Lists the accounts the token can access, the client's account is not used
+#[derive(Serialize, Default)]
+pub struct ListAccounts {
+    name: Option<String>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListAccounts {
+    /// Only list accounts whose name contains `name`
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of accounts per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List Accounts](https://developers.cloudflare.com/api/operations/accounts-list-accounts#Responses) API
+#[derive(Deserialize)]
+pub struct ListAccountsRes {
+    /// Accounts in this page
+    pub accounts: Vec<Account>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListAccounts {
+    const METHOD: Method = Method::GET;
+    type Response = ListAccountsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (accounts, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListAccountsRes {
+            accounts,
+            page_info,
+        })
+    }
+}
+
+impl CfReqAuth for ListAccounts {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "accounts".to_string()
+    }
+}
+
+/// The user owning the API token
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct User {
+    /// User ID
+    pub id: String,
+    /// Email of the user
+    pub email: String,
+    /// First name
+    pub first_name: Option<String>,
+    /// Last name
+    pub last_name: Option<String>,
+    /// Username
+    pub username: Option<String>,
+    /// Country code
+    pub country: Option<String>,
+    /// Whether two-factor authentication is enabled
+    #[serde(default)]
+    pub two_factor_authentication_enabled: bool,
+    /// Whether the user is suspended
+    #[serde(default)]
+    pub suspended: bool,
+    /// Creation time
+    pub created_on: Option<String>,
+    /// Last update time
+    pub modified_on: Option<String>,
+}
+
+/// [User Details](https://developers.cloudflare.com/api/operations/user-user-details) API
+/// The client's account is not used
+/// Tokens scoped to an account (rather than a user) can't use this API
+#[derive(Serialize, Default)]
+pub struct GetUser {}
+
+/// Success response from the [User Details](https://developers.cloudflare.com/api/operations/user-user-details#Responses) API
+pub type GetUserRes = User;
+
+impl CfReqMeta for GetUser {
+    const METHOD: Method = Method::GET;
+    type Response = GetUserRes;
+}
+
+impl CfReqAuth for GetUser {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "user".to_string()
+    }
+}
+
+/// A role that can be given to an account member
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccountRole {
+    /// Role ID
+    pub id: String,
+    /// Name of the role (e.g `Administrator`)
+    #[serde(default)]
+    pub name: String,
+    /// Description of the role
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Roles can be given wherever a role ID is expected
+impl AsRef<str> for AccountRole {
+    fn as_ref(&self) -> &str {
+        &self.id
+    }
+}
+
+/// [List Roles](https://developers.cloudflare.com/api/operations/account-roles-list-roles) API
+#[derive(Serialize, Default)]
+pub struct ListAccountRoles {}
+
+/// Success response from the [List Roles](https://developers.cloudflare.com/api/operations/account-roles-list-roles#Responses) API
+pub type ListAccountRolesRes = Vec<AccountRole>;
+
+impl CfReqMeta for ListAccountRoles {
+    const METHOD: Method = Method::GET;
+    type Response = ListAccountRolesRes;
+}
+
+impl CfReqAuth for ListAccountRoles {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/roles")
+    }
+}
+
+/// Status of an account member
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MemberStatus {
+    /// Member accepted the invitation
+    Accepted,
+    /// Invitation was not accepted yet
+    Pending,
+}
+
+/// The user of an account member
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MemberUser {
+    /// User ID
+    pub id: String,
+    /// Email of the user
+    pub email: String,
+    /// First name
+    pub first_name: Option<String>,
+    /// Last name
+    pub last_name: Option<String>,
+    /// Whether two-factor authentication is enabled
+    #[serde(default)]
+    pub two_factor_authentication_enabled: bool,
+}
+
+/// A member of an account
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccountMember {
+    /// Member ID
+    pub id: String,
+    /// Status of the member
+    pub status: MemberStatus,
+    /// The member's user
+    pub user: MemberUser,
+    /// Roles of the member
+    #[serde(default)]
+    pub roles: Vec<AccountRole>,
+}
+
+/// [List Members](https://developers.cloudflare.com/api/operations/account-members-list-members) API
+#[derive(Serialize, Default)]
+pub struct ListAccountMembers {
+    status: Option<MemberStatus>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListAccountMembers {
+    /// Only list members with `status`
+    pub fn status(mut self, status: MemberStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of members per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List Members](https://developers.cloudflare.com/api/operations/account-members-list-members#Responses) API
+#[derive(Deserialize)]
+pub struct ListAccountMembersRes {
+    /// Members in this page
+    pub members: Vec<AccountMember>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListAccountMembers {
+    const METHOD: Method = Method::GET;
+    type Response = ListAccountMembersRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (members, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListAccountMembersRes { members, page_info })
+    }
+}
+
+impl CfReqAuth for ListAccountMembers {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/members")
+    }
+}
+
+/// [Add Member](https://developers.cloudflare.com/api/operations/account-members-add-member) API
+#[derive(Serialize)]
+pub struct AddAccountMember {
+    email: String,
+    roles: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<MemberStatus>,
+}
+
+impl AddAccountMember {
+    /// Invite `email` to the account, see [ListAccountRoles] for the roles
+    pub fn new<R: AsRef<str>>(
+        email: impl Into<String>,
+        roles: impl IntoIterator<Item = R>,
+    ) -> Self {
+        Self {
+            email: email.into(),
+            roles: roles.into_iter().map(|r| r.as_ref().to_string()).collect(),
+            status: None,
+        }
+    }
+
+    /// Add the member as [MemberStatus::Accepted] without sending an invitation,
+    /// only allowed for some enterprise accounts
+    pub fn accepted(mut self) -> Self {
+        self.status = Some(MemberStatus::Accepted);
+        self
+    }
+}
+
+/// Success response from the [Add Member](https://developers.cloudflare.com/api/operations/account-members-add-member#Responses) API
+pub type AddAccountMemberRes = AccountMember;
+
+impl CfReqMeta for AddAccountMember {
+    const METHOD: Method = Method::POST;
+    type Response = AddAccountMemberRes;
+}
+
+impl CfReqAuth for AddAccountMember {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/members")
+    }
+}
+
+/// [Member Details](https://developers.cloudflare.com/api/operations/account-members-member-details) API
+#[derive(Serialize)]
+pub struct GetAccountMember {
+    #[serde(skip)]
+    member_id: String,
+}
+
+impl GetAccountMember {
+    /// Get the member `member_id`
+    pub fn new(member_id: impl Into<String>) -> Self {
+        Self {
+            member_id: member_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Member Details](https://developers.cloudflare.com/api/operations/account-members-member-details#Responses) API
+pub type GetAccountMemberRes = AccountMember;
+
+impl CfReqMeta for GetAccountMember {
+    const METHOD: Method = Method::GET;
+    type Response = GetAccountMemberRes;
+}
+
+impl CfReqAuth for GetAccountMember {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/members/{}", self.member_id)
+    }
+}
+
+#[derive(Serialize)]
+struct RoleRef {
+    id: String,
+}
+
+/// [Update Member](https://developers.cloudflare.com/api/operations/account-members-update-member) API
+/// The roles of the member are replaced
+#[derive(Serialize)]
+pub struct UpdateAccountMember {
+    #[serde(skip)]
+    member_id: String,
+    roles: Vec<RoleRef>,
+}
+
+impl UpdateAccountMember {
+    /// Replace the roles of the member `member_id`
+    pub fn new<R: AsRef<str>>(
+        member_id: impl Into<String>,
+        roles: impl IntoIterator<Item = R>,
+    ) -> Self {
+        Self {
+            member_id: member_id.into(),
+            roles: roles
+                .into_iter()
+                .map(|r| RoleRef {
+                    id: r.as_ref().to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Success response from the [Update Member](https://developers.cloudflare.com/api/operations/account-members-update-member#Responses) API
+pub type UpdateAccountMemberRes = AccountMember;
+
+impl CfReqMeta for UpdateAccountMember {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateAccountMemberRes;
+}
+
+impl CfReqAuth for UpdateAccountMember {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/members/{}", self.member_id)
+    }
+}
+
+/// [Remove Member](https://developers.cloudflare.com/api/operations/account-members-remove-member) API
+#[derive(Serialize)]
+pub struct RemoveAccountMember {
+    #[serde(skip)]
+    member_id: String,
+}
+
+impl RemoveAccountMember {
+    /// Remove the member `member_id` from the account
+    pub fn new(member_id: impl Into<String>) -> Self {
+        Self {
+            member_id: member_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for RemoveAccountMember {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqAuth for RemoveAccountMember {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/members/{}", self.member_id)
+    }
+}
@@ -0,0 +1,110 @@
+//! Cloudflare Cache related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/cache/)
+use std::collections::HashMap;
+
+use crate::{CfReqMeta, CfReqZone};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// A file to purge from the cache
+#[derive(Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum PurgeFile {
+    /// URL of the file
+    Url(String),
+    /// URL of the file along with the request headers selecting the cached variant
+    WithHeaders {
+        /// URL of the file
+        url: String,
+        /// Request headers (e.g `Origin`, `CF-Device-Type`)
+        headers: HashMap<String, String>,
+    },
+}
+
+impl From<String> for PurgeFile {
+    fn from(value: String) -> Self {
+        Self::Url(value)
+    }
+}
+
+impl From<&str> for PurgeFile {
+    fn from(value: &str) -> Self {
+        Self::Url(value.to_string())
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum PurgeMode {
+    PurgeEverything(bool),
+    Files(Vec<PurgeFile>),
+    Tags(Vec<String>),
+    Hosts(Vec<String>),
+    Prefixes(Vec<String>),
+}
+
+/// [Purge Cached Content](https://developers.cloudflare.com/api/operations/zone-purge) API
+/// Send with [crate::CloudflareZone::send_zone]
+/// Purging by tag, host or prefix is only available to enterprise zones
+#[derive(Serialize)]
+pub struct PurgeCache {
+    #[serde(flatten)]
+    mode: PurgeMode,
+}
+
+impl PurgeCache {
+    /// Purge all cached content of the zone
+    pub fn everything() -> Self {
+        Self {
+            mode: PurgeMode::PurgeEverything(true),
+        }
+    }
+
+    /// Purge the given files
+    pub fn files<F: Into<PurgeFile>>(files: impl IntoIterator<Item = F>) -> Self {
+        Self {
+            mode: PurgeMode::Files(files.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// Purge files with any of the given `Cache-Tag`s
+    pub fn tags<T: Into<String>>(tags: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            mode: PurgeMode::Tags(tags.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// Purge files served from any of the given hostnames
+    pub fn hosts<H: Into<String>>(hosts: impl IntoIterator<Item = H>) -> Self {
+        Self {
+            mode: PurgeMode::Hosts(hosts.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// Purge files with URLs starting with any of the given prefixes (e.g `www.example.com/images`)
+    pub fn prefixes<P: Into<String>>(prefixes: impl IntoIterator<Item = P>) -> Self {
+        Self {
+            mode: PurgeMode::Prefixes(prefixes.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+/// Success response from the [Purge Cached Content](https://developers.cloudflare.com/api/operations/zone-purge#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct PurgeCacheRes {
+    /// Identifier of the purged zone
+    pub id: String,
+}
+
+impl CfReqMeta for PurgeCache {
+    const METHOD: Method = Method::POST;
+    type Response = PurgeCacheRes;
+}
+
+impl CfReqZone for PurgeCache {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/purge_cache")
+    }
+}
@@ -0,0 +1,692 @@
+//! Cloudflare D1 related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/d1/)
+use std::marker::PhantomData;
+
+use crate::{api::r2::LocationHint, CfReqAuth, CfReqMeta, CfSuccessResWithInfo, PageInfo, Result};
+#[cfg(feature = "d1-backup")]
+use crate::{types::Backoff, CloudflareAuth, Error, PollOptions};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Database helper
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct D1Database {
+    /// Database UUID
+    database_id: String,
+}
+
+impl D1Database {
+    /// Create a new database helper
+    pub fn new(database_id: String) -> Self {
+        Self { database_id }
+    }
+
+    /// Database UUID
+    pub fn id(&self) -> &str {
+        &self.database_id
+    }
+
+    /// Run SQL statements, returning rows as JSON objects
+    pub fn query(&self, sql: impl Into<String>) -> D1Query {
+        self.query_as(sql)
+    }
+
+    /// Run SQL statements, deserializing rows as `T`
+    pub fn query_as<T>(&self, sql: impl Into<String>) -> D1Query<T> {
+        D1Query {
+            database_id: self.database_id.clone(),
+            sql: sql.into(),
+            params: Vec::new(),
+            _row: PhantomData,
+        }
+    }
+
+    /// Export the database as SQL
+    pub fn export(&self) -> D1Export {
+        D1Export {
+            database_id: self.database_id.clone(),
+            output_format: "polling",
+            current_bookmark: None,
+            dump_options: None,
+        }
+    }
+
+    /// Start an import of a SQL file whose MD5 hash (hex encoded) is `etag`
+    pub fn import_init(&self, etag: impl Into<String>) -> D1Import {
+        self.import(D1ImportAction::Init { etag: etag.into() })
+    }
+
+    /// Ingest a SQL file uploaded to the URL returned by [Self::import_init]
+    pub fn import_ingest(&self, etag: impl Into<String>, filename: impl Into<String>) -> D1Import {
+        self.import(D1ImportAction::Ingest {
+            etag: etag.into(),
+            filename: filename.into(),
+        })
+    }
+
+    /// Poll the status of an ingest started by [Self::import_ingest]
+    pub fn import_poll(&self, current_bookmark: impl Into<String>) -> D1Import {
+        self.import(D1ImportAction::Poll {
+            current_bookmark: current_bookmark.into(),
+        })
+    }
+
+    fn import(&self, action: D1ImportAction) -> D1Import {
+        D1Import {
+            database_id: self.database_id.clone(),
+            action,
+        }
+    }
+
+    /// Run SQL statements, returning rows as arrays of values
+    pub fn raw_query(&self, sql: impl Into<String>) -> D1RawQuery {
+        D1RawQuery {
+            database_id: self.database_id.clone(),
+            sql: sql.into(),
+            params: Vec::new(),
+        }
+    }
+}
+
+/// A D1 database
+#[derive(Serialize, Deserialize)]
+pub struct Database {
+    /// UUID of the database
+    pub uuid: String,
+    /// Name of the database
+    pub name: String,
+    /// Storage backend version (e.g `production`)
+    pub version: Option<String>,
+    /// RFC3339 timestamp of when the database was created
+    pub created_at: Option<String>,
+    /// Size of the database in bytes
+    pub file_size: Option<u64>,
+    /// Number of tables in the database
+    pub num_tables: Option<u64>,
+}
+
+impl From<Database> for D1Database {
+    fn from(value: Database) -> Self {
+        Self::new(value.uuid)
+    }
+}
+
+/// [Create D1 Database](https://developers.cloudflare.com/api/operations/cloudflare-d1-create-database) API
+#[derive(Serialize)]
+pub struct CreateDatabase {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    primary_location_hint: Option<LocationHint>,
+}
+
+impl CreateDatabase {
+    /// Create a database with the given name
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            primary_location_hint: None,
+        }
+    }
+
+    /// Place the primary copy of the database close to the given region
+    pub fn primary_location_hint(mut self, location_hint: LocationHint) -> Self {
+        self.primary_location_hint = Some(location_hint);
+        self
+    }
+}
+
+/// Success response from the [Create D1 Database](https://developers.cloudflare.com/api/operations/cloudflare-d1-create-database#Responses) API
+pub type CreateDatabaseRes = Database;
+
+impl CfReqMeta for CreateDatabase {
+    const METHOD: Method = Method::POST;
+    type Response = CreateDatabaseRes;
+}
+
+impl CfReqAuth for CreateDatabase {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/d1/database")
+    }
+}
+
+/// [List D1 Databases](https://developers.cloudflare.com/api/operations/cloudflare-d1-list-databases) API
+#[derive(Serialize, Default)]
+pub struct ListDatabases {
+    name: Option<String>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListDatabases {
+    /// Only return databases with the given name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of databases per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List D1 Databases](https://developers.cloudflare.com/api/operations/cloudflare-d1-list-databases#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListDatabasesRes {
+    /// Databases in this page
+    pub databases: Vec<Database>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListDatabases {
+    const METHOD: Method = Method::GET;
+    type Response = ListDatabasesRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (databases, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListDatabasesRes {
+            databases,
+            page_info,
+        })
+    }
+}
+
+impl CfReqAuth for ListDatabases {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/d1/database")
+    }
+}
+
+/// [Get D1 Database](https://developers.cloudflare.com/api/operations/cloudflare-d1-get-database) API
+#[derive(Serialize)]
+pub struct GetDatabase {
+    #[serde(skip)]
+    database_id: String,
+}
+
+impl GetDatabase {
+    /// Retrieve the database with the given UUID
+    pub fn new(database_id: impl Into<String>) -> Self {
+        Self {
+            database_id: database_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get D1 Database](https://developers.cloudflare.com/api/operations/cloudflare-d1-get-database#Responses) API
+pub type GetDatabaseRes = Database;
+
+impl CfReqMeta for GetDatabase {
+    const METHOD: Method = Method::GET;
+    type Response = GetDatabaseRes;
+}
+
+impl CfReqAuth for GetDatabase {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/d1/database/{}", self.database_id)
+    }
+}
+
+/// [Delete D1 Database](https://developers.cloudflare.com/api/operations/cloudflare-d1-delete-database) API
+#[derive(Serialize)]
+pub struct DeleteDatabase {
+    #[serde(skip)]
+    database_id: String,
+}
+
+impl DeleteDatabase {
+    /// Delete the database with the given UUID
+    pub fn new(database_id: impl Into<String>) -> Self {
+        Self {
+            database_id: database_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete D1 Database](https://developers.cloudflare.com/api/operations/cloudflare-d1-delete-database#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteDatabaseRes;
+
+impl CfReqMeta for DeleteDatabase {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteDatabaseRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(DeleteDatabaseRes)
+    }
+}
+
+impl CfReqAuth for DeleteDatabase {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/d1/database/{}", self.database_id)
+    }
+}
+
+/// Statistics of a D1 query
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct D1QueryMeta {
+    /// Duration of the query in milliseconds
+    pub duration: f64,
+    /// Number of rows read
+    pub rows_read: u64,
+    /// Number of rows written
+    pub rows_written: u64,
+    /// Number of rows changed
+    pub changes: u64,
+    /// Rowid of the last inserted row
+    pub last_row_id: i64,
+    /// Whether the database was changed
+    pub changed_db: bool,
+    /// Size of the database in bytes after the query
+    pub size_after: u64,
+}
+
+/// Result of a single statement of a [D1Query]
+#[derive(Serialize, Deserialize)]
+pub struct D1QueryResult<T> {
+    /// Rows returned by the statement
+    #[serde(default = "Vec::new")]
+    pub results: Vec<T>,
+    /// Whether the statement succeeded
+    pub success: bool,
+    /// Statistics of the statement
+    #[serde(default)]
+    pub meta: D1QueryMeta,
+}
+
+/// [Query D1 Database](https://developers.cloudflare.com/api/operations/cloudflare-d1-query-database) API
+/// Created via [D1Database::query] or [D1Database::query_as]
+#[derive(Serialize)]
+pub struct D1Query<T = serde_json::Value> {
+    #[serde(skip)]
+    database_id: String,
+    sql: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    params: Vec<serde_json::Value>,
+    #[serde(skip)]
+    _row: PhantomData<T>,
+}
+
+impl<T> D1Query<T> {
+    /// Bind the next `?` parameter of the statement
+    pub fn bind(mut self, param: &impl Serialize) -> Result<Self> {
+        self.params.push(serde_json::to_value(param)?);
+        Ok(self)
+    }
+
+    /// Bind all parameters of the statement
+    pub fn params(mut self, params: Vec<serde_json::Value>) -> Self {
+        self.params = params;
+        self
+    }
+}
+
+/// Success response from the [Query D1 Database](https://developers.cloudflare.com/api/operations/cloudflare-d1-query-database#Responses) API
+/// One result per statement
+pub type D1QueryRes<T> = Vec<D1QueryResult<T>>;
+
+impl<T: DeserializeOwned + Send> CfReqMeta for D1Query<T> {
+    const METHOD: Method = Method::POST;
+    type Response = D1QueryRes<T>;
+}
+
+impl<T: DeserializeOwned + Send> CfReqAuth for D1Query<T> {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/d1/database/{}/query",
+            self.database_id
+        )
+    }
+}
+
+/// Columnar rows of a [D1RawQuery]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct D1RawRows {
+    /// Names of the columns
+    pub columns: Vec<String>,
+    /// Rows, with values in the same order as the columns
+    pub rows: Vec<Vec<serde_json::Value>>,
+}
+
+/// Result of a single statement of a [D1RawQuery]
+#[derive(Serialize, Deserialize)]
+pub struct D1RawQueryResult {
+    /// Rows returned by the statement
+    #[serde(default)]
+    pub results: D1RawRows,
+    /// Whether the statement succeeded
+    pub success: bool,
+    /// Statistics of the statement
+    #[serde(default)]
+    pub meta: D1QueryMeta,
+}
+
+/// [Raw D1 Database query](https://developers.cloudflare.com/api/operations/cloudflare-d1-raw-database-query) API
+/// Created via [D1Database::raw_query]
+#[derive(Serialize)]
+pub struct D1RawQuery {
+    #[serde(skip)]
+    database_id: String,
+    sql: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    params: Vec<serde_json::Value>,
+}
+
+impl D1RawQuery {
+    /// Bind the next `?` parameter of the statement
+    pub fn bind(mut self, param: &impl Serialize) -> Result<Self> {
+        self.params.push(serde_json::to_value(param)?);
+        Ok(self)
+    }
+
+    /// Bind all parameters of the statement
+    pub fn params(mut self, params: Vec<serde_json::Value>) -> Self {
+        self.params = params;
+        self
+    }
+}
+
+/// Success response from the [Raw D1 Database query](https://developers.cloudflare.com/api/operations/cloudflare-d1-raw-database-query#Responses) API
+/// One result per statement
+pub type D1RawQueryRes = Vec<D1RawQueryResult>;
+
+impl CfReqMeta for D1RawQuery {
+    const METHOD: Method = Method::POST;
+    type Response = D1RawQueryRes;
+}
+
+impl CfReqAuth for D1RawQuery {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/d1/database/{}/raw", self.database_id)
+    }
+}
+
+/// Status of a D1 export or import
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum D1TaskStatus {
+    /// Still running, poll again with the returned bookmark
+    Active,
+    /// Done
+    Complete,
+    /// Failed, see the `error` field
+    Error,
+    /// A status unknown to this library, polling continues
+    #[serde(other)]
+    Unknown,
+}
+
+/// What to include in a [D1Export]
+#[derive(Serialize, Clone, Debug, Default)]
+struct D1DumpOptions {
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    no_data: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    no_schema: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tables: Vec<String>,
+}
+
+/// [Export D1 Database as SQL](https://developers.cloudflare.com/api/operations/cloudflare-d1-export-database) API
+/// Created via [D1Database::export], must be re-sent with the returned bookmark until complete
+#[derive(Serialize)]
+pub struct D1Export {
+    #[serde(skip)]
+    database_id: String,
+    output_format: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current_bookmark: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dump_options: Option<D1DumpOptions>,
+}
+
+impl D1Export {
+    /// Continue polling an export started earlier
+    /// `bookmark` is the `at_bookmark` of the previous [D1ExportRes]
+    pub fn current_bookmark(mut self, bookmark: impl Into<String>) -> Self {
+        self.current_bookmark = Some(bookmark.into());
+        self
+    }
+
+    /// Only export the schema
+    pub fn no_data(mut self) -> Self {
+        self.dump_options
+            .get_or_insert_with(Default::default)
+            .no_data = true;
+        self
+    }
+
+    /// Only export the data
+    pub fn no_schema(mut self) -> Self {
+        self.dump_options
+            .get_or_insert_with(Default::default)
+            .no_schema = true;
+        self
+    }
+
+    /// Only export the given table, can be called multiple times
+    pub fn add_table(mut self, table: impl Into<String>) -> Self {
+        self.dump_options
+            .get_or_insert_with(Default::default)
+            .tables
+            .push(table.into());
+        self
+    }
+}
+
+/// Result of a completed [D1Export]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct D1ExportResult {
+    /// Name of the exported file
+    pub filename: String,
+    /// URL to download the exported SQL from
+    pub signed_url: String,
+}
+
+/// Success response from the [Export D1 Database as SQL](https://developers.cloudflare.com/api/operations/cloudflare-d1-export-database#Responses) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct D1ExportRes {
+    /// Bookmark to poll the export with
+    pub at_bookmark: Option<String>,
+    /// Status of the export
+    pub status: Option<D1TaskStatus>,
+    /// Error message, if the export failed
+    pub error: Option<String>,
+    /// Progress messages
+    #[serde(default)]
+    pub messages: Vec<String>,
+    /// Download info, once the export is complete
+    pub result: Option<D1ExportResult>,
+}
+
+impl CfReqMeta for D1Export {
+    const METHOD: Method = Method::POST;
+    type Response = D1ExportRes;
+}
+
+impl CfReqAuth for D1Export {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/d1/database/{}/export",
+            self.database_id
+        )
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "action", rename_all = "lowercase")]
+enum D1ImportAction {
+    Init { etag: String },
+    Ingest { etag: String, filename: String },
+    Poll { current_bookmark: String },
+}
+
+/// [Import SQL into D1 Database](https://developers.cloudflare.com/api/operations/cloudflare-d1-import-database) API
+/// Created via [D1Database::import_init], [D1Database::import_ingest] and [D1Database::import_poll]
+#[derive(Serialize)]
+pub struct D1Import {
+    #[serde(skip)]
+    database_id: String,
+    #[serde(flatten)]
+    action: D1ImportAction,
+}
+
+/// Result of a completed [D1Import]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct D1ImportResult {
+    /// Bookmark of the database after the import
+    pub final_bookmark: Option<String>,
+    /// Number of queries executed
+    pub num_queries: Option<u64>,
+    /// Statistics of the import
+    #[serde(default)]
+    pub meta: D1QueryMeta,
+}
+
+/// Success response from the [Import SQL into D1 Database](https://developers.cloudflare.com/api/operations/cloudflare-d1-import-database#Responses) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct D1ImportRes {
+    /// URL to upload the SQL file to, returned by the `init` action
+    pub upload_url: Option<String>,
+    /// Name of the uploaded file, returned by the `init` action
+    pub filename: Option<String>,
+    /// Bookmark to poll the import with
+    pub at_bookmark: Option<String>,
+    /// Status of the import
+    pub status: Option<D1TaskStatus>,
+    /// Error message, if the import failed
+    pub error: Option<String>,
+    /// Progress messages
+    #[serde(default)]
+    pub messages: Vec<String>,
+    /// Import statistics, once the import is complete
+    pub result: Option<D1ImportResult>,
+}
+
+impl CfReqMeta for D1Import {
+    const METHOD: Method = Method::POST;
+    type Response = D1ImportRes;
+}
+
+impl CfReqAuth for D1Import {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/d1/database/{}/import",
+            self.database_id
+        )
+    }
+}
+
+#[cfg(feature = "d1-backup")]
+fn task_error(operation: &'static str, error: Option<String>) -> Error {
+    Error::D1Task {
+        operation,
+        message: error.unwrap_or_else(|| "unknown error".into()),
+    }
+}
+
+#[cfg(feature = "d1-backup")]
+impl D1Database {
+    /// Export the database, polling until the export is complete
+    /// Returns the URL to download the exported SQL from
+    pub async fn export_to_url(
+        &self,
+        client: &CloudflareAuth,
+        options: PollOptions,
+    ) -> Result<String> {
+        let mut backoff = Backoff::new(options);
+        let mut bookmark = None;
+        loop {
+            let mut req = self.export();
+            if let Some(bookmark) = bookmark.take() {
+                req = req.current_bookmark(bookmark);
+            }
+            let res = client.send_auth(req).await?;
+            match (res.status, res.result) {
+                (Some(D1TaskStatus::Error), _) => return Err(task_error("export", res.error)),
+                (Some(D1TaskStatus::Complete), Some(result)) => return Ok(result.signed_url),
+                _ => {}
+            }
+            bookmark = res.at_bookmark;
+            backoff.wait().await?;
+        }
+    }
+
+    /// Import a SQL file into the database, polling until the import is complete
+    pub async fn import_from_sql(
+        &self,
+        client: &CloudflareAuth,
+        sql: impl Into<Bytes>,
+        options: PollOptions,
+    ) -> Result<D1ImportResult> {
+        use md5::{Digest, Md5};
+        use std::fmt::Write;
+
+        let sql = sql.into();
+        let etag = Md5::digest(&sql)
+            .iter()
+            .fold(String::new(), |mut etag, byte| {
+                _ = write!(etag, "{byte:02x}");
+                etag
+            });
+
+        let init = client.send_auth(self.import_init(&etag)).await?;
+        let (Some(upload_url), Some(filename)) = (init.upload_url, init.filename) else {
+            return Err(task_error("import", init.error));
+        };
+        // the upload URL is pre-signed and must not receive the API token
+        client
+            .inner
+            .client
+            .put(upload_url)
+            .body(sql)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let mut res = client
+            .send_auth(self.import_ingest(&etag, filename))
+            .await?;
+        let mut backoff = Backoff::new(options);
+        loop {
+            match (res.status, res.result) {
+                (Some(D1TaskStatus::Error), _) => return Err(task_error("import", res.error)),
+                (Some(D1TaskStatus::Complete), Some(result)) => return Ok(result),
+                _ => {}
+            }
+            let Some(bookmark) = res.at_bookmark else {
+                return Err(task_error("import", res.error));
+            };
+            backoff.wait().await?;
+            res = client.send_auth(self.import_poll(bookmark)).await?;
+        }
+    }
+}
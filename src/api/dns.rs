@@ -0,0 +1,681 @@
+//! Cloudflare DNS related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/dns/)
+//! All requests are zone scoped, send them with [crate::CloudflareZone::send_zone]
+use crate::{CfReqMeta, CfReqZone, CfSuccessResWithInfo, Error, PageInfo, Result};
+use bytes::Bytes;
+use reqwest::{
+    multipart::{Form, Part},
+    Method,
+};
+use serde::{Deserialize, Serialize};
+
+/// TTL value telling cloudflare to pick the TTL automatically
+pub const DNS_TTL_AUTO: u32 = 1;
+
+/// Maximum number of operations in a [BatchDnsRecords] request,
+/// zones on the free plan are limited to 200
+pub const DNS_BATCH_MAX_OPERATIONS: usize = 3500;
+
+/// Type of a DNS record
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum DnsRecordType {
+    /// IPv4 address
+    A,
+    /// IPv6 address
+    Aaaa,
+    /// Alias to another name
+    Cname,
+    /// Text record
+    Txt,
+    /// Mail exchange
+    Mx,
+    /// Service locator, see [SrvData]
+    Srv,
+    /// Certification authority authorization, see [CaaData]
+    Caa,
+    /// Nameserver
+    Ns,
+    /// A record type unknown to this library
+    Other(String),
+}
+
+impl DnsRecordType {
+    /// The record type as used by cloudflare
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::A => "A",
+            Self::Aaaa => "AAAA",
+            Self::Cname => "CNAME",
+            Self::Txt => "TXT",
+            Self::Mx => "MX",
+            Self::Srv => "SRV",
+            Self::Caa => "CAA",
+            Self::Ns => "NS",
+            Self::Other(ty) => ty,
+        }
+    }
+}
+
+impl From<String> for DnsRecordType {
+    fn from(ty: String) -> Self {
+        match ty.as_str() {
+            "A" => Self::A,
+            "AAAA" => Self::Aaaa,
+            "CNAME" => Self::Cname,
+            "TXT" => Self::Txt,
+            "MX" => Self::Mx,
+            "SRV" => Self::Srv,
+            "CAA" => Self::Caa,
+            "NS" => Self::Ns,
+            _ => Self::Other(ty),
+        }
+    }
+}
+
+impl From<DnsRecordType> for String {
+    fn from(ty: DnsRecordType) -> Self {
+        match ty {
+            DnsRecordType::Other(ty) => ty,
+            ty => ty.as_str().to_string(),
+        }
+    }
+}
+
+/// Data of a SRV record
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SrvData {
+    /// Priority of the target, lower is preferred
+    pub priority: u16,
+    /// Relative weight of targets with the same priority
+    pub weight: u16,
+    /// Port of the service
+    pub port: u16,
+    /// Domain name of the target
+    pub target: String,
+}
+
+/// Data of a CAA record
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CaaData {
+    /// Flags of the record, 0 or 128 (critical)
+    pub flags: u8,
+    /// Property tag (e.g `issue`, `issuewild`, `iodef`)
+    pub tag: String,
+    /// Value of the property (e.g `letsencrypt.org`)
+    pub value: String,
+}
+
+/// Structured data of a DNS record
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum DnsRecordData {
+    /// SRV record data
+    Srv(SrvData),
+    /// CAA record data
+    Caa(CaaData),
+    /// Data of other record types
+    Other(serde_json::Value),
+}
+
+/// A DNS record
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DnsRecord {
+    /// Identifier of the record
+    pub id: String,
+    /// Type of the record
+    #[serde(rename = "type")]
+    pub record_type: DnsRecordType,
+    /// Fully qualified name of the record
+    pub name: String,
+    /// Content of the record, derived from [Self::data] for structured records
+    pub content: Option<String>,
+    /// Structured data of the record
+    pub data: Option<DnsRecordData>,
+    /// TTL in seconds, [DNS_TTL_AUTO] for automatic
+    pub ttl: u32,
+    /// Whether traffic is proxied through cloudflare
+    #[serde(default)]
+    pub proxied: bool,
+    /// Whether the record can be proxied
+    #[serde(default)]
+    pub proxiable: bool,
+    /// Priority of MX records
+    pub priority: Option<u16>,
+    /// Comment of the record
+    pub comment: Option<String>,
+    /// Tags of the record (`name:value`)
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// RFC3339 timestamp of when the record was created
+    pub created_on: Option<String>,
+    /// RFC3339 timestamp of when the record was last modified
+    pub modified_on: Option<String>,
+}
+
+/// Fields of a DNS record to create or change
+/// Fields which are not set are not sent
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct DnsRecordParams {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    record_type: Option<DnsRecordType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<DnsRecordData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ttl: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proxied: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+}
+
+impl DnsRecordParams {
+    /// A record of type `record_type` for `name` with the given content
+    pub fn new(
+        record_type: DnsRecordType,
+        name: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        Self::default()
+            .record_type(record_type)
+            .name(name)
+            .content(content)
+    }
+
+    /// A SRV record for `name`
+    pub fn srv(name: impl Into<String>, data: SrvData) -> Self {
+        Self::default()
+            .record_type(DnsRecordType::Srv)
+            .name(name)
+            .data(DnsRecordData::Srv(data))
+    }
+
+    /// A CAA record for `name`
+    pub fn caa(name: impl Into<String>, data: CaaData) -> Self {
+        Self::default()
+            .record_type(DnsRecordType::Caa)
+            .name(name)
+            .data(DnsRecordData::Caa(data))
+    }
+
+    /// Type of the record
+    pub fn record_type(mut self, record_type: DnsRecordType) -> Self {
+        self.record_type = Some(record_type);
+        self
+    }
+
+    /// Name of the record (e.g `www.example.com`, `@` for the zone apex)
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Content of the record (e.g an IP address for `A` records)
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Structured data of the record, for records like SRV and CAA
+    pub fn data(mut self, data: DnsRecordData) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// TTL in seconds, use [DNS_TTL_AUTO] for automatic
+    pub fn ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Whether traffic is proxied through cloudflare
+    pub fn proxied(mut self, proxied: bool) -> Self {
+        self.proxied = Some(proxied);
+        self
+    }
+
+    /// Priority of MX records
+    pub fn priority(mut self, priority: u16) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Comment of the record
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Replace the tags of the record (`name:value`)
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+}
+
+/// [List DNS Records](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-list-dns-records) API
+#[derive(Serialize, Default)]
+pub struct ListDnsRecords {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    record_type: Option<DnsRecordType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proxied: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u32>,
+}
+
+impl ListDnsRecords {
+    /// Only list records of the given type
+    pub fn record_type(mut self, record_type: DnsRecordType) -> Self {
+        self.record_type = Some(record_type);
+        self
+    }
+
+    /// Only list records with the given fully qualified name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Only list records with the given content
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Only list proxied or unproxied records
+    pub fn proxied(mut self, proxied: bool) -> Self {
+        self.proxied = Some(proxied);
+        self
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of records per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List DNS Records](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-list-dns-records#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListDnsRecordsRes {
+    /// Records in this page
+    pub records: Vec<DnsRecord>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListDnsRecords {
+    const METHOD: Method = Method::GET;
+    type Response = ListDnsRecordsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (records, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListDnsRecordsRes { records, page_info })
+    }
+}
+
+impl CfReqZone for ListDnsRecords {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/dns_records")
+    }
+}
+
+/// [Create DNS Record](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-create-dns-record) API
+#[derive(Serialize)]
+pub struct CreateDnsRecord {
+    #[serde(flatten)]
+    params: DnsRecordParams,
+}
+
+impl CreateDnsRecord {
+    /// Create a record, the type and name of the record must be set
+    pub fn new(params: DnsRecordParams) -> Self {
+        Self { params }
+    }
+}
+
+/// Success response from the [Create DNS Record](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-create-dns-record#Responses) API
+pub type CreateDnsRecordRes = DnsRecord;
+
+impl CfReqMeta for CreateDnsRecord {
+    const METHOD: Method = Method::POST;
+    type Response = CreateDnsRecordRes;
+}
+
+impl CfReqZone for CreateDnsRecord {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/dns_records")
+    }
+}
+
+/// [Overwrite DNS Record](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-update-dns-record) API
+#[derive(Serialize)]
+pub struct UpdateDnsRecord {
+    #[serde(skip)]
+    record_id: String,
+    #[serde(flatten)]
+    params: DnsRecordParams,
+}
+
+impl UpdateDnsRecord {
+    /// Replace the record with the given id, fields which are not set are reset
+    pub fn new(record_id: impl Into<String>, params: DnsRecordParams) -> Self {
+        Self {
+            record_id: record_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Overwrite DNS Record](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-update-dns-record#Responses) API
+pub type UpdateDnsRecordRes = DnsRecord;
+
+impl CfReqMeta for UpdateDnsRecord {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateDnsRecordRes;
+}
+
+impl CfReqZone for UpdateDnsRecord {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/dns_records/{}", self.record_id)
+    }
+}
+
+/// [Update DNS Record](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-patch-dns-record) API
+#[derive(Serialize)]
+pub struct PatchDnsRecord {
+    #[serde(skip)]
+    record_id: String,
+    #[serde(flatten)]
+    params: DnsRecordParams,
+}
+
+impl PatchDnsRecord {
+    /// Change the fields set in `params` of the record with the given id
+    pub fn new(record_id: impl Into<String>, params: DnsRecordParams) -> Self {
+        Self {
+            record_id: record_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Update DNS Record](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-patch-dns-record#Responses) API
+pub type PatchDnsRecordRes = DnsRecord;
+
+impl CfReqMeta for PatchDnsRecord {
+    const METHOD: Method = Method::PATCH;
+    type Response = PatchDnsRecordRes;
+}
+
+impl CfReqZone for PatchDnsRecord {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/dns_records/{}", self.record_id)
+    }
+}
+
+/// [Delete DNS Record](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-delete-dns-record) API
+#[derive(Serialize)]
+pub struct DeleteDnsRecord {
+    #[serde(skip)]
+    record_id: String,
+}
+
+impl DeleteDnsRecord {
+    /// Delete the record with the given id
+    pub fn new(record_id: impl Into<String>) -> Self {
+        Self {
+            record_id: record_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete DNS Record](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-delete-dns-record#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteDnsRecordRes {
+    /// Identifier of the deleted record
+    pub id: String,
+}
+
+impl CfReqMeta for DeleteDnsRecord {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteDnsRecordRes;
+}
+
+impl CfReqZone for DeleteDnsRecord {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/dns_records/{}", self.record_id)
+    }
+}
+
+/// [Export DNS Records](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-export-dns-records) API
+#[derive(Serialize, Default)]
+pub struct ExportDnsRecords {}
+
+/// BIND zone file of the zone's records
+pub type ExportDnsRecordsRes = String;
+
+impl CfReqMeta for ExportDnsRecords {
+    const METHOD: Method = Method::GET;
+    type Response = ExportDnsRecordsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        Ok(std::str::from_utf8(&body)?.to_string())
+    }
+}
+
+impl CfReqZone for ExportDnsRecords {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/dns_records/export")
+    }
+}
+
+/// [Import DNS Records](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-import-dns-records) API
+/// Send with [crate::CloudflareZone::send_zone_multipart]
+pub struct ImportDnsRecords {
+    file: Bytes,
+    proxied: Option<bool>,
+}
+
+impl ImportDnsRecords {
+    /// Import the records of a BIND zone file
+    pub fn new(file: impl Into<Bytes>) -> Self {
+        Self {
+            file: file.into(),
+            proxied: None,
+        }
+    }
+
+    /// Whether imported records that can be proxied are proxied
+    pub fn proxied(mut self, proxied: bool) -> Self {
+        self.proxied = Some(proxied);
+        self
+    }
+}
+
+impl TryFrom<ImportDnsRecords> for Form {
+    type Error = Error;
+
+    fn try_from(value: ImportDnsRecords) -> Result<Self> {
+        let len = value.file.len() as u64;
+        let file = Part::stream_with_length(value.file, len)
+            .file_name("zone.txt")
+            .mime_str("text/plain")?;
+        let mut form = Form::new().part("file", file);
+        if let Some(proxied) = value.proxied {
+            form = form.text("proxied", proxied.to_string());
+        }
+        Ok(form)
+    }
+}
+
+/// Success response from the [Import DNS Records](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-import-dns-records#Responses) API
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ImportDnsRecordsRes {
+    /// Number of records added
+    pub recs_added: u32,
+    /// Number of records found in the zone file
+    pub total_records_parsed: u32,
+}
+
+impl ImportDnsRecordsRes {
+    /// Number of records of the zone file that were not added (e.g duplicates)
+    pub fn skipped(&self) -> u32 {
+        self.total_records_parsed.saturating_sub(self.recs_added)
+    }
+}
+
+impl CfReqMeta for ImportDnsRecords {
+    const METHOD: Method = Method::POST;
+    type Response = ImportDnsRecordsRes;
+}
+
+impl CfReqZone for ImportDnsRecords {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/dns_records/import")
+    }
+}
+
+#[derive(Serialize)]
+struct BatchDelete {
+    id: String,
+}
+
+#[derive(Serialize)]
+struct BatchUpdate {
+    id: String,
+    #[serde(flatten)]
+    params: DnsRecordParams,
+}
+
+/// [Batch DNS Records](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-batch-dns-records) API
+/// Operations are applied atomically in the order deletes, patches, puts, posts
+#[derive(Serialize, Default)]
+pub struct BatchDnsRecords {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    deletes: Vec<BatchDelete>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    patches: Vec<BatchUpdate>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    puts: Vec<BatchUpdate>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    posts: Vec<DnsRecordParams>,
+}
+
+impl BatchDnsRecords {
+    fn check_len(&self) -> Result<()> {
+        let len = self.deletes.len() + self.patches.len() + self.puts.len() + self.posts.len();
+        if len >= DNS_BATCH_MAX_OPERATIONS {
+            return Err(Error::InvalidRequest(format!(
+                "a DNS batch can contain at most {DNS_BATCH_MAX_OPERATIONS} operations"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Delete the record with the given id
+    /// Fails if the batch is full
+    pub fn delete(mut self, record_id: impl Into<String>) -> Result<Self> {
+        self.check_len()?;
+        self.deletes.push(BatchDelete {
+            id: record_id.into(),
+        });
+        Ok(self)
+    }
+
+    /// Change the fields set in `params` of the record with the given id
+    /// Fails if the batch is full
+    pub fn patch(mut self, record_id: impl Into<String>, params: DnsRecordParams) -> Result<Self> {
+        self.check_len()?;
+        self.patches.push(BatchUpdate {
+            id: record_id.into(),
+            params,
+        });
+        Ok(self)
+    }
+
+    /// Replace the record with the given id
+    /// Fails if the batch is full
+    pub fn put(mut self, record_id: impl Into<String>, params: DnsRecordParams) -> Result<Self> {
+        self.check_len()?;
+        self.puts.push(BatchUpdate {
+            id: record_id.into(),
+            params,
+        });
+        Ok(self)
+    }
+
+    /// Create a record
+    /// Fails if the batch is full
+    pub fn post(mut self, params: DnsRecordParams) -> Result<Self> {
+        self.check_len()?;
+        self.posts.push(params);
+        Ok(self)
+    }
+}
+
+/// Success response from the [Batch DNS Records](https://developers.cloudflare.com/api/operations/dns-records-for-a-zone-batch-dns-records#Responses) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BatchDnsRecordsRes {
+    /// Deleted records
+    #[serde(default)]
+    pub deletes: Vec<DnsRecord>,
+    /// Patched records
+    #[serde(default)]
+    pub patches: Vec<DnsRecord>,
+    /// Replaced records
+    #[serde(default)]
+    pub puts: Vec<DnsRecord>,
+    /// Created records
+    #[serde(default)]
+    pub posts: Vec<DnsRecord>,
+}
+
+impl CfReqMeta for BatchDnsRecords {
+    const METHOD: Method = Method::POST;
+    type Response = BatchDnsRecordsRes;
+}
+
+impl CfReqZone for BatchDnsRecords {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/dns_records/batch")
+    }
+}
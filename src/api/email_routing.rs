@@ -0,0 +1,654 @@
+//! Cloudflare Email Routing related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/email-routing/)
+//! Destination addresses belong to the account, rules belong to a zone
+//! and are sent with [crate::CloudflareZone::send_zone]
+use crate::{CfReqAuth, CfReqMeta, CfReqZone, CfSuccessResWithInfo, PageInfo, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// A destination address emails can be forwarded to
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DestinationAddress {
+    /// Identifier of the address
+    pub id: String,
+    /// Deprecated identifier of the address, same as [Self::id]
+    pub tag: Option<String>,
+    /// Email address
+    pub email: String,
+    /// RFC3339 timestamp of when the address was verified, `None` until the owner verifies it
+    pub verified: Option<String>,
+    /// RFC3339 timestamp of when the address was created
+    pub created: Option<String>,
+    /// RFC3339 timestamp of when the address was last modified
+    pub modified: Option<String>,
+}
+
+impl DestinationAddress {
+    /// Whether the owner of the address verified it
+    pub fn is_verified(&self) -> bool {
+        self.verified.is_some()
+    }
+}
+
+/// [Create a destination address](https://developers.cloudflare.com/api/operations/email-routing-destination-addresses-create-a-destination-address) API
+/// Cloudflare sends a verification email to the address
+#[derive(Serialize)]
+pub struct CreateDestinationAddress {
+    email: String,
+}
+
+impl CreateDestinationAddress {
+    /// Add the destination address `email`
+    pub fn new(email: impl Into<String>) -> Self {
+        Self {
+            email: email.into(),
+        }
+    }
+}
+
+/// Success response from the [Create a destination address](https://developers.cloudflare.com/api/operations/email-routing-destination-addresses-create-a-destination-address#Responses) API
+pub type CreateDestinationAddressRes = DestinationAddress;
+
+impl CfReqMeta for CreateDestinationAddress {
+    const METHOD: Method = Method::POST;
+    type Response = CreateDestinationAddressRes;
+}
+
+impl CfReqAuth for CreateDestinationAddress {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/email/routing/addresses")
+    }
+}
+
+/// [List destination addresses](https://developers.cloudflare.com/api/operations/email-routing-destination-addresses-list-destination-addresses) API
+#[derive(Serialize, Default)]
+pub struct ListDestinationAddresses {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u32>,
+}
+
+impl ListDestinationAddresses {
+    /// Only list verified or unverified addresses
+    pub fn verified(mut self, verified: bool) -> Self {
+        self.verified = Some(verified);
+        self
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of addresses per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List destination addresses](https://developers.cloudflare.com/api/operations/email-routing-destination-addresses-list-destination-addresses#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListDestinationAddressesRes {
+    /// Addresses in this page
+    pub addresses: Vec<DestinationAddress>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListDestinationAddresses {
+    const METHOD: Method = Method::GET;
+    type Response = ListDestinationAddressesRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (addresses, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListDestinationAddressesRes {
+            addresses,
+            page_info,
+        })
+    }
+}
+
+impl CfReqAuth for ListDestinationAddresses {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/email/routing/addresses")
+    }
+}
+
+/// [Get a destination address](https://developers.cloudflare.com/api/operations/email-routing-destination-addresses-get-a-destination-address) API
+#[derive(Serialize)]
+pub struct GetDestinationAddress {
+    #[serde(skip)]
+    address_id: String,
+}
+
+impl GetDestinationAddress {
+    /// Retrieve the destination address with the given id,
+    /// poll it to find out when the address is verified
+    pub fn new(address_id: impl Into<String>) -> Self {
+        Self {
+            address_id: address_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get a destination address](https://developers.cloudflare.com/api/operations/email-routing-destination-addresses-get-a-destination-address#Responses) API
+pub type GetDestinationAddressRes = DestinationAddress;
+
+impl CfReqMeta for GetDestinationAddress {
+    const METHOD: Method = Method::GET;
+    type Response = GetDestinationAddressRes;
+}
+
+impl CfReqAuth for GetDestinationAddress {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/email/routing/addresses/{}",
+            self.address_id
+        )
+    }
+}
+
+/// [Delete destination address](https://developers.cloudflare.com/api/operations/email-routing-destination-addresses-delete-destination-address) API
+#[derive(Serialize)]
+pub struct DeleteDestinationAddress {
+    #[serde(skip)]
+    address_id: String,
+}
+
+impl DeleteDestinationAddress {
+    /// Delete the destination address with the given id
+    pub fn new(address_id: impl Into<String>) -> Self {
+        Self {
+            address_id: address_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete destination address](https://developers.cloudflare.com/api/operations/email-routing-destination-addresses-delete-destination-address#Responses) API
+pub type DeleteDestinationAddressRes = DestinationAddress;
+
+impl CfReqMeta for DeleteDestinationAddress {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteDestinationAddressRes;
+}
+
+impl CfReqAuth for DeleteDestinationAddress {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/email/routing/addresses/{}",
+            self.address_id
+        )
+    }
+}
+
+/// Field of an email matched by a [EmailMatcher::Literal]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MatcherField {
+    /// Recipient of the email
+    To,
+}
+
+/// Condition an email must match for a rule to apply
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum EmailMatcher {
+    /// Match a field exactly
+    Literal {
+        /// Field to match
+        field: MatcherField,
+        /// Expected value of the field
+        value: String,
+    },
+    /// Match all emails, used by the catch-all rule
+    All,
+}
+
+impl EmailMatcher {
+    /// Match emails sent to `address`
+    pub fn to(address: impl Into<String>) -> Self {
+        Self::Literal {
+            field: MatcherField::To,
+            value: address.into(),
+        }
+    }
+}
+
+/// Action applied to emails matching a rule
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(
+    tag = "type",
+    content = "value",
+    rename_all = "lowercase",
+    try_from = "RawEmailAction"
+)]
+pub enum EmailAction {
+    /// Forward to verified destination addresses
+    Forward(Vec<String>),
+    /// Process with email workers
+    Worker(Vec<String>),
+    /// Drop the email
+    Drop,
+}
+
+// cloudflare sends a `value` for every action, including an empty one for `drop`
+#[derive(Deserialize)]
+struct RawEmailAction {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    value: Option<Vec<String>>,
+}
+
+impl TryFrom<RawEmailAction> for EmailAction {
+    type Error = String;
+
+    fn try_from(raw: RawEmailAction) -> std::result::Result<Self, Self::Error> {
+        let value = raw.value.unwrap_or_default();
+        match raw.kind.as_str() {
+            "forward" => Ok(Self::Forward(value)),
+            "worker" => Ok(Self::Worker(value)),
+            "drop" => Ok(Self::Drop),
+            kind => Err(format!("unknown email action type `{kind}`")),
+        }
+    }
+}
+
+/// An email routing rule
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EmailRoutingRule {
+    /// Identifier of the rule
+    pub id: String,
+    /// Deprecated identifier of the rule, same as [Self::id]
+    pub tag: Option<String>,
+    /// Name of the rule
+    #[serde(default)]
+    pub name: String,
+    /// Whether the rule is enabled
+    pub enabled: bool,
+    /// Priority of the rule, lower runs first
+    #[serde(default)]
+    pub priority: u32,
+    /// Conditions an email must match
+    pub matchers: Vec<EmailMatcher>,
+    /// Actions applied to matching emails
+    pub actions: Vec<EmailAction>,
+}
+
+/// Fields of an email routing rule
+#[derive(Serialize, Clone, Debug)]
+pub struct EmailRoutingRuleParams {
+    matchers: Vec<EmailMatcher>,
+    actions: Vec<EmailAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<u32>,
+}
+
+impl EmailRoutingRuleParams {
+    /// A rule applying `actions` to emails matching all `matchers`
+    pub fn new(matchers: Vec<EmailMatcher>, actions: Vec<EmailAction>) -> Self {
+        Self {
+            matchers,
+            actions,
+            name: None,
+            enabled: None,
+            priority: None,
+        }
+    }
+
+    /// Name of the rule
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether the rule is enabled
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Priority of the rule, lower runs first
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+}
+
+/// [Create routing rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-create-routing-rule) API
+#[derive(Serialize)]
+pub struct CreateEmailRoutingRule {
+    #[serde(flatten)]
+    params: EmailRoutingRuleParams,
+}
+
+impl CreateEmailRoutingRule {
+    /// Create a rule
+    pub fn new(params: EmailRoutingRuleParams) -> Self {
+        Self { params }
+    }
+}
+
+/// Success response from the [Create routing rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-create-routing-rule#Responses) API
+pub type CreateEmailRoutingRuleRes = EmailRoutingRule;
+
+impl CfReqMeta for CreateEmailRoutingRule {
+    const METHOD: Method = Method::POST;
+    type Response = CreateEmailRoutingRuleRes;
+}
+
+impl CfReqZone for CreateEmailRoutingRule {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/email/routing/rules")
+    }
+}
+
+/// [List routing rules](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-list-routing-rules) API
+#[derive(Serialize, Default)]
+pub struct ListEmailRoutingRules {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u32>,
+}
+
+impl ListEmailRoutingRules {
+    /// Only list enabled or disabled rules
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of rules per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List routing rules](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-list-routing-rules#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListEmailRoutingRulesRes {
+    /// Rules in this page
+    pub rules: Vec<EmailRoutingRule>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListEmailRoutingRules {
+    const METHOD: Method = Method::GET;
+    type Response = ListEmailRoutingRulesRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (rules, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListEmailRoutingRulesRes { rules, page_info })
+    }
+}
+
+impl CfReqZone for ListEmailRoutingRules {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/email/routing/rules")
+    }
+}
+
+/// [Get routing rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-get-routing-rule) API
+#[derive(Serialize)]
+pub struct GetEmailRoutingRule {
+    #[serde(skip)]
+    rule_id: String,
+}
+
+impl GetEmailRoutingRule {
+    /// Retrieve the rule with the given id
+    pub fn new(rule_id: impl Into<String>) -> Self {
+        Self {
+            rule_id: rule_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get routing rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-get-routing-rule#Responses) API
+pub type GetEmailRoutingRuleRes = EmailRoutingRule;
+
+impl CfReqMeta for GetEmailRoutingRule {
+    const METHOD: Method = Method::GET;
+    type Response = GetEmailRoutingRuleRes;
+}
+
+impl CfReqZone for GetEmailRoutingRule {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/email/routing/rules/{}", self.rule_id)
+    }
+}
+
+/// [Update routing rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-update-routing-rule) API
+#[derive(Serialize)]
+pub struct UpdateEmailRoutingRule {
+    #[serde(skip)]
+    rule_id: String,
+    #[serde(flatten)]
+    params: EmailRoutingRuleParams,
+}
+
+impl UpdateEmailRoutingRule {
+    /// Replace the rule with the given id
+    pub fn new(rule_id: impl Into<String>, params: EmailRoutingRuleParams) -> Self {
+        Self {
+            rule_id: rule_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Update routing rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-update-routing-rule#Responses) API
+pub type UpdateEmailRoutingRuleRes = EmailRoutingRule;
+
+impl CfReqMeta for UpdateEmailRoutingRule {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateEmailRoutingRuleRes;
+}
+
+impl CfReqZone for UpdateEmailRoutingRule {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/email/routing/rules/{}", self.rule_id)
+    }
+}
+
+/// [Delete routing rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-delete-routing-rule) API
+#[derive(Serialize)]
+pub struct DeleteEmailRoutingRule {
+    #[serde(skip)]
+    rule_id: String,
+}
+
+impl DeleteEmailRoutingRule {
+    /// Delete the rule with the given id
+    pub fn new(rule_id: impl Into<String>) -> Self {
+        Self {
+            rule_id: rule_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete routing rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-delete-routing-rule#Responses) API
+pub type DeleteEmailRoutingRuleRes = EmailRoutingRule;
+
+impl CfReqMeta for DeleteEmailRoutingRule {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteEmailRoutingRuleRes;
+}
+
+impl CfReqZone for DeleteEmailRoutingRule {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/email/routing/rules/{}", self.rule_id)
+    }
+}
+
+/// [Get catch-all rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-get-catch-all-rule) API
+#[derive(Serialize, Default)]
+pub struct GetCatchAllRule {}
+
+/// Success response from the [Get catch-all rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-get-catch-all-rule#Responses) API
+pub type GetCatchAllRuleRes = EmailRoutingRule;
+
+impl CfReqMeta for GetCatchAllRule {
+    const METHOD: Method = Method::GET;
+    type Response = GetCatchAllRuleRes;
+}
+
+impl CfReqZone for GetCatchAllRule {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/email/routing/rules/catch_all")
+    }
+}
+
+/// [Update catch-all rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-update-catch-all-rule) API
+#[derive(Serialize)]
+pub struct UpdateCatchAllRule {
+    matchers: [EmailMatcher; 1],
+    actions: Vec<EmailAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+}
+
+impl UpdateCatchAllRule {
+    /// Apply `actions` to emails not matched by any other rule
+    pub fn new(actions: Vec<EmailAction>) -> Self {
+        Self {
+            matchers: [EmailMatcher::All],
+            actions,
+            name: None,
+            enabled: None,
+        }
+    }
+
+    /// Name of the rule
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether the rule is enabled
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+}
+
+/// Success response from the [Update catch-all rule](https://developers.cloudflare.com/api/operations/email-routing-routing-rules-update-catch-all-rule#Responses) API
+pub type UpdateCatchAllRuleRes = EmailRoutingRule;
+
+impl CfReqMeta for UpdateCatchAllRule {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateCatchAllRuleRes;
+}
+
+impl CfReqZone for UpdateCatchAllRule {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/email/routing/rules/catch_all")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_destination_address() {
+        let body = br#"{
+            "result": {
+                "id": "ea95132c15732412d22c1476fa83f27a",
+                "created": "2014-01-02T02:20:00Z",
+                "email": "user@example.com",
+                "modified": "2014-01-02T02:20:00Z",
+                "tag": "ea95132c15732412d22c1476fa83f27a",
+                "verified": "2014-01-02T02:20:00Z"
+            },
+            "success": true,
+            "errors": [],
+            "messages": []
+        }"#;
+        let address =
+            CreateDestinationAddress::deserialize_response(Bytes::from_static(body)).unwrap();
+        assert_eq!(address.id, "ea95132c15732412d22c1476fa83f27a");
+        assert_eq!(address.tag.as_deref(), Some(address.id.as_str()));
+        assert!(address.is_verified());
+    }
+
+    #[test]
+    fn deserialize_routing_rule() {
+        let rule: EmailRoutingRule = serde_json::from_str(
+            r#"{
+                "id": "a7e6fb77503c41d8a7f3113c6918f10c",
+                "actions": [{"type": "forward", "value": ["destinationaddress@example.net"]}],
+                "enabled": true,
+                "matchers": [{"field": "to", "type": "literal", "value": "test@example.com"}],
+                "name": "Send to user@example.net rule.",
+                "priority": 0,
+                "tag": "a7e6fb77503c41d8a7f3113c6918f10c"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(rule.id, "a7e6fb77503c41d8a7f3113c6918f10c");
+        assert_eq!(rule.matchers, [EmailMatcher::to("test@example.com")]);
+        assert_eq!(
+            rule.actions,
+            [EmailAction::Forward(vec![
+                "destinationaddress@example.net".into()
+            ])]
+        );
+    }
+
+    #[test]
+    fn drop_action_ignores_value() {
+        let actions: Vec<EmailAction> =
+            serde_json::from_str(r#"[{"type":"drop","value":[]},{"type":"drop"}]"#).unwrap();
+        assert_eq!(actions, [EmailAction::Drop, EmailAction::Drop]);
+        assert_eq!(
+            serde_json::to_string(&EmailAction::Drop).unwrap(),
+            r#"{"type":"drop"}"#
+        );
+    }
+}
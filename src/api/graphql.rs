@@ -0,0 +1,222 @@
+//! Cloudflare GraphQL Analytics API
+//! See [Cloudflare Docs](https://developers.cloudflare.com/analytics/graphql-api/)
+use std::marker::PhantomData;
+
+use crate::{request::cf_errors, CfReqAuth, CfReqMeta, Error, GraphqlError, Result};
+use bytes::Bytes;
+use reqwest::{Method, StatusCode};
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Deserialize, Serialize,
+};
+
+/// [GraphQL Analytics](https://developers.cloudflare.com/analytics/graphql-api/) API
+/// The client's account is not used, `T` is the type of the `data` field of the response
+#[derive(Serialize)]
+pub struct GraphqlQuery<T = serde_json::Value> {
+    query: String,
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    variables: serde_json::Map<String, serde_json::Value>,
+    #[serde(skip)]
+    _data: PhantomData<fn() -> T>,
+}
+
+impl<T> GraphqlQuery<T> {
+    /// Run the given GraphQL query
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            variables: serde_json::Map::new(),
+            _data: PhantomData,
+        }
+    }
+
+    /// Set the variable `$name` of the query
+    pub fn variable(mut self, name: impl Into<String>, value: &impl Serialize) -> Result<Self> {
+        self.variables
+            .insert(name.into(), serde_json::to_value(value)?);
+        Ok(self)
+    }
+
+    /// Replace all variables of the query
+    pub fn variables(mut self, variables: serde_json::Map<String, serde_json::Value>) -> Self {
+        self.variables = variables;
+        self
+    }
+}
+
+// graphql responses are not wrapped in a CfSuccessRes
+#[derive(Deserialize)]
+struct GraphqlRes<T> {
+    data: Option<T>,
+    errors: Option<Vec<GraphqlError>>,
+}
+
+impl<T: DeserializeOwned> CfReqMeta for GraphqlQuery<T> {
+    const METHOD: Method = Method::POST;
+    type Response = T;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let res: GraphqlRes<T> = serde_json::from_slice(&body)?;
+        match (res.data, res.errors.unwrap_or_default()) {
+            (Some(data), errors) if errors.is_empty() => Ok(data),
+            (_, errors) => Err(Error::Graphql(errors)),
+        }
+    }
+
+    fn handle_error_body(status: StatusCode, body: Bytes) -> Result<Self::Response> {
+        // cloudflare errors (e.g authentication) have a code, graphql errors (e.g a 400 for a
+        // query that does not parse) only have a message
+        let errors = cf_errors(&body);
+        if errors.is_empty() {
+            if let Ok(GraphqlRes {
+                errors: Some(errors),
+                ..
+            }) = serde_json::from_slice::<GraphqlRes<IgnoredAny>>(&body)
+            {
+                return Err(Error::Graphql(errors));
+            }
+        }
+        Self::handle_error(status, errors)
+    }
+}
+
+impl<T: DeserializeOwned> CfReqAuth for GraphqlQuery<T> {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "graphql".to_string()
+    }
+}
+
+const ZONE_REQUESTS_BY_DAY: &str = "query ZoneRequestsByDay($zoneTag: string!, $since: Date!, $until: Date!) {
+  viewer {
+    zones(filter: { zoneTag: $zoneTag }) {
+      httpRequests1dGroups(limit: 1000, filter: { date_geq: $since, date_leq: $until }, orderBy: [date_ASC]) {
+        dimensions { date }
+        sum { requests bytes cachedRequests cachedBytes threats pageViews }
+      }
+    }
+  }
+}";
+
+/// HTTP traffic of a zone during a day
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DailyRequests {
+    /// Day, formatted as `YYYY-MM-DD`
+    pub date: String,
+    /// Number of requests
+    pub requests: u64,
+    /// Number of bytes served
+    pub bytes: u64,
+    /// Number of requests served from the cache
+    pub cached_requests: u64,
+    /// Number of bytes served from the cache
+    pub cached_bytes: u64,
+    /// Number of requests classified as threats
+    pub threats: u64,
+    /// Number of HTML page views
+    pub page_views: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestsSum {
+    requests: u64,
+    bytes: u64,
+    cached_requests: u64,
+    cached_bytes: u64,
+    threats: u64,
+    page_views: u64,
+}
+
+#[derive(Deserialize)]
+struct DateDimensions {
+    date: String,
+}
+
+#[derive(Deserialize)]
+struct RequestsGroup {
+    dimensions: DateDimensions,
+    sum: RequestsSum,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ZoneGroups {
+    http_requests1d_groups: Vec<RequestsGroup>,
+}
+
+#[derive(Deserialize)]
+struct Viewer {
+    zones: Vec<ZoneGroups>,
+}
+
+/// Data of a [GraphqlQuery::zone_requests_by_day] query
+#[derive(Deserialize)]
+pub struct ZoneRequestsByDay {
+    viewer: Viewer,
+}
+
+impl ZoneRequestsByDay {
+    /// Traffic of each day, oldest first
+    pub fn days(self) -> Vec<DailyRequests> {
+        self.viewer
+            .zones
+            .into_iter()
+            .flat_map(|zone| zone.http_requests1d_groups)
+            .map(|group| DailyRequests {
+                date: group.dimensions.date,
+                requests: group.sum.requests,
+                bytes: group.sum.bytes,
+                cached_requests: group.sum.cached_requests,
+                cached_bytes: group.sum.cached_bytes,
+                threats: group.sum.threats,
+                page_views: group.sum.page_views,
+            })
+            .collect()
+    }
+}
+
+impl GraphqlQuery<ZoneRequestsByDay> {
+    /// HTTP traffic of the zone `zone_id` per day between `since` and `until` (inclusive, `YYYY-MM-DD`)
+    pub fn zone_requests_by_day(
+        zone_id: impl Into<String>,
+        since: impl Into<String>,
+        until: impl Into<String>,
+    ) -> Self {
+        let mut variables = serde_json::Map::new();
+        variables.insert("zoneTag".to_string(), zone_id.into().into());
+        variables.insert("since".to_string(), since.into().into());
+        variables.insert("until".to_string(), until.into().into());
+        Self::new(ZONE_REQUESTS_BY_DAY).variables(variables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Query = GraphqlQuery<serde_json::Value>;
+
+    #[test]
+    fn graphql_errors_on_error_status() {
+        let body = br#"{"data":null,"errors":[{"message":"error parsing args","path":["viewer","zones"],"extensions":{"code":"INVALID_ARGS"}}]}"#;
+        match Query::handle_error_body(StatusCode::BAD_REQUEST, Bytes::from_static(body)) {
+            Err(Error::Graphql(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].message, "error parsing args");
+            }
+            res => panic!("unexpected result {res:?}"),
+        }
+    }
+
+    #[test]
+    fn cloudflare_errors_on_error_status() {
+        let body = br#"{"success":false,"errors":[{"code":10000,"message":"Authentication error"}],"messages":[],"result":null}"#;
+        match Query::handle_error_body(StatusCode::FORBIDDEN, Bytes::from_static(body)) {
+            Err(Error::Cloudflare(errors)) => assert_eq!(errors[0].code, 10000),
+            res => panic!("unexpected result {res:?}"),
+        }
+    }
+}
@@ -0,0 +1,579 @@
+//! Cloudflare Health Checks related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/health-checks/)
+//! These are standalone health checks, see [crate::api::load_balancing] for monitors attached to pools.
+//! All requests are zone scoped, send them with [crate::CloudflareZone::send_zone]
+use std::collections::HashMap;
+
+use crate::{CfReqMeta, CfReqZone, CfSuccessResWithInfo, PageInfo, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Protocol of a health check
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HealthcheckType {
+    /// HTTP request, configured by [HttpHealthcheckConfig]
+    #[default]
+    Http,
+    /// HTTPS request, configured by [HttpHealthcheckConfig]
+    Https,
+    /// TCP connection, configured by [TcpHealthcheckConfig]
+    Tcp,
+    /// A type unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Region health checks are sent from
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HealthcheckRegion {
+    /// Western North America
+    Wnam,
+    /// Eastern North America
+    Enam,
+    /// Western Europe
+    Weu,
+    /// Eastern Europe
+    Eeu,
+    /// Northern South America
+    Nsam,
+    /// Southern South America
+    Ssam,
+    /// Oceania
+    Oc,
+    /// Middle East
+    Me,
+    /// Northern Africa
+    Naf,
+    /// Southern Africa
+    Saf,
+    /// India
+    In,
+    /// South East Asia
+    Seas,
+    /// North East Asia
+    Neas,
+    /// Every region (Enterprise only)
+    AllRegions,
+    /// A region unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// HTTP method of an HTTP(S) health check
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpHealthcheckMethod {
+    /// GET request
+    #[default]
+    Get,
+    /// HEAD request
+    Head,
+    /// A method unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Settings of an HTTP(S) health check
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct HttpHealthcheckConfig {
+    /// HTTP method, defaults to `GET`
+    pub method: HttpHealthcheckMethod,
+    /// Path requested, defaults to `/`
+    pub path: String,
+    /// Port of the origin, Cloudflare defaults to 80 (HTTP) or 443 (HTTPS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Expected response codes (e.g `200` or `2xx`), defaults to `200`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_codes: Option<Vec<String>>,
+    /// Case insensitive substring the response body must contain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_body: Option<String>,
+    /// Whether redirects are followed
+    pub follow_redirects: bool,
+    /// Whether invalid certificates are accepted (HTTPS only)
+    pub allow_insecure: bool,
+    /// Request headers sent with the check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<HashMap<String, Vec<String>>>,
+}
+
+impl Default for HttpHealthcheckConfig {
+    fn default() -> Self {
+        Self {
+            method: HttpHealthcheckMethod::Get,
+            path: "/".into(),
+            port: None,
+            expected_codes: Some(vec!["200".into()]),
+            expected_body: None,
+            follow_redirects: false,
+            allow_insecure: false,
+            header: None,
+        }
+    }
+}
+
+impl HttpHealthcheckConfig {
+    /// Add the `value` of the header `name` sent with the check
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.header
+            .get_or_insert_with(HashMap::new)
+            .entry(name.into())
+            .or_default()
+            .push(value.into());
+        self
+    }
+}
+
+/// Check performed by a TCP health check
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TcpHealthcheckMethod {
+    /// The connection is established
+    #[default]
+    ConnectionEstablished,
+    /// A method unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Settings of a TCP health check
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct TcpHealthcheckConfig {
+    /// Check performed, defaults to [TcpHealthcheckMethod::ConnectionEstablished]
+    pub method: TcpHealthcheckMethod,
+    /// Port of the origin, Cloudflare defaults to 80
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+}
+
+/// Configuration of a health check, used by [CreateHealthcheck], [PatchHealthcheck] and [CreateHealthcheckPreview]
+/// The default is an HTTP `GET /` every 60 seconds expecting a `200`,
+/// only the address has to be set (see [Self::http], [Self::https] and [Self::tcp])
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct HealthcheckConfig {
+    /// Hostname or IP address of the origin
+    pub address: String,
+    /// Protocol of the check
+    #[serde(rename = "type")]
+    pub kind: HealthcheckType,
+    /// Description of the check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Regions the check is sent from, Cloudflare picks one when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_regions: Option<Vec<HealthcheckRegion>>,
+    /// Seconds between checks
+    pub interval: u32,
+    /// Retries on timeout or connection failure before marking the origin unhealthy
+    pub retries: u32,
+    /// Seconds before a check times out
+    pub timeout: u32,
+    /// Consecutive failures before the origin becomes unhealthy
+    pub consecutive_fails: u32,
+    /// Consecutive successes before the origin becomes healthy
+    pub consecutive_successes: u32,
+    /// Whether the check is suspended
+    pub suspended: bool,
+    /// HTTP(S) settings, only for [HealthcheckType::Http] and [HealthcheckType::Https]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_config: Option<HttpHealthcheckConfig>,
+    /// TCP settings, only for [HealthcheckType::Tcp]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tcp_config: Option<TcpHealthcheckConfig>,
+}
+
+impl Default for HealthcheckConfig {
+    fn default() -> Self {
+        Self {
+            address: String::new(),
+            kind: HealthcheckType::Http,
+            description: None,
+            check_regions: None,
+            interval: 60,
+            retries: 2,
+            timeout: 5,
+            consecutive_fails: 1,
+            consecutive_successes: 1,
+            suspended: false,
+            http_config: Some(HttpHealthcheckConfig::default()),
+            tcp_config: None,
+        }
+    }
+}
+
+impl HealthcheckConfig {
+    /// HTTP check of `address` with the default settings
+    pub fn http(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            ..Default::default()
+        }
+    }
+
+    /// HTTPS check of `address` with the default settings
+    pub fn https(address: impl Into<String>) -> Self {
+        Self {
+            kind: HealthcheckType::Https,
+            ..Self::http(address)
+        }
+    }
+
+    /// TCP check of `address` on `port`
+    pub fn tcp(address: impl Into<String>, port: u16) -> Self {
+        Self {
+            kind: HealthcheckType::Tcp,
+            http_config: None,
+            tcp_config: Some(TcpHealthcheckConfig {
+                port: Some(port),
+                ..Default::default()
+            }),
+            ..Self::http(address)
+        }
+    }
+}
+
+/// Health status of an origin
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthcheckStatus {
+    /// Not checked yet
+    Unknown,
+    /// Origin is healthy
+    Healthy,
+    /// Origin is unhealthy, see [Healthcheck::failure_reason]
+    Unhealthy,
+    /// Check is suspended
+    Suspended,
+    /// A status unknown to this library
+    #[serde(other)]
+    Other,
+}
+
+/// A health check
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Healthcheck {
+    /// Health check ID
+    pub id: String,
+    /// Name of the check
+    pub name: String,
+    /// Current health of the origin
+    pub status: HealthcheckStatus,
+    /// Reason of the last failure
+    #[serde(default)]
+    pub failure_reason: String,
+    /// Configuration of the check
+    #[serde(flatten)]
+    pub config: HealthcheckConfig,
+    /// Creation time
+    pub created_on: String,
+    /// Last update time
+    pub modified_on: String,
+}
+
+impl Healthcheck {
+    /// Whether the origin is healthy
+    pub fn is_healthy(&self) -> bool {
+        self.status == HealthcheckStatus::Healthy
+    }
+}
+
+/// [Create Health Check](https://developers.cloudflare.com/api/operations/health-checks-create-health-check) API
+#[derive(Serialize)]
+pub struct CreateHealthcheck {
+    name: String,
+    #[serde(flatten)]
+    config: HealthcheckConfig,
+}
+
+impl CreateHealthcheck {
+    /// Create the health check `name`
+    pub fn new(name: impl Into<String>, config: HealthcheckConfig) -> Self {
+        Self {
+            name: name.into(),
+            config,
+        }
+    }
+}
+
+/// Success response from the [Create Health Check](https://developers.cloudflare.com/api/operations/health-checks-create-health-check#Responses) API
+pub type CreateHealthcheckRes = Healthcheck;
+
+impl CfReqMeta for CreateHealthcheck {
+    const METHOD: Method = Method::POST;
+    type Response = CreateHealthcheckRes;
+}
+
+impl CfReqZone for CreateHealthcheck {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/healthchecks")
+    }
+}
+
+/// [List Health Checks](https://developers.cloudflare.com/api/operations/health-checks-list-health-checks) API
+#[derive(Serialize, Default)]
+pub struct ListHealthchecks {
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListHealthchecks {
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of health checks per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List Health Checks](https://developers.cloudflare.com/api/operations/health-checks-list-health-checks#Responses) API
+#[derive(Deserialize)]
+pub struct ListHealthchecksRes {
+    /// Health checks in this page
+    pub healthchecks: Vec<Healthcheck>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListHealthchecks {
+    const METHOD: Method = Method::GET;
+    type Response = ListHealthchecksRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (healthchecks, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListHealthchecksRes {
+            healthchecks,
+            page_info,
+        })
+    }
+}
+
+impl CfReqZone for ListHealthchecks {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/healthchecks")
+    }
+}
+
+/// [Health Check Details](https://developers.cloudflare.com/api/operations/health-checks-health-check-details) API
+#[derive(Serialize)]
+pub struct GetHealthcheck {
+    #[serde(skip)]
+    healthcheck_id: String,
+}
+
+impl GetHealthcheck {
+    /// Get the health check `healthcheck_id`
+    pub fn new(healthcheck_id: impl Into<String>) -> Self {
+        Self {
+            healthcheck_id: healthcheck_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Health Check Details](https://developers.cloudflare.com/api/operations/health-checks-health-check-details#Responses) API
+pub type GetHealthcheckRes = Healthcheck;
+
+impl CfReqMeta for GetHealthcheck {
+    const METHOD: Method = Method::GET;
+    type Response = GetHealthcheckRes;
+}
+
+impl CfReqZone for GetHealthcheck {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/healthchecks/{}", self.healthcheck_id)
+    }
+}
+
+/// [Patch Health Check](https://developers.cloudflare.com/api/operations/health-checks-patch-health-check) API
+/// The API requires the name and the full configuration (e.g [Healthcheck::config] with changes)
+#[derive(Serialize)]
+pub struct PatchHealthcheck {
+    #[serde(skip)]
+    healthcheck_id: String,
+    name: String,
+    #[serde(flatten)]
+    config: HealthcheckConfig,
+}
+
+impl PatchHealthcheck {
+    /// Change the health check `healthcheck_id` to `name` and `config`
+    pub fn new(
+        healthcheck_id: impl Into<String>,
+        name: impl Into<String>,
+        config: HealthcheckConfig,
+    ) -> Self {
+        Self {
+            healthcheck_id: healthcheck_id.into(),
+            name: name.into(),
+            config,
+        }
+    }
+}
+
+/// Success response from the [Patch Health Check](https://developers.cloudflare.com/api/operations/health-checks-patch-health-check#Responses) API
+pub type PatchHealthcheckRes = Healthcheck;
+
+impl CfReqMeta for PatchHealthcheck {
+    const METHOD: Method = Method::PATCH;
+    type Response = PatchHealthcheckRes;
+}
+
+impl CfReqZone for PatchHealthcheck {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/healthchecks/{}", self.healthcheck_id)
+    }
+}
+
+/// [Delete Health Check](https://developers.cloudflare.com/api/operations/health-checks-delete-health-check) API
+#[derive(Serialize)]
+pub struct DeleteHealthcheck {
+    #[serde(skip)]
+    healthcheck_id: String,
+}
+
+impl DeleteHealthcheck {
+    /// Delete the health check `healthcheck_id`
+    pub fn new(healthcheck_id: impl Into<String>) -> Self {
+        Self {
+            healthcheck_id: healthcheck_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeleteHealthcheck {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqZone for DeleteHealthcheck {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/healthchecks/{}", self.healthcheck_id)
+    }
+}
+
+/// [Create Preview Health Check](https://developers.cloudflare.com/api/operations/health-checks-create-preview-health-check) API
+/// A preview runs the check without saving it, fetch the result with [GetHealthcheckPreview]
+#[derive(Serialize)]
+pub struct CreateHealthcheckPreview {
+    name: String,
+    #[serde(flatten)]
+    config: HealthcheckConfig,
+}
+
+impl CreateHealthcheckPreview {
+    /// Preview the health check `name`
+    pub fn new(name: impl Into<String>, config: HealthcheckConfig) -> Self {
+        Self {
+            name: name.into(),
+            config,
+        }
+    }
+}
+
+/// Success response from the [Create Preview Health Check](https://developers.cloudflare.com/api/operations/health-checks-create-preview-health-check#Responses) API
+pub type CreateHealthcheckPreviewRes = Healthcheck;
+
+impl CfReqMeta for CreateHealthcheckPreview {
+    const METHOD: Method = Method::POST;
+    type Response = CreateHealthcheckPreviewRes;
+}
+
+impl CfReqZone for CreateHealthcheckPreview {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/healthchecks/preview")
+    }
+}
+
+/// [Health Check Preview Details](https://developers.cloudflare.com/api/operations/health-checks-health-check-preview-details) API
+#[derive(Serialize)]
+pub struct GetHealthcheckPreview {
+    #[serde(skip)]
+    preview_id: String,
+}
+
+impl GetHealthcheckPreview {
+    /// Get the preview `preview_id` and its result
+    pub fn new(preview_id: impl Into<String>) -> Self {
+        Self {
+            preview_id: preview_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Health Check Preview Details](https://developers.cloudflare.com/api/operations/health-checks-health-check-preview-details#Responses) API
+pub type GetHealthcheckPreviewRes = Healthcheck;
+
+impl CfReqMeta for GetHealthcheckPreview {
+    const METHOD: Method = Method::GET;
+    type Response = GetHealthcheckPreviewRes;
+}
+
+impl CfReqZone for GetHealthcheckPreview {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/healthchecks/preview/{}", self.preview_id)
+    }
+}
+
+/// [Delete Preview Health Check](https://developers.cloudflare.com/api/operations/health-checks-delete-preview-health-check) API
+#[derive(Serialize)]
+pub struct DeleteHealthcheckPreview {
+    #[serde(skip)]
+    preview_id: String,
+}
+
+impl DeleteHealthcheckPreview {
+    /// Delete the preview `preview_id`
+    pub fn new(preview_id: impl Into<String>) -> Self {
+        Self {
+            preview_id: preview_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeleteHealthcheckPreview {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqZone for DeleteHealthcheckPreview {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/healthchecks/preview/{}", self.preview_id)
+    }
+}
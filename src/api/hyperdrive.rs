@@ -0,0 +1,337 @@
+//! Cloudflare Hyperdrive related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/hyperdrive/)
+use std::fmt;
+
+use crate::{CfReqAuth, CfReqMeta, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Database a Hyperdrive config connects to, including its credentials
+#[derive(Serialize, Clone)]
+pub struct HyperdriveOrigin {
+    host: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    port: Option<u16>,
+    database: String,
+    user: String,
+    password: String,
+    scheme: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    access_client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    access_client_secret: Option<String>,
+}
+
+impl fmt::Debug for HyperdriveOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HyperdriveOrigin")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("database", &self.database)
+            .field("user", &self.user)
+            .field("password", &"<redacted>")
+            .field("scheme", &self.scheme)
+            .field("access_client_id", &self.access_client_id)
+            .field(
+                "access_client_secret",
+                &self.access_client_secret.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+impl HyperdriveOrigin {
+    /// A postgres database reachable at `host:port`
+    pub fn new(
+        host: impl Into<String>,
+        port: u16,
+        database: impl Into<String>,
+        user: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        Self {
+            host: host.into(),
+            port: Some(port),
+            database: database.into(),
+            user: user.into(),
+            password: password.into(),
+            scheme: "postgres".to_string(),
+            access_client_id: None,
+            access_client_secret: None,
+        }
+    }
+
+    /// A postgres database behind a Cloudflare Tunnel protected by Access,
+    /// authenticated with a service token
+    pub fn access(
+        host: impl Into<String>,
+        database: impl Into<String>,
+        user: impl Into<String>,
+        password: impl Into<String>,
+        access_client_id: impl Into<String>,
+        access_client_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            host: host.into(),
+            port: None,
+            database: database.into(),
+            user: user.into(),
+            password: password.into(),
+            scheme: "postgres".to_string(),
+            access_client_id: Some(access_client_id.into()),
+            access_client_secret: Some(access_client_secret.into()),
+        }
+    }
+
+    /// Connection scheme (e.g `postgres`, `postgresql`)
+    pub fn scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.scheme = scheme.into();
+        self
+    }
+}
+
+/// Database a Hyperdrive config connects to, as returned by cloudflare
+/// Secrets are never returned
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HyperdriveOriginDetails {
+    /// Hostname of the database
+    pub host: String,
+    /// Port of the database, not set when connecting through Access
+    pub port: Option<u16>,
+    /// Name of the database
+    pub database: String,
+    /// User connecting to the database
+    pub user: String,
+    /// Connection scheme
+    pub scheme: Option<String>,
+    /// Client ID of the Access service token
+    pub access_client_id: Option<String>,
+}
+
+/// Query caching of a Hyperdrive config
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct HyperdriveCaching {
+    /// Whether query caching is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    /// Seconds a query result is cached
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age: Option<u32>,
+    /// Seconds a stale query result is served while it is refreshed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stale_while_revalidate: Option<u32>,
+}
+
+/// A Hyperdrive config
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HyperdriveConfig {
+    /// Identifier of the config, used in worker bindings
+    pub id: String,
+    /// Name of the config
+    pub name: String,
+    /// Database the config connects to
+    pub origin: HyperdriveOriginDetails,
+    /// Query caching
+    pub caching: Option<HyperdriveCaching>,
+}
+
+/// [Create Hyperdrive](https://developers.cloudflare.com/api/operations/create-hyperdrive) API
+#[derive(Serialize, Debug)]
+pub struct CreateHyperdriveConfig {
+    name: String,
+    origin: HyperdriveOrigin,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caching: Option<HyperdriveCaching>,
+}
+
+impl CreateHyperdriveConfig {
+    /// Create a config named `name` connecting to `origin`
+    pub fn new(name: impl Into<String>, origin: HyperdriveOrigin) -> Self {
+        Self {
+            name: name.into(),
+            origin,
+            caching: None,
+        }
+    }
+
+    /// Query caching, cloudflare's defaults are used if not set
+    pub fn caching(mut self, caching: HyperdriveCaching) -> Self {
+        self.caching = Some(caching);
+        self
+    }
+}
+
+/// Success response from the [Create Hyperdrive](https://developers.cloudflare.com/api/operations/create-hyperdrive#Responses) API
+pub type CreateHyperdriveConfigRes = HyperdriveConfig;
+
+impl CfReqMeta for CreateHyperdriveConfig {
+    const METHOD: Method = Method::POST;
+    type Response = CreateHyperdriveConfigRes;
+}
+
+impl CfReqAuth for CreateHyperdriveConfig {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/hyperdrive/configs")
+    }
+}
+
+/// [List Hyperdrives](https://developers.cloudflare.com/api/operations/list-hyperdrive) API
+#[derive(Serialize, Default)]
+pub struct ListHyperdriveConfigs {}
+
+/// Success response from the [List Hyperdrives](https://developers.cloudflare.com/api/operations/list-hyperdrive#Responses) API
+pub type ListHyperdriveConfigsRes = Vec<HyperdriveConfig>;
+
+impl CfReqMeta for ListHyperdriveConfigs {
+    const METHOD: Method = Method::GET;
+    type Response = ListHyperdriveConfigsRes;
+}
+
+impl CfReqAuth for ListHyperdriveConfigs {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/hyperdrive/configs")
+    }
+}
+
+/// [Get Hyperdrive](https://developers.cloudflare.com/api/operations/get-hyperdrive) API
+#[derive(Serialize)]
+pub struct GetHyperdriveConfig {
+    #[serde(skip)]
+    config_id: String,
+}
+
+impl GetHyperdriveConfig {
+    /// Retrieve the config with the given id
+    pub fn new(config_id: impl Into<String>) -> Self {
+        Self {
+            config_id: config_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Hyperdrive](https://developers.cloudflare.com/api/operations/get-hyperdrive#Responses) API
+pub type GetHyperdriveConfigRes = HyperdriveConfig;
+
+impl CfReqMeta for GetHyperdriveConfig {
+    const METHOD: Method = Method::GET;
+    type Response = GetHyperdriveConfigRes;
+}
+
+impl CfReqAuth for GetHyperdriveConfig {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/hyperdrive/configs/{}",
+            self.config_id
+        )
+    }
+}
+
+/// [Patch Hyperdrive](https://developers.cloudflare.com/api/operations/patch-hyperdrive) API
+#[derive(Serialize, Debug)]
+pub struct PatchHyperdriveConfig {
+    #[serde(skip)]
+    config_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    origin: Option<HyperdriveOrigin>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caching: Option<HyperdriveCaching>,
+}
+
+impl PatchHyperdriveConfig {
+    /// Change the config with the given id, only the fields set are changed
+    pub fn new(config_id: impl Into<String>) -> Self {
+        Self {
+            config_id: config_id.into(),
+            name: None,
+            origin: None,
+            caching: None,
+        }
+    }
+
+    /// Rename the config
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Connect to another database or rotate its credentials
+    pub fn origin(mut self, origin: HyperdriveOrigin) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Change the query caching
+    pub fn caching(mut self, caching: HyperdriveCaching) -> Self {
+        self.caching = Some(caching);
+        self
+    }
+}
+
+/// Success response from the [Patch Hyperdrive](https://developers.cloudflare.com/api/operations/patch-hyperdrive#Responses) API
+pub type PatchHyperdriveConfigRes = HyperdriveConfig;
+
+impl CfReqMeta for PatchHyperdriveConfig {
+    const METHOD: Method = Method::PATCH;
+    type Response = PatchHyperdriveConfigRes;
+}
+
+impl CfReqAuth for PatchHyperdriveConfig {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/hyperdrive/configs/{}",
+            self.config_id
+        )
+    }
+}
+
+/// [Delete Hyperdrive](https://developers.cloudflare.com/api/operations/delete-hyperdrive) API
+#[derive(Serialize)]
+pub struct DeleteHyperdriveConfig {
+    #[serde(skip)]
+    config_id: String,
+}
+
+impl DeleteHyperdriveConfig {
+    /// Delete the config with the given id
+    pub fn new(config_id: impl Into<String>) -> Self {
+        Self {
+            config_id: config_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Hyperdrive](https://developers.cloudflare.com/api/operations/delete-hyperdrive#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteHyperdriveConfigRes;
+
+impl CfReqMeta for DeleteHyperdriveConfig {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteHyperdriveConfigRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(DeleteHyperdriveConfigRes)
+    }
+}
+
+impl CfReqAuth for DeleteHyperdriveConfig {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/hyperdrive/configs/{}",
+            self.config_id
+        )
+    }
+}
@@ -0,0 +1,923 @@
+//! Cloudflare Images related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/images/)
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    request::{file_part, rfc3339},
+    CfReqAuth, CfReqMeta, CfSuccessRes, Error, Result,
+};
+use bytes::Bytes;
+use reqwest::{
+    multipart::{Form, Part},
+    Method,
+};
+use serde::{Deserialize, Serialize};
+
+/// Cloudflare error code returned when an image does not exist
+/// see [crate::Error::has_cf_code]
+pub const IMAGE_NOT_FOUND: u32 = 5404;
+
+/// Minimum time from now at which a [DirectUploadImage] URL can expire
+pub const IMAGE_DIRECT_UPLOAD_MIN_EXPIRY: Duration = Duration::from_secs(2 * 60);
+/// Maximum time from now at which a [DirectUploadImage] URL can expire
+pub const IMAGE_DIRECT_UPLOAD_MAX_EXPIRY: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// [Create authenticated direct upload URL V2](https://developers.cloudflare.com/api/operations/cloudflare-images-create-authenticated-direct-upload-url-v-2) API
+/// Sent via [crate::CloudflareAuth::send_auth_multipart]
+#[derive(Default)]
+pub struct DirectUploadImage {
+    id: Option<String>,
+    require_signed_urls: Option<bool>,
+    metadata: Option<String>,
+    expiry: Option<String>,
+}
+
+/// Success response from the [Create authenticated direct upload URL V2](https://developers.cloudflare.com/api/operations/cloudflare-images-create-authenticated-direct-upload-url-v-2#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DirectUploadImageRes {
+    /// Identifier of the image
+    pub id: String,
+    /// URL for uploading the image
+    #[serde(rename = "uploadURL")]
+    pub upload_url: String,
+}
+
+impl DirectUploadImage {
+    /// Use a custom identifier for the image instead of a generated one
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Require signed URLs for delivery
+    pub fn require_signed_urls(mut self, require_signed_urls: bool) -> Self {
+        self.require_signed_urls = Some(require_signed_urls);
+        self
+    }
+
+    /// Attach user defined metadata to the image
+    pub fn metadata(mut self, metadata: &impl Serialize) -> Result<Self> {
+        self.metadata = Some(serde_json::to_string(metadata)?);
+        Ok(self)
+    }
+
+    /// Time at which the upload URL stops working
+    /// Must be between [IMAGE_DIRECT_UPLOAD_MIN_EXPIRY] and [IMAGE_DIRECT_UPLOAD_MAX_EXPIRY] from now
+    pub fn expiry(self, at: SystemTime) -> Result<Self> {
+        let expires_in = at.duration_since(SystemTime::now()).unwrap_or_default();
+        self.expiry_in(expires_in)
+    }
+
+    /// Duration from now after which the upload URL stops working
+    /// Must be between [IMAGE_DIRECT_UPLOAD_MIN_EXPIRY] and [IMAGE_DIRECT_UPLOAD_MAX_EXPIRY]
+    pub fn expiry_in(mut self, expires_in: Duration) -> Result<Self> {
+        if !(IMAGE_DIRECT_UPLOAD_MIN_EXPIRY..=IMAGE_DIRECT_UPLOAD_MAX_EXPIRY).contains(&expires_in)
+        {
+            return Err(Error::InvalidRequest(format!(
+                "image direct upload expiry must be between {}s and {}s from now, got {}s",
+                IMAGE_DIRECT_UPLOAD_MIN_EXPIRY.as_secs(),
+                IMAGE_DIRECT_UPLOAD_MAX_EXPIRY.as_secs(),
+                expires_in.as_secs()
+            )));
+        }
+        self.expiry = Some(rfc3339(SystemTime::now() + expires_in));
+        Ok(self)
+    }
+}
+
+impl From<DirectUploadImage> for Form {
+    fn from(value: DirectUploadImage) -> Self {
+        let mut form = Form::new();
+        if let Some(id) = value.id {
+            form = form.text("id", id);
+        }
+        if let Some(require_signed_urls) = value.require_signed_urls {
+            form = form.text("requireSignedURLs", require_signed_urls.to_string());
+        }
+        if let Some(metadata) = value.metadata {
+            form = form.text("metadata", metadata);
+        }
+        if let Some(expiry) = value.expiry {
+            form = form.text("expiry", expiry);
+        }
+        form
+    }
+}
+
+impl CfReqMeta for DirectUploadImage {
+    const METHOD: Method = Method::POST;
+    type Response = DirectUploadImageRes;
+}
+
+impl CfReqAuth for DirectUploadImage {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v2/direct_upload")
+    }
+}
+
+/// An image stored in Cloudflare Images
+#[derive(Serialize, Deserialize)]
+pub struct Image {
+    /// Identifier of the image
+    pub id: String,
+    /// Name of the uploaded file
+    pub filename: Option<String>,
+    /// User defined metadata
+    pub meta: Option<serde_json::Value>,
+    /// RFC3339 timestamp of when the image was uploaded
+    pub uploaded: String,
+    /// Whether the image can only be delivered with signed URLs
+    #[serde(rename = "requireSignedURLs")]
+    pub require_signed_urls: bool,
+    /// Delivery URLs of the image, one per variant
+    pub variants: Vec<String>,
+}
+
+enum ImageSource {
+    File(Box<Part>),
+    Url(String),
+}
+
+/// [Upload an image](https://developers.cloudflare.com/api/operations/cloudflare-images-upload-an-image-via-url) API
+/// Sent via [crate::CloudflareAuth::send_auth_multipart]
+pub struct UploadImage {
+    source: ImageSource,
+    id: Option<String>,
+    metadata: Option<String>,
+    require_signed_urls: Option<bool>,
+}
+
+/// Success response from the [Upload an image](https://developers.cloudflare.com/api/operations/cloudflare-images-upload-an-image-via-url#Responses) API
+pub type UploadImageRes = Image;
+
+impl UploadImage {
+    fn with_source(source: ImageSource) -> Self {
+        Self {
+            source,
+            id: None,
+            metadata: None,
+            require_signed_urls: None,
+        }
+    }
+
+    /// Upload an in-memory image
+    /// the content type is guessed from `file_name`
+    pub fn from_bytes(file: impl Into<Bytes>, file_name: impl Into<String>) -> Result<Self> {
+        let part = file_part(file.into(), file_name.into())?;
+        Ok(Self::with_source(ImageSource::File(Box::new(part))))
+    }
+
+    /// Upload an in-memory image with an explicit content type (e.g `image/png`)
+    pub fn from_bytes_with_type(
+        file: impl Into<Bytes>,
+        file_name: impl Into<String>,
+        content_type: &str,
+    ) -> Result<Self> {
+        let file = file.into();
+        let len = file.len() as u64;
+        let part = Part::stream_with_length(file, len)
+            .file_name(file_name.into())
+            .mime_str(content_type)?;
+        Ok(Self::with_source(ImageSource::File(Box::new(part))))
+    }
+
+    /// Let cloudflare fetch the image from the given URL
+    pub fn from_url(url: impl Into<String>) -> Self {
+        Self::with_source(ImageSource::Url(url.into()))
+    }
+
+    /// Use a custom identifier for the image instead of a generated one
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Attach user defined metadata to the image
+    pub fn metadata(mut self, metadata: &impl Serialize) -> Result<Self> {
+        self.metadata = Some(serde_json::to_string(metadata)?);
+        Ok(self)
+    }
+
+    /// Require signed URLs for delivery
+    pub fn require_signed_urls(mut self, require_signed_urls: bool) -> Self {
+        self.require_signed_urls = Some(require_signed_urls);
+        self
+    }
+}
+
+impl From<UploadImage> for Form {
+    fn from(value: UploadImage) -> Self {
+        let mut form = match value.source {
+            ImageSource::File(part) => Form::new().part("file", *part),
+            ImageSource::Url(url) => Form::new().text("url", url),
+        };
+        if let Some(id) = value.id {
+            form = form.text("id", id);
+        }
+        if let Some(metadata) = value.metadata {
+            form = form.text("metadata", metadata);
+        }
+        if let Some(require_signed_urls) = value.require_signed_urls {
+            form = form.text("requireSignedURLs", require_signed_urls.to_string());
+        }
+        form
+    }
+}
+
+impl CfReqMeta for UploadImage {
+    const METHOD: Method = Method::POST;
+    type Response = UploadImageRes;
+}
+
+impl CfReqAuth for UploadImage {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v1")
+    }
+}
+
+/// Sort order for [ListImages]
+#[derive(Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageSortOrder {
+    /// Oldest images first
+    Asc,
+    /// Newest images first
+    Desc,
+}
+
+/// [List images V2](https://developers.cloudflare.com/api/operations/cloudflare-images-list-images-v-2) API
+#[derive(Serialize, Default)]
+pub struct ListImages {
+    per_page: Option<u32>,
+    continuation_token: Option<String>,
+    sort_order: Option<ImageSortOrder>,
+}
+
+impl ListImages {
+    /// Number of images per page (10 - 10000, Cloudflare defaults to 1000)
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Continue listing from the token returned by a previous [ListImagesRes]
+    pub fn continuation_token(mut self, continuation_token: impl Into<String>) -> Self {
+        self.continuation_token = Some(continuation_token.into());
+        self
+    }
+
+    /// Order of the images by upload time
+    pub fn sort_order(mut self, sort_order: ImageSortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+}
+
+/// Success response from the [List images V2](https://developers.cloudflare.com/api/operations/cloudflare-images-list-images-v-2#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListImagesRes {
+    /// Images in this page
+    pub images: Vec<Image>,
+    /// Token for fetching the next page, `None` if this is the last page
+    pub continuation_token: Option<String>,
+}
+
+impl CfReqMeta for ListImages {
+    const METHOD: Method = Method::GET;
+    type Response = ListImagesRes;
+}
+
+impl CfReqAuth for ListImages {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v2")
+    }
+}
+
+/// [Image details](https://developers.cloudflare.com/api/operations/cloudflare-images-image-details) API
+#[derive(Serialize)]
+pub struct ImageDetails {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl ImageDetails {
+    /// Retrieve details of an image
+    /// identifier is the image's id
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Image details](https://developers.cloudflare.com/api/operations/cloudflare-images-image-details#Responses) API
+pub type ImageDetailsRes = Image;
+
+impl CfReqMeta for ImageDetails {
+    const METHOD: Method = Method::GET;
+    type Response = ImageDetailsRes;
+}
+
+impl CfReqAuth for ImageDetails {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v1/{}", self.identifier)
+    }
+}
+
+/// [Delete image](https://developers.cloudflare.com/api/operations/cloudflare-images-delete-image) API
+/// A missing image results in an error for which [crate::Error::has_cf_code] is true with [IMAGE_NOT_FOUND]
+#[derive(Serialize)]
+pub struct DeleteImage {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl DeleteImage {
+    /// Delete an image
+    /// identifier is the image's id
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete image](https://developers.cloudflare.com/api/operations/cloudflare-images-delete-image#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteImageRes;
+
+impl CfReqMeta for DeleteImage {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteImageRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty object
+        Ok(DeleteImageRes)
+    }
+}
+
+impl CfReqAuth for DeleteImage {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v1/{}", self.identifier)
+    }
+}
+
+/// [Update image](https://developers.cloudflare.com/api/operations/cloudflare-images-update-image) API
+#[derive(Serialize)]
+pub struct UpdateImage {
+    #[serde(skip)]
+    identifier: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<serde_json::Value>,
+    #[serde(rename = "requireSignedURLs", skip_serializing_if = "Option::is_none")]
+    require_signed_urls: Option<bool>,
+}
+
+impl UpdateImage {
+    /// Update an image, only the fields set are changed
+    /// identifier is the image's id
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+            metadata: None,
+            require_signed_urls: None,
+        }
+    }
+
+    /// Replace the user defined metadata of the image
+    pub fn metadata(mut self, metadata: &impl Serialize) -> Result<Self> {
+        self.metadata = Some(serde_json::to_value(metadata)?);
+        Ok(self)
+    }
+
+    /// Require signed URLs for delivery
+    pub fn require_signed_urls(mut self, require_signed_urls: bool) -> Self {
+        self.require_signed_urls = Some(require_signed_urls);
+        self
+    }
+}
+
+/// Success response from the [Update image](https://developers.cloudflare.com/api/operations/cloudflare-images-update-image#Responses) API
+pub type UpdateImageRes = Image;
+
+impl CfReqMeta for UpdateImage {
+    const METHOD: Method = Method::PATCH;
+    type Response = UpdateImageRes;
+}
+
+impl CfReqAuth for UpdateImage {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v1/{}", self.identifier)
+    }
+}
+
+/// [Base image](https://developers.cloudflare.com/api/operations/cloudflare-images-base-image) API
+/// returning the originally uploaded image as is
+#[derive(Serialize)]
+pub struct DownloadImageBlob {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl DownloadImageBlob {
+    /// Download the original image
+    /// identifier is the image's id
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Raw bytes of the original image
+pub type DownloadImageBlobRes = Bytes;
+
+impl CfReqMeta for DownloadImageBlob {
+    const METHOD: Method = Method::GET;
+    type Response = DownloadImageBlobRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        // errors are still JSON, handled before this is called
+        Ok(body)
+    }
+}
+
+impl CfReqAuth for DownloadImageBlob {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v1/{}/blob", self.identifier)
+    }
+}
+
+/// How an image is resized to fit a variant's dimensions
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum VariantFit {
+    /// Shrink to fit within the dimensions, never enlarging
+    ScaleDown,
+    /// Resize to fit within the dimensions, preserving aspect ratio
+    Contain,
+    /// Resize to fill the dimensions, cropping if needed
+    Cover,
+    /// Shrink and crop to fill the dimensions, never enlarging
+    Crop,
+    /// Resize to fit within the dimensions, padding the rest
+    Pad,
+}
+
+/// What image metadata (EXIF) is kept in a variant
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VariantMetadata {
+    /// Keep all metadata
+    Keep,
+    /// Only keep copyright metadata
+    Copyright,
+    /// Strip all metadata
+    None,
+}
+
+/// Resizing options of a variant
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct VariantOptions {
+    /// How the image is resized
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fit: Option<VariantFit>,
+    /// Maximum width in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    /// Maximum height in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// What metadata is kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<VariantMetadata>,
+}
+
+/// A named variant of Cloudflare Images
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Variant {
+    /// Name of the variant
+    pub id: String,
+    /// Resizing options
+    pub options: VariantOptions,
+    /// Whether the variant can be delivered without signed URLs
+    #[serde(rename = "neverRequireSignedURLs", default)]
+    pub never_require_signed_urls: bool,
+}
+
+#[derive(Deserialize)]
+struct VariantResult {
+    variant: Variant,
+}
+
+// variants are wrapped in `{"variant": ...}`
+fn variant_from_body(body: &[u8]) -> Result<Variant> {
+    let res: CfSuccessRes<VariantResult> = serde_json::from_slice(body)?;
+    Ok(res.result.variant)
+}
+
+/// [Create a variant](https://developers.cloudflare.com/api/operations/cloudflare-images-variants-create-a-variant) API
+#[derive(Serialize)]
+pub struct CreateVariant {
+    id: String,
+    options: VariantOptions,
+    #[serde(
+        rename = "neverRequireSignedURLs",
+        skip_serializing_if = "Option::is_none"
+    )]
+    never_require_signed_urls: Option<bool>,
+}
+
+impl CreateVariant {
+    /// Create a variant with the given name
+    pub fn new(id: impl Into<String>, options: VariantOptions) -> Self {
+        Self {
+            id: id.into(),
+            options,
+            never_require_signed_urls: None,
+        }
+    }
+
+    /// Allow delivering this variant without signed URLs,
+    /// even for images that require them
+    pub fn never_require_signed_urls(mut self, never_require_signed_urls: bool) -> Self {
+        self.never_require_signed_urls = Some(never_require_signed_urls);
+        self
+    }
+}
+
+/// Success response from the [Create a variant](https://developers.cloudflare.com/api/operations/cloudflare-images-variants-create-a-variant#Responses) API
+pub type CreateVariantRes = Variant;
+
+impl CfReqMeta for CreateVariant {
+    const METHOD: Method = Method::POST;
+    type Response = CreateVariantRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        variant_from_body(&body)
+    }
+}
+
+impl CfReqAuth for CreateVariant {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v1/variants")
+    }
+}
+
+/// [List variants](https://developers.cloudflare.com/api/operations/cloudflare-images-variants-list-variants) API
+#[derive(Serialize, Default)]
+pub struct ListVariants {}
+
+/// Success response from the [List variants](https://developers.cloudflare.com/api/operations/cloudflare-images-variants-list-variants#Responses) API
+/// Variants keyed by name
+pub type ListVariantsRes = HashMap<String, Variant>;
+
+#[derive(Deserialize)]
+struct VariantsResult {
+    variants: ListVariantsRes,
+}
+
+impl CfReqMeta for ListVariants {
+    const METHOD: Method = Method::GET;
+    type Response = ListVariantsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let res: CfSuccessRes<VariantsResult> = serde_json::from_slice(&body)?;
+        Ok(res.result.variants)
+    }
+}
+
+impl CfReqAuth for ListVariants {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v1/variants")
+    }
+}
+
+/// [Variant details](https://developers.cloudflare.com/api/operations/cloudflare-images-variants-variant-details) API
+#[derive(Serialize)]
+pub struct GetVariant {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl GetVariant {
+    /// Retrieve a variant
+    /// identifier is the variant's name
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Variant details](https://developers.cloudflare.com/api/operations/cloudflare-images-variants-variant-details#Responses) API
+pub type GetVariantRes = Variant;
+
+impl CfReqMeta for GetVariant {
+    const METHOD: Method = Method::GET;
+    type Response = GetVariantRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        variant_from_body(&body)
+    }
+}
+
+impl CfReqAuth for GetVariant {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/images/v1/variants/{}",
+            self.identifier
+        )
+    }
+}
+
+/// [Update a variant](https://developers.cloudflare.com/api/operations/cloudflare-images-variants-update-a-variant) API
+#[derive(Serialize)]
+pub struct UpdateVariant {
+    #[serde(skip)]
+    identifier: String,
+    options: VariantOptions,
+    #[serde(
+        rename = "neverRequireSignedURLs",
+        skip_serializing_if = "Option::is_none"
+    )]
+    never_require_signed_urls: Option<bool>,
+}
+
+impl UpdateVariant {
+    /// Update a variant, only the options set are changed
+    /// identifier is the variant's name
+    pub fn new(identifier: impl Into<String>, options: VariantOptions) -> Self {
+        Self {
+            identifier: identifier.into(),
+            options,
+            never_require_signed_urls: None,
+        }
+    }
+
+    /// Allow delivering this variant without signed URLs,
+    /// even for images that require them
+    pub fn never_require_signed_urls(mut self, never_require_signed_urls: bool) -> Self {
+        self.never_require_signed_urls = Some(never_require_signed_urls);
+        self
+    }
+}
+
+/// Success response from the [Update a variant](https://developers.cloudflare.com/api/operations/cloudflare-images-variants-update-a-variant#Responses) API
+pub type UpdateVariantRes = Variant;
+
+impl CfReqMeta for UpdateVariant {
+    const METHOD: Method = Method::PATCH;
+    type Response = UpdateVariantRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        variant_from_body(&body)
+    }
+}
+
+impl CfReqAuth for UpdateVariant {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/images/v1/variants/{}",
+            self.identifier
+        )
+    }
+}
+
+/// [Delete a variant](https://developers.cloudflare.com/api/operations/cloudflare-images-variants-delete-a-variant) API
+#[derive(Serialize)]
+pub struct DeleteVariant {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl DeleteVariant {
+    /// Delete a variant
+    /// identifier is the variant's name
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete a variant](https://developers.cloudflare.com/api/operations/cloudflare-images-variants-delete-a-variant#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteVariantRes;
+
+impl CfReqMeta for DeleteVariant {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteVariantRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty object
+        Ok(DeleteVariantRes)
+    }
+}
+
+impl CfReqAuth for DeleteVariant {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/images/v1/variants/{}",
+            self.identifier
+        )
+    }
+}
+
+/// A key used to sign image delivery URLs
+#[derive(Serialize, Deserialize)]
+pub struct ImagesKey {
+    /// Name of the key
+    pub name: String,
+    /// Secret value of the key
+    pub value: String,
+}
+
+/// [List signing keys](https://developers.cloudflare.com/api/operations/cloudflare-images-keys-list-signing-keys) API
+#[derive(Serialize, Default)]
+pub struct GetImagesKeys {}
+
+/// Success response from the [List signing keys](https://developers.cloudflare.com/api/operations/cloudflare-images-keys-list-signing-keys#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct GetImagesKeysRes {
+    /// Signing keys of the account
+    pub keys: Vec<ImagesKey>,
+}
+
+impl CfReqMeta for GetImagesKeys {
+    const METHOD: Method = Method::GET;
+    type Response = GetImagesKeysRes;
+}
+
+impl CfReqAuth for GetImagesKeys {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v1/keys")
+    }
+}
+
+/// Sign an image delivery URL for images that require signed URLs, without calling the API
+/// `key` is the `value` of an [ImagesKey], `url_or_path` is either a full delivery URL
+/// (`https://imagedelivery.net/<hash>/<id>/<variant>`) or just its path.
+/// Returns `url_or_path` with the `exp` and `sig` query parameters appended
+#[cfg(feature = "images-signing")]
+pub fn sign_delivery_url(key: &str, url_or_path: &str, expiry: SystemTime) -> Result<String> {
+    use hmac::{Hmac, Mac};
+    use reqwest::Url;
+    use std::{fmt::Write, time::UNIX_EPOCH};
+
+    let is_path = url_or_path.starts_with('/');
+    let mut url = if is_path {
+        Url::parse("https://imagedelivery.net")?.join(url_or_path)?
+    } else {
+        Url::parse(url_or_path)?
+    };
+    let exp = expiry
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    url.query_pairs_mut().append_pair("exp", &exp.to_string());
+
+    // cloudflare signs the path along with the query string
+    let to_sign = format!("{}?{}", url.path(), url.query().unwrap_or_default());
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(key.as_bytes())
+        .map_err(|_| Error::InvalidRequest("invalid images signing key".into()))?;
+    mac.update(to_sign.as_bytes());
+    let sig = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .fold(String::new(), |mut sig, byte| {
+            _ = write!(sig, "{byte:02x}");
+            sig
+        });
+    url.query_pairs_mut().append_pair("sig", &sig);
+
+    if is_path {
+        Ok(format!(
+            "{}?{}",
+            url.path(),
+            url.query().unwrap_or_default()
+        ))
+    } else {
+        Ok(url.into())
+    }
+}
+
+/// Image counts of an account
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ImagesCount {
+    /// Number of images stored
+    pub current: u64,
+    /// Maximum number of images allowed by the plan
+    pub allowed: u64,
+}
+
+/// [Images usage statistics](https://developers.cloudflare.com/api/operations/cloudflare-images-images-usage-statistics) API
+#[derive(Serialize, Default)]
+pub struct ImagesStats {}
+
+/// Success response from the [Images usage statistics](https://developers.cloudflare.com/api/operations/cloudflare-images-images-usage-statistics#Responses) API
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ImagesStatsRes {
+    /// Image counts
+    pub count: ImagesCount,
+}
+
+impl CfReqMeta for ImagesStats {
+    const METHOD: Method = Method::GET;
+    type Response = ImagesStatsRes;
+}
+
+impl CfReqAuth for ImagesStats {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/images/v1/stats")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_stats() {
+        let body = br#"{
+            "result": {"count": {"current": 1342, "allowed": 100000}},
+            "success": true,
+            "errors": [],
+            "messages": []
+        }"#;
+        let stats = ImagesStats::deserialize_response(Bytes::from_static(body)).unwrap();
+        assert_eq!(stats.count.current, 1342);
+        assert_eq!(stats.count.allowed, 100000);
+    }
+}
+
+#[cfg(all(test, feature = "images-signing"))]
+mod signing_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::*;
+
+    // key, image and expected signatures follow the "Serve private images" example of
+    // https://developers.cloudflare.com/images/manage-images/serve-images/serve-private-images/
+    // which signs `pathname + "?" + searchParams` (with `exp` set last) using HMAC-SHA256
+    const KEY: &str = "YOUR_KEY_FROM_IMAGES_DASHBOARD";
+    const IMAGE: &str = "/ZWd9g1K7eljCn_KDTu_MWA/083eb7b2-5392-4565-b69e-aff66acddd00";
+
+    #[test]
+    fn sign_delivery_url_vectors() {
+        let expiry = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let sig = "9b0e279bb4021e2868fc29908643bd2863b7ce5492771aa27fdb3eab5abdc57c";
+        assert_eq!(
+            sign_delivery_url(
+                KEY,
+                &format!("https://imagedelivery.net{IMAGE}/public"),
+                expiry
+            )
+            .unwrap(),
+            format!("https://imagedelivery.net{IMAGE}/public?exp=1700000000&sig={sig}")
+        );
+        assert_eq!(
+            sign_delivery_url(KEY, &format!("{IMAGE}/public"), expiry).unwrap(),
+            format!("{IMAGE}/public?exp=1700000000&sig={sig}")
+        );
+
+        // existing query parameters are part of the signed string
+        let sig = "f3c0c73f0ff2342c280d032076ee80d9558d3f4b9f51c86b1873f3bd6f992a3b";
+        assert_eq!(
+            sign_delivery_url(KEY, &format!("{IMAGE}/w=400?v=2"), expiry).unwrap(),
+            format!("{IMAGE}/w=400?v=2&exp=1700000000&sig={sig}")
+        );
+    }
+}
@@ -1,17 +1,51 @@
 //! Cloudflare KV API
 //! See [Cloudflare Docs](https://developers.cloudflare.com/kv/)
 
-use std::marker::PhantomData;
+use std::{
+    collections::HashMap,
+    marker::PhantomData,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use bytes::Bytes;
-use reqwest::{multipart::Form, Method};
+use reqwest::{multipart::Form, Method, StatusCode};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
-    request::{CfReqAuth, CfReqMeta},
+    request::{encode_segment, not_found_error, CfReqAuth, CfReqMeta, Optional},
+    CfApiErr, CfSuccessRes, CfSuccessResWithInfo, CloudflareAuth, CursorInfo, Error, PageInfo,
     Result,
 };
 
+/// Maximum number of keys in a single bulk write or delete request
+pub const BULK_LIMIT: usize = 10_000;
+
+/// Maximum number of keys in a single [BulkGetKV] request
+pub const BULK_GET_LIMIT: usize = 100;
+
+/// Minimum TTL accepted by Cloudflare for expiring keys
+pub const MIN_EXPIRATION_TTL: Duration = Duration::from_secs(60);
+
+fn check_expiration_ttl(ttl: Duration) -> Result<u64> {
+    if ttl < MIN_EXPIRATION_TTL {
+        return Err(Error::InvalidRequest(format!(
+            "expiration ttl must be at least {}s, got {}s",
+            MIN_EXPIRATION_TTL.as_secs(),
+            ttl.as_secs()
+        )));
+    }
+    Ok(ttl.as_secs())
+}
+
+fn check_bulk_limit(op: &str, len: usize, limit: usize) -> Result<()> {
+    if len > limit {
+        return Err(Error::InvalidRequest(format!(
+            "bulk {op} supports at most {limit} keys, got {len}"
+        )));
+    }
+    Ok(())
+}
+
 /// Namespace helper
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct KvNamespace {
@@ -25,13 +59,37 @@ impl KvNamespace {
         Self { namespace_id }
     }
 
+    /// Find a namespace by its title, going through all pages of [ListNamespaces]
+    pub async fn find_by_title(client: &CloudflareAuth, title: &str) -> Result<Option<Self>> {
+        const PER_PAGE: u32 = 100;
+        let mut page = 1;
+        loop {
+            let res = client
+                .send_auth(ListNamespaces::default().page(page).per_page(PER_PAGE))
+                .await?;
+            if let Some(ns) = res.namespaces.into_iter().find(|ns| ns.title == title) {
+                return Ok(Some(Self::new(ns.id)));
+            }
+            if res.page_info.count < PER_PAGE || page * PER_PAGE >= res.page_info.total_count {
+                return Ok(None);
+            }
+            page += 1;
+        }
+    }
+
+    /// Namespace ID
+    pub fn id(&self) -> &str {
+        &self.namespace_id
+    }
+
     /// Write a KV pair
     pub fn write_kv(&self, key_name: String) -> WriteKVWithMeta {
         WriteKVWithMeta {
             namespace_id: self.namespace_id.clone(),
             key_name,
-            metadata: String::new(),
+            metadata: None,
             value: String::new(),
+            expiration: None,
         }
     }
 
@@ -43,6 +101,32 @@ impl KvNamespace {
         }
     }
 
+    /// Read a KV pair, returning `None` if the key does not exist
+    pub async fn try_read_kv(
+        &self,
+        client: &CloudflareAuth,
+        key_name: String,
+    ) -> Result<Option<ReadKVRes>> {
+        client.send_auth(Optional(self.read_kv(key_name))).await
+    }
+
+    /// Read a KV pair as raw bytes, for values that are not valid UTF-8
+    pub fn read_kv_raw(&self, key_name: String) -> ReadKVBytes {
+        ReadKVBytes {
+            namespace_id: self.namespace_id.clone(),
+            key_name,
+        }
+    }
+
+    /// Read a KV pair and deserialize the JSON value
+    pub fn read_kv_json<T: DeserializeOwned>(&self, key_name: String) -> ReadKVJson<T> {
+        ReadKVJson {
+            namespace_id: self.namespace_id.clone(),
+            key_name,
+            _value: PhantomData,
+        }
+    }
+
     /// Read the metadata for a KV pair
     pub fn read_kv_metadata<Meta: DeserializeOwned>(&self, key_name: String) -> ReadKVMeta<Meta> {
         ReadKVMeta {
@@ -51,21 +135,131 @@ impl KvNamespace {
             _meta: PhantomData,
         }
     }
+
+    /// Read the metadata for a KV pair, returning `None` if the key does not exist
+    pub async fn try_read_kv_metadata<Meta: DeserializeOwned + Send>(
+        &self,
+        client: &CloudflareAuth,
+        key_name: String,
+    ) -> Result<Option<ReadKVMetaRes<Meta>>> {
+        client
+            .send_auth(Optional(self.read_kv_metadata(key_name)))
+            .await
+    }
+
+    /// Delete a KV pair
+    pub fn delete_kv(&self, key_name: String) -> DeleteKV {
+        DeleteKV {
+            namespace_id: self.namespace_id.clone(),
+            key_name,
+        }
+    }
+
+    /// Write multiple KV pairs in a single request
+    /// Fails if there are more than [BULK_LIMIT] entries, see [Self::bulk_write_kv_chunked]
+    pub fn bulk_write_kv(
+        &self,
+        entries: impl IntoIterator<Item = BulkWriteEntry>,
+    ) -> Result<BulkWriteKV> {
+        let entries: Vec<_> = entries.into_iter().collect();
+        check_bulk_limit("write", entries.len(), BULK_LIMIT)?;
+        Ok(BulkWriteKV {
+            namespace_id: self.namespace_id.clone(),
+            entries,
+        })
+    }
+
+    /// Write multiple KV pairs, split into as many requests as required by [BULK_LIMIT]
+    pub fn bulk_write_kv_chunked(
+        &self,
+        entries: impl IntoIterator<Item = BulkWriteEntry>,
+    ) -> Vec<BulkWriteKV> {
+        let mut reqs = Vec::new();
+        let mut entries = entries.into_iter().peekable();
+        while entries.peek().is_some() {
+            reqs.push(BulkWriteKV {
+                namespace_id: self.namespace_id.clone(),
+                entries: entries.by_ref().take(BULK_LIMIT).collect(),
+            });
+        }
+        reqs
+    }
+
+    /// Delete multiple KV pairs in a single request
+    /// Fails if there are more than [BULK_LIMIT] keys
+    pub fn bulk_delete_kv(
+        &self,
+        key_names: impl IntoIterator<Item = String>,
+    ) -> Result<BulkDeleteKV> {
+        let key_names: Vec<_> = key_names.into_iter().collect();
+        check_bulk_limit("delete", key_names.len(), BULK_LIMIT)?;
+        Ok(BulkDeleteKV {
+            namespace_id: self.namespace_id.clone(),
+            key_names,
+        })
+    }
+
+    /// Read multiple KV pairs in a single request
+    /// Fails if there are more than [BULK_GET_LIMIT] keys
+    pub fn bulk_get_kv(&self, key_names: impl IntoIterator<Item = String>) -> Result<BulkGetKV> {
+        let keys: Vec<_> = key_names.into_iter().collect();
+        check_bulk_limit("get", keys.len(), BULK_GET_LIMIT)?;
+        Ok(BulkGetKV {
+            namespace_id: self.namespace_id.clone(),
+            keys,
+            with_metadata: false,
+        })
+    }
+
+    /// List the keys in the namespace
+    pub fn list_keys(&self) -> ListKeys {
+        ListKeys {
+            namespace_id: self.namespace_id.clone(),
+            limit: None,
+            prefix: None,
+            cursor: None,
+        }
+    }
 }
 
 /// [Write KV pair with metadata](https://developers.cloudflare.com/api/operations/workers-kv-namespace-write-key-value-pair-with-metadata) API
-pub struct WriteKVWithMeta {
+/// Metadata is serialized when the request is sent
+pub struct WriteKVWithMeta<M = ()> {
     namespace_id: String,
     key_name: String,
-    metadata: String,
+    metadata: Option<M>,
     value: String,
+    expiration: Option<Expiration>,
+}
+
+enum Expiration {
+    /// Unix timestamp
+    At(u64),
+    /// Seconds from now
+    Ttl(u64),
 }
 
-impl WriteKVWithMeta {
+impl<M> WriteKVWithMeta<M> {
     /// Metadata for the KV pair
-    pub fn metadata(mut self, metadata: &impl Serialize) -> Result<Self> {
-        self.metadata = serde_json::to_string(&metadata)?;
-        Ok(self)
+    pub fn with_metadata<N: Serialize>(self, metadata: N) -> WriteKVWithMeta<N> {
+        WriteKVWithMeta {
+            namespace_id: self.namespace_id,
+            key_name: self.key_name,
+            metadata: Some(metadata),
+            value: self.value,
+            expiration: self.expiration,
+        }
+    }
+
+    /// Metadata for the KV pair, serialized eagerly
+    /// Prefer [Self::with_metadata]
+    pub fn metadata(self, metadata: &impl Serialize) -> Result<WriteKVWithMeta<serde_json::Value>> {
+        Ok(self.with_metadata(serde_json::to_value(metadata)?))
+    }
+
+    /// Current metadata for the KV pair
+    pub fn get_metadata(&self) -> Option<&M> {
+        self.metadata.as_ref()
     }
 
     /// Value corresponding to the key
@@ -73,35 +267,64 @@ impl WriteKVWithMeta {
         self.value = value;
         self
     }
+
+    /// Expire the KV pair after the given duration
+    /// Must be at least [MIN_EXPIRATION_TTL], overrides [Self::expiration_at]
+    pub fn expiration_ttl(mut self, ttl: Duration) -> Result<Self> {
+        self.expiration = Some(Expiration::Ttl(check_expiration_ttl(ttl)?));
+        Ok(self)
+    }
+
+    /// Expire the KV pair at the given time, overrides [Self::expiration_ttl]
+    pub fn expiration_at(mut self, at: SystemTime) -> Result<Self> {
+        let at = at
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| Error::InvalidRequest("expiration is before the unix epoch".into()))?;
+        self.expiration = Some(Expiration::At(at.as_secs()));
+        Ok(self)
+    }
 }
 
 /// Success response from the [Write KV pair with metadata](https://developers.cloudflare.com/api/operations/workers-kv-namespace-write-key-value-pair-with-metadata#response-body) API
 #[derive(Serialize, Deserialize)]
 pub struct WriteKVWithMetaRes;
 
-impl CfReqMeta for WriteKVWithMeta {
+impl<M: Send> CfReqMeta for WriteKVWithMeta<M> {
     const METHOD: Method = Method::PUT;
     type Response = WriteKVWithMetaRes;
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        match self.expiration {
+            Some(Expiration::At(at)) => vec![("expiration", at.to_string())],
+            Some(Expiration::Ttl(ttl)) => vec![("expiration_ttl", ttl.to_string())],
+            None => Vec::new(),
+        }
+    }
 }
 
-impl CfReqAuth for WriteKVWithMeta {
+impl<M: Send> CfReqAuth for WriteKVWithMeta<M> {
     type Url = String;
 
     fn path(&self, account_id: &str) -> String {
         format!(
             "accounts/{account_id}/storage/kv/namespaces/{namespace_id}/values/{key_name}",
-            namespace_id = self.namespace_id,
-            key_name = self.key_name,
+            namespace_id = encode_segment(&self.namespace_id),
+            key_name = encode_segment(&self.key_name),
             account_id = account_id
         )
     }
 }
 
-impl From<WriteKVWithMeta> for Form {
-    fn from(value: WriteKVWithMeta) -> Self {
-        Form::new()
-            .text("metadata", value.metadata)
-            .text("value", value.value)
+impl<M: Serialize> TryFrom<WriteKVWithMeta<M>> for Form {
+    type Error = Error;
+
+    fn try_from(value: WriteKVWithMeta<M>) -> Result<Self> {
+        let form = Form::new().text("value", value.value);
+        // cloudflare rejects empty metadata
+        let Some(metadata) = value.metadata else {
+            return Ok(form);
+        };
+        Ok(form.text("metadata", serde_json::to_string(&metadata)?))
     }
 }
 
@@ -132,13 +355,90 @@ impl CfReqAuth for ReadKV {
     fn path(&self, account_id: &str) -> String {
         format!(
             "accounts/{account_id}/storage/kv/namespaces/{namespace_id}/values/{key_name}",
-            namespace_id = self.namespace_id,
-            key_name = self.key_name,
+            namespace_id = encode_segment(&self.namespace_id),
+            key_name = encode_segment(&self.key_name),
             account_id = account_id
         )
     }
 }
 
+/// [Read KV pair](https://developers.cloudflare.com/api/operations/workers-kv-namespace-read-key-value-pair) API
+/// returning the value as is
+#[derive(Serialize)]
+pub struct ReadKVBytes {
+    #[serde(skip)]
+    namespace_id: String,
+    #[serde(skip)]
+    key_name: String,
+}
+
+/// Raw value corresponding to the key
+pub type ReadKVBytesRes = Bytes;
+
+impl CfReqMeta for ReadKVBytes {
+    const METHOD: Method = Method::GET;
+    type Response = ReadKVBytesRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        Ok(body)
+    }
+}
+
+impl CfReqAuth for ReadKVBytes {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/storage/kv/namespaces/{namespace_id}/values/{key_name}",
+            namespace_id = encode_segment(&self.namespace_id),
+            key_name = encode_segment(&self.key_name),
+        )
+    }
+}
+
+/// [Read KV pair](https://developers.cloudflare.com/api/operations/workers-kv-namespace-read-key-value-pair) API
+/// deserializing the value as JSON
+#[derive(Serialize)]
+pub struct ReadKVJson<T> {
+    #[serde(skip)]
+    namespace_id: String,
+    #[serde(skip)]
+    key_name: String,
+    #[serde(skip)]
+    _value: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned + Send> CfReqMeta for ReadKVJson<T> {
+    const METHOD: Method = Method::GET;
+    type Response = T;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    fn deserialize_err(&self, err: Error) -> Error {
+        match err {
+            Error::Json(source) => Error::KvValue {
+                key: self.key_name.clone(),
+                source,
+            },
+            err => err,
+        }
+    }
+}
+
+impl<T: DeserializeOwned + Send> CfReqAuth for ReadKVJson<T> {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/storage/kv/namespaces/{namespace_id}/values/{key_name}",
+            namespace_id = encode_segment(&self.namespace_id),
+            key_name = encode_segment(&self.key_name),
+        )
+    }
+}
+
 /// [Read KV pair metadata](https://developers.cloudflare.com/api/operations/workers-kv-namespace-read-the-metadata-for-a-key)
 #[derive(Serialize)]
 pub struct ReadKVMeta<Meta> {
@@ -165,9 +465,539 @@ impl<Meta: DeserializeOwned + Send> CfReqAuth for ReadKVMeta<Meta> {
     fn path(&self, account_id: &str) -> String {
         format!(
             "accounts/{account_id}/storage/kv/namespaces/{namespace_id}/metadata/{key_name}",
-            namespace_id = self.namespace_id,
-            key_name = self.key_name,
+            namespace_id = encode_segment(&self.namespace_id),
+            key_name = encode_segment(&self.key_name),
             account_id = account_id
         )
     }
 }
+
+/// [Delete KV pair](https://developers.cloudflare.com/api/operations/workers-kv-namespace-delete-key-value-pair) API
+/// A missing key results in an error for which [crate::Error::is_not_found] is true
+#[derive(Serialize)]
+pub struct DeleteKV {
+    #[serde(skip)]
+    namespace_id: String,
+    #[serde(skip)]
+    key_name: String,
+}
+
+/// Success response from the [Delete KV pair](https://developers.cloudflare.com/api/operations/workers-kv-namespace-delete-key-value-pair#response-body) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteKVRes;
+
+impl CfReqMeta for DeleteKV {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteKVRes;
+
+    fn handle_error(status: StatusCode, errors: Vec<CfApiErr>) -> Result<Self::Response> {
+        not_found_error(status, errors)
+    }
+}
+
+impl CfReqAuth for DeleteKV {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/storage/kv/namespaces/{namespace_id}/values/{key_name}",
+            namespace_id = encode_segment(&self.namespace_id),
+            key_name = encode_segment(&self.key_name),
+        )
+    }
+}
+
+/// An entry of a [BulkWriteKV] request
+#[derive(Serialize)]
+pub struct BulkWriteEntry {
+    key: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiration_ttl: Option<u64>,
+    base64: bool,
+}
+
+impl BulkWriteEntry {
+    /// Create a new entry with the given key and value
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+            metadata: None,
+            expiration_ttl: None,
+            base64: false,
+        }
+    }
+
+    /// Metadata for the KV pair
+    pub fn metadata(mut self, metadata: &impl Serialize) -> Result<Self> {
+        self.metadata = Some(serde_json::to_value(metadata)?);
+        Ok(self)
+    }
+
+    /// Expire the KV pair after the given duration
+    /// Must be at least [MIN_EXPIRATION_TTL]
+    pub fn expiration_ttl(mut self, ttl: Duration) -> Result<Self> {
+        self.expiration_ttl = Some(check_expiration_ttl(ttl)?);
+        Ok(self)
+    }
+
+    /// Whether the value is base64 encoded binary data
+    pub fn base64(mut self, base64: bool) -> Self {
+        self.base64 = base64;
+        self
+    }
+}
+
+/// [Write multiple KV pairs](https://developers.cloudflare.com/api/operations/workers-kv-namespace-write-multiple-key-value-pairs) API
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct BulkWriteKV {
+    #[serde(skip)]
+    namespace_id: String,
+    entries: Vec<BulkWriteEntry>,
+}
+
+/// Success response from the bulk KV APIs
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct BulkKVRes {
+    /// Number of keys successfully written or deleted
+    pub successful_key_count: u64,
+    /// Keys that could not be written or deleted
+    pub unsuccessful_keys: Vec<String>,
+}
+
+impl BulkKVRes {
+    fn deserialize(body: Bytes) -> Result<Self> {
+        // older versions of the API respond with a null result
+        let res: CfSuccessRes<Option<BulkKVRes>> = serde_json::from_slice(&body)?;
+        Ok(res.result.unwrap_or_default())
+    }
+}
+
+/// Success response from the [Write multiple KV pairs](https://developers.cloudflare.com/api/operations/workers-kv-namespace-write-multiple-key-value-pairs#response-body) API
+pub type BulkWriteKVRes = BulkKVRes;
+
+impl CfReqMeta for BulkWriteKV {
+    const METHOD: Method = Method::PUT;
+    type Response = BulkWriteKVRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        BulkKVRes::deserialize(body)
+    }
+}
+
+impl CfReqAuth for BulkWriteKV {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/storage/kv/namespaces/{namespace_id}/bulk",
+            namespace_id = encode_segment(&self.namespace_id),
+        )
+    }
+}
+
+/// [Delete multiple KV pairs](https://developers.cloudflare.com/api/operations/workers-kv-namespace-delete-multiple-key-value-pairs) API
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct BulkDeleteKV {
+    #[serde(skip)]
+    namespace_id: String,
+    key_names: Vec<String>,
+}
+
+/// Success response from the [Delete multiple KV pairs](https://developers.cloudflare.com/api/operations/workers-kv-namespace-delete-multiple-key-value-pairs#response-body) API
+pub type BulkDeleteKVRes = BulkKVRes;
+
+impl CfReqMeta for BulkDeleteKV {
+    const METHOD: Method = Method::DELETE;
+    type Response = BulkDeleteKVRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        BulkKVRes::deserialize(body)
+    }
+}
+
+impl CfReqAuth for BulkDeleteKV {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/storage/kv/namespaces/{namespace_id}/bulk",
+            namespace_id = encode_segment(&self.namespace_id),
+        )
+    }
+}
+
+/// [Get multiple KV pairs](https://developers.cloudflare.com/api/operations/workers-kv-namespace-get-multiple-key-value-pairs) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkGetKV {
+    #[serde(skip)]
+    namespace_id: String,
+    keys: Vec<String>,
+    with_metadata: bool,
+}
+
+impl BulkGetKV {
+    /// Also return the metadata and expiration of each KV pair
+    pub fn with_metadata(mut self, with_metadata: bool) -> Self {
+        self.with_metadata = with_metadata;
+        self
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BulkGetValueRepr {
+    Plain(String),
+    WithMeta {
+        value: String,
+        metadata: Option<serde_json::Value>,
+        expiration: Option<u64>,
+    },
+}
+
+/// A value returned by [BulkGetKV]
+#[derive(Serialize, Deserialize)]
+#[serde(from = "BulkGetValueRepr")]
+pub struct BulkGetValue {
+    /// Value corresponding to the key
+    pub value: String,
+    /// Metadata for the KV pair, only present if requested via [BulkGetKV::with_metadata]
+    pub metadata: Option<serde_json::Value>,
+    /// Unix timestamp at which the key expires, only present if requested via [BulkGetKV::with_metadata]
+    pub expiration: Option<u64>,
+}
+
+impl From<BulkGetValueRepr> for BulkGetValue {
+    fn from(value: BulkGetValueRepr) -> Self {
+        match value {
+            BulkGetValueRepr::Plain(value) => Self {
+                value,
+                metadata: None,
+                expiration: None,
+            },
+            BulkGetValueRepr::WithMeta {
+                value,
+                metadata,
+                expiration,
+            } => Self {
+                value,
+                metadata,
+                expiration,
+            },
+        }
+    }
+}
+
+/// Success response from the [Get multiple KV pairs](https://developers.cloudflare.com/api/operations/workers-kv-namespace-get-multiple-key-value-pairs#response-body) API
+#[derive(Serialize, Deserialize)]
+pub struct BulkGetKVRes {
+    /// Values by key, `None` if the key does not exist
+    pub values: HashMap<String, Option<BulkGetValue>>,
+}
+
+impl CfReqMeta for BulkGetKV {
+    const METHOD: Method = Method::POST;
+    type Response = BulkGetKVRes;
+}
+
+impl CfReqAuth for BulkGetKV {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/storage/kv/namespaces/{namespace_id}/bulk/get",
+            namespace_id = encode_segment(&self.namespace_id),
+        )
+    }
+}
+
+/// [List a Namespace's Keys](https://developers.cloudflare.com/api/operations/workers-kv-namespace-list-a-namespace'-s-keys) API
+#[derive(Serialize)]
+pub struct ListKeys {
+    #[serde(skip)]
+    namespace_id: String,
+    limit: Option<u32>,
+    prefix: Option<String>,
+    cursor: Option<String>,
+}
+
+impl ListKeys {
+    /// Maximum number of keys to return (Cloudflare defaults to 1000)
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only return keys starting with the given prefix
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Continue listing from the cursor returned by a previous [ListKeysRes]
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+/// A key in a KV namespace
+#[derive(Serialize, Deserialize)]
+pub struct KvKey {
+    /// Name of the key
+    pub name: String,
+    /// Unix timestamp at which the key expires
+    pub expiration: Option<u64>,
+    /// Metadata attached to the key
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// Success response from the [List a Namespace's Keys](https://developers.cloudflare.com/api/operations/workers-kv-namespace-list-a-namespace'-s-keys#response-body) API
+#[derive(Serialize, Deserialize)]
+pub struct ListKeysRes {
+    /// Keys in this page
+    pub keys: Vec<KvKey>,
+    /// Cursor for fetching the next page, `None` if this is the last page
+    pub cursor: Option<String>,
+}
+
+impl CfReqMeta for ListKeys {
+    const METHOD: Method = Method::GET;
+    type Response = ListKeysRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (keys, info): (_, CursorInfo) = CfSuccessResWithInfo::from_body(&body)?;
+        let cursor = info.cursor.filter(|cursor| !cursor.is_empty());
+        Ok(ListKeysRes { keys, cursor })
+    }
+}
+
+impl CfReqAuth for ListKeys {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/storage/kv/namespaces/{namespace_id}/keys",
+            namespace_id = encode_segment(&self.namespace_id),
+        )
+    }
+}
+
+/// Ordering for [ListNamespaces]
+#[derive(Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum NamespaceOrder {
+    /// Order by namespace id
+    Id,
+    /// Order by namespace title
+    Title,
+}
+
+/// [List Namespaces](https://developers.cloudflare.com/api/operations/workers-kv-namespace-list-namespaces) API
+#[derive(Serialize, Default)]
+pub struct ListNamespaces {
+    page: Option<u32>,
+    per_page: Option<u32>,
+    order: Option<NamespaceOrder>,
+}
+
+impl ListNamespaces {
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of namespaces per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Field to order the namespaces by
+    pub fn order(mut self, order: NamespaceOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+}
+
+/// A KV namespace
+#[derive(Serialize, Deserialize)]
+pub struct Namespace {
+    /// Namespace ID
+    pub id: String,
+    /// Human readable name of the namespace
+    pub title: String,
+    /// Whether the namespace supports url encoded keys
+    pub supports_url_encoding: Option<bool>,
+}
+
+impl From<Namespace> for KvNamespace {
+    fn from(value: Namespace) -> Self {
+        Self::new(value.id)
+    }
+}
+
+/// Success response from the [List Namespaces](https://developers.cloudflare.com/api/operations/workers-kv-namespace-list-namespaces#response-body) API
+#[derive(Serialize, Deserialize)]
+pub struct ListNamespacesRes {
+    /// Namespaces in this page
+    pub namespaces: Vec<Namespace>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListNamespaces {
+    const METHOD: Method = Method::GET;
+    type Response = ListNamespacesRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (namespaces, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListNamespacesRes {
+            namespaces,
+            page_info,
+        })
+    }
+}
+
+impl CfReqAuth for ListNamespaces {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/storage/kv/namespaces")
+    }
+}
+
+/// [Create a Namespace](https://developers.cloudflare.com/api/operations/workers-kv-namespace-create-a-namespace) API
+#[derive(Serialize)]
+pub struct CreateNamespace {
+    title: String,
+}
+
+impl CreateNamespace {
+    /// Create a namespace with the given title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+        }
+    }
+}
+
+/// Success response from the [Create a Namespace](https://developers.cloudflare.com/api/operations/workers-kv-namespace-create-a-namespace#response-body) API
+pub type CreateNamespaceRes = Namespace;
+
+impl CfReqMeta for CreateNamespace {
+    const METHOD: Method = Method::POST;
+    type Response = CreateNamespaceRes;
+}
+
+impl CfReqAuth for CreateNamespace {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/storage/kv/namespaces")
+    }
+}
+
+/// [Remove a Namespace](https://developers.cloudflare.com/api/operations/workers-kv-namespace-remove-a-namespace) API
+#[derive(Serialize)]
+pub struct DeleteNamespace {
+    #[serde(skip)]
+    namespace_id: String,
+}
+
+impl DeleteNamespace {
+    /// Delete the namespace with the given id
+    pub fn new(namespace_id: impl Into<String>) -> Self {
+        Self {
+            namespace_id: namespace_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Remove a Namespace](https://developers.cloudflare.com/api/operations/workers-kv-namespace-remove-a-namespace#response-body) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteNamespaceRes;
+
+impl CfReqMeta for DeleteNamespace {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteNamespaceRes;
+}
+
+impl CfReqAuth for DeleteNamespace {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/storage/kv/namespaces/{namespace_id}",
+            namespace_id = encode_segment(&self.namespace_id),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use percent_encoding::percent_decode_str;
+
+    use super::*;
+    use crate::Credentials;
+
+    fn client() -> CloudflareAuth {
+        CloudflareAuth::new(Credentials {
+            token: "token".into(),
+            account_id: "account".into(),
+        })
+    }
+
+    fn value_url(key: &str) -> String {
+        let ns = KvNamespace::new("ns".into());
+        client()
+            .build_url(&ns.read_kv(key.into()))
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn key_path_is_encoded() {
+        let prefix = "https://api.cloudflare.com/client/v4/accounts/account/storage/kv/namespaces/ns/values/";
+        assert_eq!(value_url("user/123"), format!("{prefix}user%2F123"));
+        assert_eq!(value_url("a b"), format!("{prefix}a%20b"));
+        assert_eq!(value_url("a?b#c"), format!("{prefix}a%3Fb%23c"));
+        assert_eq!(
+            value_url("ключ"),
+            format!("{prefix}%D0%BA%D0%BB%D1%8E%D1%87")
+        );
+        assert_eq!(value_url("v1.json"), format!("{prefix}v1.json"));
+    }
+
+    #[test]
+    fn key_round_trips_through_write_and_read() {
+        let client = client();
+        let ns = KvNamespace::new("ns".into());
+        for key in ["user/123", "a b", "ключ 🔑", "100%", "..."] {
+            let write = client.build_url(&ns.write_kv(key.into())).unwrap();
+            let read = client.build_url(&ns.read_kv(key.into())).unwrap();
+            assert_eq!(write, read);
+            let segment = read.path_segments().unwrap().next_back().unwrap();
+            assert_eq!(percent_decode_str(segment).decode_utf8().unwrap(), key);
+        }
+    }
+
+    #[test]
+    fn dot_segment_keys_are_rejected() {
+        let client = client();
+        let ns = KvNamespace::new("ns".into());
+        for key in [".", ".."] {
+            let err = client.build_url(&ns.read_kv(key.into())).unwrap_err();
+            assert!(matches!(err, Error::InvalidRequest(_)), "{err:?}");
+            assert!(client.build_url(&ns.write_kv(key.into())).is_err());
+        }
+    }
+}
@@ -0,0 +1,1194 @@
+//! Cloudflare Load Balancing related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/load-balancing/)
+//! Pools and monitors are account scoped, load balancers are zone scoped
+//! and sent with [crate::CloudflareZone::send_zone]
+use std::collections::HashMap;
+
+use crate::{CfReqAuth, CfReqMeta, CfReqZone, Error, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// An origin server of a [Pool]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Origin {
+    /// Name of the origin
+    pub name: String,
+    /// IP address or hostname of the origin
+    pub address: String,
+    /// Whether the origin receives traffic
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Share of the pool's traffic, between 0 and 1
+    /// Private so that it can only be set through the validating [Self::weight]
+    #[serde(default = "default_weight")]
+    weight: f64,
+    /// Request headers overridden for this origin, only `Host` is supported
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub header: HashMap<String, Vec<String>>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+impl Origin {
+    /// Enabled origin `name` at `address`, with a weight of 1
+    pub fn new(name: impl Into<String>, address: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            address: address.into(),
+            enabled: true,
+            weight: 1.0,
+            header: HashMap::new(),
+        }
+    }
+
+    /// Share of the pool's traffic, must be between 0 and 1
+    pub fn weight(mut self, weight: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&weight) {
+            return Err(Error::InvalidRequest(format!(
+                "origin weight must be between 0 and 1, got {weight}"
+            )));
+        }
+        self.weight = weight;
+        Ok(self)
+    }
+
+    /// Share of the pool's traffic, between 0 and 1
+    pub fn get_weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Whether the origin receives traffic
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// `Host` header sent to the origin
+    pub fn host(mut self, host: impl Into<String>) -> Self {
+        self.header.insert("Host".to_string(), vec![host.into()]);
+        self
+    }
+
+    /// `Host` header sent to the origin, if overridden
+    pub fn host_header(&self) -> Option<&str> {
+        self.header.get("Host")?.first().map(String::as_str)
+    }
+}
+
+/// A pool of origins
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Pool {
+    /// Pool ID
+    pub id: String,
+    /// Name of the pool
+    pub name: String,
+    /// Description of the pool
+    #[serde(default)]
+    pub description: String,
+    /// Whether the pool receives traffic
+    pub enabled: bool,
+    /// Minimum number of healthy origins for the pool to be healthy
+    pub minimum_origins: u32,
+    /// ID of the [Monitor] checking the origins
+    pub monitor: Option<String>,
+    /// Origins of the pool
+    pub origins: Vec<Origin>,
+    /// Email notified of health changes
+    pub notification_email: Option<String>,
+    /// Regions health checks are sent from
+    pub check_regions: Option<Vec<String>>,
+    /// Creation time
+    pub created_on: String,
+    /// Last update time
+    pub modified_on: String,
+}
+
+/// Pools can be given wherever a pool ID is expected
+impl AsRef<str> for Pool {
+    fn as_ref(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Settings of a pool, used by [CreatePool] and [PatchPool]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct PoolParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    origins: Option<Vec<Origin>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    minimum_origins: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    monitor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notification_email: Option<String>,
+}
+
+impl PoolParams {
+    /// Name of the pool
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Add an origin to the pool
+    pub fn origin(mut self, origin: Origin) -> Self {
+        self.origins.get_or_insert_with(Vec::new).push(origin);
+        self
+    }
+
+    /// Description of the pool
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the pool receives traffic
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Minimum number of healthy origins for the pool to be healthy
+    pub fn minimum_origins(mut self, minimum_origins: u32) -> Self {
+        self.minimum_origins = Some(minimum_origins);
+        self
+    }
+
+    /// ID of the [Monitor] checking the origins
+    pub fn monitor(mut self, monitor_id: impl Into<String>) -> Self {
+        self.monitor = Some(monitor_id.into());
+        self
+    }
+
+    /// Email notified of health changes
+    pub fn notification_email(mut self, email: impl Into<String>) -> Self {
+        self.notification_email = Some(email.into());
+        self
+    }
+}
+
+/// [Create Pool](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-create-pool) API
+#[derive(Serialize)]
+pub struct CreatePool {
+    #[serde(flatten)]
+    params: PoolParams,
+}
+
+impl CreatePool {
+    /// Create the pool `name` with `origins`
+    pub fn new(name: impl Into<String>, origins: Vec<Origin>) -> Self {
+        Self {
+            params: PoolParams {
+                name: Some(name.into()),
+                origins: Some(origins),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Create a pool with the given settings, which must include a name and origins
+    pub fn with_params(params: PoolParams) -> Self {
+        Self { params }
+    }
+}
+
+/// Success response from the [Create Pool](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-create-pool#Responses) API
+pub type CreatePoolRes = Pool;
+
+impl CfReqMeta for CreatePool {
+    const METHOD: Method = Method::POST;
+    type Response = CreatePoolRes;
+}
+
+impl CfReqAuth for CreatePool {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/load_balancers/pools")
+    }
+}
+
+/// [List Pools](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-list-pools) API
+#[derive(Serialize, Default)]
+pub struct ListPools {
+    monitor: Option<String>,
+}
+
+impl ListPools {
+    /// Only list pools checked by the monitor `monitor_id`
+    pub fn monitor(mut self, monitor_id: impl Into<String>) -> Self {
+        self.monitor = Some(monitor_id.into());
+        self
+    }
+}
+
+/// Success response from the [List Pools](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-list-pools#Responses) API
+pub type ListPoolsRes = Vec<Pool>;
+
+impl CfReqMeta for ListPools {
+    const METHOD: Method = Method::GET;
+    type Response = ListPoolsRes;
+}
+
+impl CfReqAuth for ListPools {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/load_balancers/pools")
+    }
+}
+
+/// [Pool Details](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-pool-details) API
+#[derive(Serialize)]
+pub struct GetPool {
+    #[serde(skip)]
+    pool_id: String,
+}
+
+impl GetPool {
+    /// Get the pool `pool_id`
+    pub fn new(pool_id: impl Into<String>) -> Self {
+        Self {
+            pool_id: pool_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Pool Details](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-pool-details#Responses) API
+pub type GetPoolRes = Pool;
+
+impl CfReqMeta for GetPool {
+    const METHOD: Method = Method::GET;
+    type Response = GetPoolRes;
+}
+
+impl CfReqAuth for GetPool {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/load_balancers/pools/{}",
+            self.pool_id
+        )
+    }
+}
+
+/// [Patch Pool](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-patch-pool) API
+/// Only the given settings are changed, origins are replaced as a whole
+#[derive(Serialize)]
+pub struct PatchPool {
+    #[serde(skip)]
+    pool_id: String,
+    #[serde(flatten)]
+    params: PoolParams,
+}
+
+impl PatchPool {
+    /// Change the settings of the pool `pool_id`
+    pub fn new(pool_id: impl Into<String>, params: PoolParams) -> Self {
+        Self {
+            pool_id: pool_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Patch Pool](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-patch-pool#Responses) API
+pub type PatchPoolRes = Pool;
+
+impl CfReqMeta for PatchPool {
+    const METHOD: Method = Method::PATCH;
+    type Response = PatchPoolRes;
+}
+
+impl CfReqAuth for PatchPool {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/load_balancers/pools/{}",
+            self.pool_id
+        )
+    }
+}
+
+/// [Delete Pool](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-delete-pool) API
+/// Pools used by a load balancer can't be deleted
+#[derive(Serialize)]
+pub struct DeletePool {
+    #[serde(skip)]
+    pool_id: String,
+}
+
+impl DeletePool {
+    /// Delete the pool `pool_id`
+    pub fn new(pool_id: impl Into<String>) -> Self {
+        Self {
+            pool_id: pool_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeletePool {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqAuth for DeletePool {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/load_balancers/pools/{}",
+            self.pool_id
+        )
+    }
+}
+
+/// Health of an origin as seen from a region
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OriginHealth {
+    /// Whether the origin passed the health checks
+    pub healthy: bool,
+    /// Round trip time of the last check (e.g `66ms`)
+    pub rtt: Option<String>,
+    /// Why the last check failed
+    pub failure_reason: Option<String>,
+    /// HTTP status code of the last check
+    pub response_code: Option<u16>,
+}
+
+/// Health of a pool as seen from a region
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RegionHealth {
+    /// Whether the pool is healthy
+    pub healthy: bool,
+    /// Health of the origins, by origin address
+    #[serde(default)]
+    pub origins: Vec<HashMap<String, OriginHealth>>,
+}
+
+/// [Pool Health Details](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-pool-health-details) API
+#[derive(Serialize)]
+pub struct GetPoolHealth {
+    #[serde(skip)]
+    pool_id: String,
+}
+
+impl GetPoolHealth {
+    /// Get the health of the pool `pool_id`
+    pub fn new(pool_id: impl Into<String>) -> Self {
+        Self {
+            pool_id: pool_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Pool Health Details](https://developers.cloudflare.com/api/operations/account-load-balancer-pools-pool-health-details#Responses) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GetPoolHealthRes {
+    /// Pool ID
+    pub pool_id: String,
+    /// Health of the pool, by region
+    #[serde(default)]
+    pub pop_health: HashMap<String, RegionHealth>,
+}
+
+impl GetPoolHealthRes {
+    /// Health of each origin in each region, as `(region, origin address, health)`
+    pub fn origins(&self) -> impl Iterator<Item = (&str, &str, &OriginHealth)> {
+        self.pop_health.iter().flat_map(|(region, health)| {
+            health
+                .origins
+                .iter()
+                .flatten()
+                .map(move |(address, origin)| (region.as_str(), address.as_str(), origin))
+        })
+    }
+}
+
+impl CfReqMeta for GetPoolHealth {
+    const METHOD: Method = Method::GET;
+    type Response = GetPoolHealthRes;
+}
+
+impl CfReqAuth for GetPoolHealth {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/load_balancers/pools/{}/health",
+            self.pool_id
+        )
+    }
+}
+
+/// Protocol of a [Monitor]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MonitorType {
+    /// HTTP request
+    Http,
+    /// HTTPS request
+    Https,
+    /// TCP connection
+    Tcp,
+    /// UDP probe falling back to ICMP
+    UdpIcmp,
+    /// ICMP ping
+    IcmpPing,
+    /// SMTP connection
+    Smtp,
+}
+
+/// A health monitor checking the origins of pools
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Monitor {
+    /// Monitor ID
+    pub id: String,
+    /// Protocol of the checks
+    #[serde(rename = "type")]
+    pub monitor_type: MonitorType,
+    /// Description of the monitor
+    #[serde(default)]
+    pub description: String,
+    /// HTTP method of the checks
+    pub method: Option<String>,
+    /// Path requested by the checks
+    pub path: Option<String>,
+    /// Port of the checks
+    pub port: Option<u16>,
+    /// Status codes considered healthy (e.g `2xx`)
+    pub expected_codes: Option<String>,
+    /// Case insensitive substring the response body must contain
+    pub expected_body: Option<String>,
+    /// Seconds between checks
+    pub interval: u32,
+    /// Retries before marking an origin unhealthy
+    pub retries: u32,
+    /// Timeout of a check in seconds
+    pub timeout: u32,
+    /// Whether redirects are followed
+    #[serde(default)]
+    pub follow_redirects: bool,
+    /// Whether invalid certificates are accepted
+    #[serde(default)]
+    pub allow_insecure: bool,
+    /// Creation time
+    pub created_on: String,
+    /// Last update time
+    pub modified_on: String,
+}
+
+/// Settings of a monitor, used by [CreateMonitor] and [UpdateMonitor]
+#[derive(Serialize, Clone, Debug)]
+pub struct MonitorParams {
+    #[serde(rename = "type")]
+    monitor_type: MonitorType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected_codes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected_body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interval: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    follow_redirects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_insecure: Option<bool>,
+}
+
+impl MonitorParams {
+    /// Monitor checking origins with `monitor_type`
+    pub fn new(monitor_type: MonitorType) -> Self {
+        Self {
+            monitor_type,
+            description: None,
+            method: None,
+            path: None,
+            port: None,
+            expected_codes: None,
+            expected_body: None,
+            interval: None,
+            retries: None,
+            timeout: None,
+            follow_redirects: None,
+            allow_insecure: None,
+        }
+    }
+
+    /// HTTPS monitor requesting `path` and expecting `expected_codes` (e.g `2xx`)
+    pub fn https(path: impl Into<String>, expected_codes: impl Into<String>) -> Self {
+        Self::new(MonitorType::Https)
+            .path(path)
+            .expected_codes(expected_codes)
+    }
+
+    /// Description of the monitor
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// HTTP method of the checks, defaults to GET
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    /// Path requested by the checks
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Port of the checks
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Status codes considered healthy (e.g `200` or `2xx`)
+    pub fn expected_codes(mut self, expected_codes: impl Into<String>) -> Self {
+        self.expected_codes = Some(expected_codes.into());
+        self
+    }
+
+    /// Case insensitive substring the response body must contain
+    pub fn expected_body(mut self, expected_body: impl Into<String>) -> Self {
+        self.expected_body = Some(expected_body.into());
+        self
+    }
+
+    /// Seconds between checks, defaults to 60
+    pub fn interval(mut self, interval: u32) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Retries before marking an origin unhealthy, defaults to 2
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    /// Timeout of a check in seconds, defaults to 5
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Whether redirects are followed
+    pub fn follow_redirects(mut self, follow_redirects: bool) -> Self {
+        self.follow_redirects = Some(follow_redirects);
+        self
+    }
+
+    /// Whether invalid certificates are accepted
+    pub fn allow_insecure(mut self, allow_insecure: bool) -> Self {
+        self.allow_insecure = Some(allow_insecure);
+        self
+    }
+}
+
+/// [Create Monitor](https://developers.cloudflare.com/api/operations/account-load-balancer-monitors-create-monitor) API
+#[derive(Serialize)]
+pub struct CreateMonitor {
+    #[serde(flatten)]
+    params: MonitorParams,
+}
+
+impl CreateMonitor {
+    /// Create a monitor
+    pub fn new(params: MonitorParams) -> Self {
+        Self { params }
+    }
+}
+
+/// Success response from the [Create Monitor](https://developers.cloudflare.com/api/operations/account-load-balancer-monitors-create-monitor#Responses) API
+pub type CreateMonitorRes = Monitor;
+
+impl CfReqMeta for CreateMonitor {
+    const METHOD: Method = Method::POST;
+    type Response = CreateMonitorRes;
+}
+
+impl CfReqAuth for CreateMonitor {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/load_balancers/monitors")
+    }
+}
+
+/// [List Monitors](https://developers.cloudflare.com/api/operations/account-load-balancer-monitors-list-monitors) API
+#[derive(Serialize, Default)]
+pub struct ListMonitors {}
+
+/// Success response from the [List Monitors](https://developers.cloudflare.com/api/operations/account-load-balancer-monitors-list-monitors#Responses) API
+pub type ListMonitorsRes = Vec<Monitor>;
+
+impl CfReqMeta for ListMonitors {
+    const METHOD: Method = Method::GET;
+    type Response = ListMonitorsRes;
+}
+
+impl CfReqAuth for ListMonitors {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/load_balancers/monitors")
+    }
+}
+
+/// [Monitor Details](https://developers.cloudflare.com/api/operations/account-load-balancer-monitors-monitor-details) API
+#[derive(Serialize)]
+pub struct GetMonitor {
+    #[serde(skip)]
+    monitor_id: String,
+}
+
+impl GetMonitor {
+    /// Get the monitor `monitor_id`
+    pub fn new(monitor_id: impl Into<String>) -> Self {
+        Self {
+            monitor_id: monitor_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Monitor Details](https://developers.cloudflare.com/api/operations/account-load-balancer-monitors-monitor-details#Responses) API
+pub type GetMonitorRes = Monitor;
+
+impl CfReqMeta for GetMonitor {
+    const METHOD: Method = Method::GET;
+    type Response = GetMonitorRes;
+}
+
+impl CfReqAuth for GetMonitor {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/load_balancers/monitors/{}",
+            self.monitor_id
+        )
+    }
+}
+
+/// [Update Monitor](https://developers.cloudflare.com/api/operations/account-load-balancer-monitors-update-monitor) API
+/// Settings that are not given are reset to their default
+#[derive(Serialize)]
+pub struct UpdateMonitor {
+    #[serde(skip)]
+    monitor_id: String,
+    #[serde(flatten)]
+    params: MonitorParams,
+}
+
+impl UpdateMonitor {
+    /// Replace the settings of the monitor `monitor_id`
+    pub fn new(monitor_id: impl Into<String>, params: MonitorParams) -> Self {
+        Self {
+            monitor_id: monitor_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Update Monitor](https://developers.cloudflare.com/api/operations/account-load-balancer-monitors-update-monitor#Responses) API
+pub type UpdateMonitorRes = Monitor;
+
+impl CfReqMeta for UpdateMonitor {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateMonitorRes;
+}
+
+impl CfReqAuth for UpdateMonitor {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/load_balancers/monitors/{}",
+            self.monitor_id
+        )
+    }
+}
+
+/// [Delete Monitor](https://developers.cloudflare.com/api/operations/account-load-balancer-monitors-delete-monitor) API
+/// Monitors used by a pool can't be deleted
+#[derive(Serialize)]
+pub struct DeleteMonitor {
+    #[serde(skip)]
+    monitor_id: String,
+}
+
+impl DeleteMonitor {
+    /// Delete the monitor `monitor_id`
+    pub fn new(monitor_id: impl Into<String>) -> Self {
+        Self {
+            monitor_id: monitor_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeleteMonitor {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqAuth for DeleteMonitor {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/load_balancers/monitors/{}",
+            self.monitor_id
+        )
+    }
+}
+
+/// How a load balancer picks pools
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SteeringPolicy {
+    /// Default pools in order (failover)
+    Off,
+    /// Pools of the client's region, see [LoadBalancerParams::region_pool]
+    Geo,
+    /// Random pool, by weight
+    Random,
+    /// Lowest round trip time
+    DynamicLatency,
+    /// Pool with the fewest outstanding requests
+    LeastOutstandingRequests,
+    /// A policy unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// How a load balancer keeps clients on the same origin
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionAffinity {
+    /// No session affinity
+    None,
+    /// Cookie set by cloudflare
+    Cookie,
+    /// Cookie set by cloudflare, falling back to the client IP
+    IpCookie,
+    /// Request headers, see [SessionAffinityAttributes::headers]
+    Header,
+    /// A session affinity unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Settings of a [SessionAffinity]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct SessionAffinityAttributes {
+    /// SameSite attribute of the cookie (`Auto`, `Lax`, `None` or `Strict`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub samesite: Option<String>,
+    /// Secure attribute of the cookie (`Auto`, `Always` or `Never`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secure: Option<String>,
+    /// Seconds existing sessions keep going to a disabled origin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drain_duration: Option<u32>,
+    /// Request headers identifying a session, for [SessionAffinity::Header]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Vec<String>>,
+}
+
+/// A zone load balancer
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LoadBalancer {
+    /// Load balancer ID
+    pub id: String,
+    /// Hostname of the load balancer
+    pub name: String,
+    /// Description of the load balancer
+    #[serde(default)]
+    pub description: String,
+    /// Whether the load balancer is enabled
+    pub enabled: bool,
+    /// Whether traffic is proxied through cloudflare
+    pub proxied: bool,
+    /// TTL of the DNS answers, when not proxied
+    pub ttl: Option<u32>,
+    /// IDs of the pools used, in failover order
+    pub default_pools: Vec<String>,
+    /// ID of the pool used when all others are unhealthy
+    pub fallback_pool: String,
+    /// How pools are picked
+    pub steering_policy: Option<SteeringPolicy>,
+    /// How clients are kept on the same origin
+    pub session_affinity: Option<SessionAffinity>,
+    /// Lifetime of a session in seconds
+    pub session_affinity_ttl: Option<u32>,
+    /// Settings of the session affinity
+    pub session_affinity_attributes: Option<SessionAffinityAttributes>,
+    /// Pool IDs by region code (e.g `WNAM`)
+    #[serde(default)]
+    pub region_pools: HashMap<String, Vec<String>>,
+    /// Pool IDs by country code
+    #[serde(default)]
+    pub country_pools: HashMap<String, Vec<String>>,
+    /// Pool IDs by datacenter code
+    #[serde(default)]
+    pub pop_pools: HashMap<String, Vec<String>>,
+    /// Creation time
+    pub created_on: String,
+    /// Last update time
+    pub modified_on: String,
+}
+
+/// Settings of a load balancer, used by [CreateLoadBalancer] and [PatchLoadBalancer]
+/// Pools are given by ID or as the [Pool] returned by the pools API
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct LoadBalancerParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_pools: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fallback_pool: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proxied: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ttl: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    steering_policy: Option<SteeringPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_affinity: Option<SessionAffinity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_affinity_ttl: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_affinity_attributes: Option<SessionAffinityAttributes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region_pools: Option<HashMap<String, Vec<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country_pools: Option<HashMap<String, Vec<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pop_pools: Option<HashMap<String, Vec<String>>>,
+}
+
+impl LoadBalancerParams {
+    /// Hostname of the load balancer
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Add a pool to the default pools, pools are tried in the order they are added
+    pub fn default_pool(mut self, pool: impl AsRef<str>) -> Self {
+        self.default_pools
+            .get_or_insert_with(Vec::new)
+            .push(pool.as_ref().to_string());
+        self
+    }
+
+    /// Pool used when all others are unhealthy
+    pub fn fallback_pool(mut self, pool: impl AsRef<str>) -> Self {
+        self.fallback_pool = Some(pool.as_ref().to_string());
+        self
+    }
+
+    /// Description of the load balancer
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the load balancer is enabled
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Whether traffic is proxied through cloudflare
+    pub fn proxied(mut self, proxied: bool) -> Self {
+        self.proxied = Some(proxied);
+        self
+    }
+
+    /// TTL of the DNS answers, when not proxied
+    pub fn ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// How pools are picked
+    pub fn steering_policy(mut self, steering_policy: SteeringPolicy) -> Self {
+        self.steering_policy = Some(steering_policy);
+        self
+    }
+
+    /// How clients are kept on the same origin, with sessions lasting `ttl` seconds
+    pub fn session_affinity(mut self, session_affinity: SessionAffinity, ttl: u32) -> Self {
+        self.session_affinity = Some(session_affinity);
+        self.session_affinity_ttl = Some(ttl);
+        self
+    }
+
+    /// Settings of the session affinity
+    pub fn session_affinity_attributes(mut self, attributes: SessionAffinityAttributes) -> Self {
+        self.session_affinity_attributes = Some(attributes);
+        self
+    }
+
+    /// Add a pool for clients of `region` (e.g `WNAM`), used by [SteeringPolicy::Geo]
+    pub fn region_pool(mut self, region: impl Into<String>, pool: impl AsRef<str>) -> Self {
+        self.region_pools
+            .get_or_insert_with(HashMap::new)
+            .entry(region.into())
+            .or_default()
+            .push(pool.as_ref().to_string());
+        self
+    }
+
+    /// Add a pool for clients of `country` (e.g `US`), used by [SteeringPolicy::Geo]
+    pub fn country_pool(mut self, country: impl Into<String>, pool: impl AsRef<str>) -> Self {
+        self.country_pools
+            .get_or_insert_with(HashMap::new)
+            .entry(country.into())
+            .or_default()
+            .push(pool.as_ref().to_string());
+        self
+    }
+
+    /// Add a pool for requests reaching the datacenter `pop` (e.g `LAX`)
+    pub fn pop_pool(mut self, pop: impl Into<String>, pool: impl AsRef<str>) -> Self {
+        self.pop_pools
+            .get_or_insert_with(HashMap::new)
+            .entry(pop.into())
+            .or_default()
+            .push(pool.as_ref().to_string());
+        self
+    }
+}
+
+/// [Create Load Balancer](https://developers.cloudflare.com/api/operations/load-balancers-create-load-balancer) API
+#[derive(Serialize)]
+pub struct CreateLoadBalancer {
+    #[serde(flatten)]
+    params: LoadBalancerParams,
+}
+
+impl CreateLoadBalancer {
+    /// Create the load balancer `name` using `default_pools` in failover order,
+    /// the last of them is also the fallback pool
+    pub fn new<P: AsRef<str>>(
+        name: impl Into<String>,
+        default_pools: impl IntoIterator<Item = P>,
+    ) -> Result<Self> {
+        let mut params = LoadBalancerParams::default().name(name);
+        for pool in default_pools {
+            params = params.default_pool(pool);
+        }
+        let Some(fallback) = params.default_pools.as_ref().and_then(|p| p.last()) else {
+            return Err(Error::InvalidRequest(
+                "a load balancer needs at least one pool".to_string(),
+            ));
+        };
+        params.fallback_pool = Some(fallback.clone());
+        Ok(Self { params })
+    }
+
+    /// Create a load balancer with the given settings,
+    /// which must include a name, default pools and a fallback pool
+    pub fn with_params(params: LoadBalancerParams) -> Self {
+        Self { params }
+    }
+}
+
+/// Success response from the [Create Load Balancer](https://developers.cloudflare.com/api/operations/load-balancers-create-load-balancer#Responses) API
+pub type CreateLoadBalancerRes = LoadBalancer;
+
+impl CfReqMeta for CreateLoadBalancer {
+    const METHOD: Method = Method::POST;
+    type Response = CreateLoadBalancerRes;
+}
+
+impl CfReqZone for CreateLoadBalancer {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/load_balancers")
+    }
+}
+
+/// [List Load Balancers](https://developers.cloudflare.com/api/operations/load-balancers-list-load-balancers) API
+#[derive(Serialize, Default)]
+pub struct ListLoadBalancers {}
+
+/// Success response from the [List Load Balancers](https://developers.cloudflare.com/api/operations/load-balancers-list-load-balancers#Responses) API
+pub type ListLoadBalancersRes = Vec<LoadBalancer>;
+
+impl CfReqMeta for ListLoadBalancers {
+    const METHOD: Method = Method::GET;
+    type Response = ListLoadBalancersRes;
+}
+
+impl CfReqZone for ListLoadBalancers {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/load_balancers")
+    }
+}
+
+/// [Load Balancer Details](https://developers.cloudflare.com/api/operations/load-balancers-load-balancer-details) API
+#[derive(Serialize)]
+pub struct GetLoadBalancer {
+    #[serde(skip)]
+    load_balancer_id: String,
+}
+
+impl GetLoadBalancer {
+    /// Get the load balancer `load_balancer_id`
+    pub fn new(load_balancer_id: impl Into<String>) -> Self {
+        Self {
+            load_balancer_id: load_balancer_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Load Balancer Details](https://developers.cloudflare.com/api/operations/load-balancers-load-balancer-details#Responses) API
+pub type GetLoadBalancerRes = LoadBalancer;
+
+impl CfReqMeta for GetLoadBalancer {
+    const METHOD: Method = Method::GET;
+    type Response = GetLoadBalancerRes;
+}
+
+impl CfReqZone for GetLoadBalancer {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/load_balancers/{}", self.load_balancer_id)
+    }
+}
+
+/// [Patch Load Balancer](https://developers.cloudflare.com/api/operations/load-balancers-patch-load-balancer) API
+/// Only the given settings are changed, pool lists and maps are replaced as a whole
+#[derive(Serialize)]
+pub struct PatchLoadBalancer {
+    #[serde(skip)]
+    load_balancer_id: String,
+    #[serde(flatten)]
+    params: LoadBalancerParams,
+}
+
+impl PatchLoadBalancer {
+    /// Change the settings of the load balancer `load_balancer_id`
+    pub fn new(load_balancer_id: impl Into<String>, params: LoadBalancerParams) -> Self {
+        Self {
+            load_balancer_id: load_balancer_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Patch Load Balancer](https://developers.cloudflare.com/api/operations/load-balancers-patch-load-balancer#Responses) API
+pub type PatchLoadBalancerRes = LoadBalancer;
+
+impl CfReqMeta for PatchLoadBalancer {
+    const METHOD: Method = Method::PATCH;
+    type Response = PatchLoadBalancerRes;
+}
+
+impl CfReqZone for PatchLoadBalancer {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/load_balancers/{}", self.load_balancer_id)
+    }
+}
+
+/// [Delete Load Balancer](https://developers.cloudflare.com/api/operations/load-balancers-delete-load-balancer) API
+#[derive(Serialize)]
+pub struct DeleteLoadBalancer {
+    #[serde(skip)]
+    load_balancer_id: String,
+}
+
+impl DeleteLoadBalancer {
+    /// Delete the load balancer `load_balancer_id`
+    pub fn new(load_balancer_id: impl Into<String>) -> Self {
+        Self {
+            load_balancer_id: load_balancer_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeleteLoadBalancer {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqZone for DeleteLoadBalancer {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/load_balancers/{}", self.load_balancer_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn origin_weight_is_validated() {
+        let origin = Origin::new("a", "192.0.2.1");
+        assert_eq!(origin.clone().weight(0.5).unwrap().get_weight(), 0.5);
+        assert!(origin.clone().weight(1.5).is_err());
+        assert!(origin.clone().weight(-0.1).is_err());
+        assert!(origin.weight(f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,553 @@
+//! Cloudflare Logpush related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/logs/about/)
+//! Jobs belong either to the account or to a zone, every request can be sent
+//! with [crate::CloudflareAuth::send_auth] for account jobs
+//! or with [crate::CloudflareZone::send_zone] for zone jobs
+use crate::{CfReqAuth, CfReqMeta, CfReqZone, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Format of timestamps in pushed logs
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampFormat {
+    /// Nanoseconds since the unix epoch
+    Unixnano,
+    /// Seconds since the unix epoch
+    Unix,
+    /// RFC3339 timestamp
+    Rfc3339,
+}
+
+/// Format of pushed logs
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogOutputType {
+    /// One JSON object per line
+    Ndjson,
+    /// Comma separated values
+    Csv,
+}
+
+/// Formatting of the logs pushed by a job
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct LogOutputOptions {
+    /// Fields included in the logs, see the dataset's field list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_names: Option<Vec<String>>,
+    /// Format of timestamps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp_format: Option<TimestampFormat>,
+    /// Fraction of records pushed, between 0 and 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_rate: Option<f64>,
+    /// Format of the logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_type: Option<LogOutputType>,
+}
+
+impl LogOutputOptions {
+    /// Fields included in the logs
+    pub fn field_names<F: Into<String>>(mut self, fields: impl IntoIterator<Item = F>) -> Self {
+        self.field_names = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Format of timestamps
+    pub fn timestamp_format(mut self, format: TimestampFormat) -> Self {
+        self.timestamp_format = Some(format);
+        self
+    }
+
+    /// Fraction of records pushed, between 0 and 1
+    pub fn sample_rate(mut self, sample_rate: f64) -> Self {
+        self.sample_rate = Some(sample_rate);
+        self
+    }
+
+    /// Format of the logs
+    pub fn output_type(mut self, output_type: LogOutputType) -> Self {
+        self.output_type = Some(output_type);
+        self
+    }
+}
+
+/// A Logpush job
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LogpushJob {
+    /// Identifier of the job
+    pub id: u64,
+    /// Name of the job
+    pub name: Option<String>,
+    /// Dataset pushed by the job (e.g `http_requests`, `workers_trace_events`)
+    pub dataset: Option<String>,
+    /// Destination of the logs (e.g `s3://bucket/path?region=us-east-1`)
+    pub destination_conf: String,
+    /// Whether the job is enabled
+    pub enabled: bool,
+    /// Formatting of the logs
+    pub output_options: Option<LogOutputOptions>,
+    /// RFC3339 timestamp of the last successful push
+    pub last_complete: Option<String>,
+    /// RFC3339 timestamp of the last failed push
+    pub last_error: Option<String>,
+    /// Reason of the last failed push
+    pub error_message: Option<String>,
+}
+
+/// [Create Logpush job](https://developers.cloudflare.com/api/operations/post-accounts-account_id-logpush-jobs) API
+/// The destination must be proven to be owned with [GetOwnershipChallenge] first
+#[derive(Serialize)]
+pub struct CreateLogpushJob {
+    dataset: String,
+    destination_conf: String,
+    ownership_challenge: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_options: Option<LogOutputOptions>,
+}
+
+impl CreateLogpushJob {
+    /// Push the logs of `dataset` to `destination_conf`,
+    /// `ownership_challenge` is the token written to the destination by [GetOwnershipChallenge]
+    pub fn new(
+        dataset: impl Into<String>,
+        destination_conf: impl Into<String>,
+        ownership_challenge: impl Into<String>,
+    ) -> Self {
+        Self {
+            dataset: dataset.into(),
+            destination_conf: destination_conf.into(),
+            ownership_challenge: ownership_challenge.into(),
+            name: None,
+            enabled: None,
+            output_options: None,
+        }
+    }
+
+    /// Name of the job
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether the job is enabled
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Formatting of the logs
+    pub fn output_options(mut self, output_options: LogOutputOptions) -> Self {
+        self.output_options = Some(output_options);
+        self
+    }
+}
+
+/// Success response from the [Create Logpush job](https://developers.cloudflare.com/api/operations/post-accounts-account_id-logpush-jobs#Responses) API
+pub type CreateLogpushJobRes = LogpushJob;
+
+impl CfReqMeta for CreateLogpushJob {
+    const METHOD: Method = Method::POST;
+    type Response = CreateLogpushJobRes;
+}
+
+impl CfReqAuth for CreateLogpushJob {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/logpush/jobs")
+    }
+}
+
+impl CfReqZone for CreateLogpushJob {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/logpush/jobs")
+    }
+}
+
+/// [List Logpush jobs](https://developers.cloudflare.com/api/operations/get-accounts-account_id-logpush-jobs) API
+#[derive(Serialize, Default)]
+pub struct ListLogpushJobs {}
+
+/// Success response from the [List Logpush jobs](https://developers.cloudflare.com/api/operations/get-accounts-account_id-logpush-jobs#Responses) API
+pub type ListLogpushJobsRes = Vec<LogpushJob>;
+
+impl CfReqMeta for ListLogpushJobs {
+    const METHOD: Method = Method::GET;
+    type Response = ListLogpushJobsRes;
+}
+
+impl CfReqAuth for ListLogpushJobs {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/logpush/jobs")
+    }
+}
+
+impl CfReqZone for ListLogpushJobs {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/logpush/jobs")
+    }
+}
+
+/// [Get Logpush job details](https://developers.cloudflare.com/api/operations/get-accounts-account_id-logpush-jobs-job_id) API
+#[derive(Serialize)]
+pub struct GetLogpushJob {
+    #[serde(skip)]
+    job_id: u64,
+}
+
+impl GetLogpushJob {
+    /// Retrieve the job with the given id
+    pub fn new(job_id: u64) -> Self {
+        Self { job_id }
+    }
+}
+
+/// Success response from the [Get Logpush job details](https://developers.cloudflare.com/api/operations/get-accounts-account_id-logpush-jobs-job_id#Responses) API
+pub type GetLogpushJobRes = LogpushJob;
+
+impl CfReqMeta for GetLogpushJob {
+    const METHOD: Method = Method::GET;
+    type Response = GetLogpushJobRes;
+}
+
+impl CfReqAuth for GetLogpushJob {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/logpush/jobs/{}", self.job_id)
+    }
+}
+
+impl CfReqZone for GetLogpushJob {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/logpush/jobs/{}", self.job_id)
+    }
+}
+
+/// [Update Logpush job](https://developers.cloudflare.com/api/operations/put-accounts-account_id-logpush-jobs-job_id) API
+#[derive(Serialize)]
+pub struct UpdateLogpushJob {
+    #[serde(skip)]
+    job_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    destination_conf: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ownership_challenge: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_options: Option<LogOutputOptions>,
+}
+
+impl UpdateLogpushJob {
+    /// Change the job with the given id, only the fields set are changed
+    pub fn new(job_id: u64) -> Self {
+        Self {
+            job_id,
+            destination_conf: None,
+            ownership_challenge: None,
+            name: None,
+            enabled: None,
+            output_options: None,
+        }
+    }
+
+    /// Move the job to another destination, proven to be owned with [GetOwnershipChallenge]
+    pub fn destination(
+        mut self,
+        destination_conf: impl Into<String>,
+        ownership_challenge: impl Into<String>,
+    ) -> Self {
+        self.destination_conf = Some(destination_conf.into());
+        self.ownership_challenge = Some(ownership_challenge.into());
+        self
+    }
+
+    /// Name of the job
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Enable or disable the job
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Formatting of the logs
+    pub fn output_options(mut self, output_options: LogOutputOptions) -> Self {
+        self.output_options = Some(output_options);
+        self
+    }
+}
+
+/// Success response from the [Update Logpush job](https://developers.cloudflare.com/api/operations/put-accounts-account_id-logpush-jobs-job_id#Responses) API
+pub type UpdateLogpushJobRes = LogpushJob;
+
+impl CfReqMeta for UpdateLogpushJob {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateLogpushJobRes;
+}
+
+impl CfReqAuth for UpdateLogpushJob {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/logpush/jobs/{}", self.job_id)
+    }
+}
+
+impl CfReqZone for UpdateLogpushJob {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/logpush/jobs/{}", self.job_id)
+    }
+}
+
+/// [Delete Logpush job](https://developers.cloudflare.com/api/operations/delete-accounts-account_id-logpush-jobs-job_id) API
+#[derive(Serialize)]
+pub struct DeleteLogpushJob {
+    #[serde(skip)]
+    job_id: u64,
+}
+
+impl DeleteLogpushJob {
+    /// Delete the job with the given id
+    pub fn new(job_id: u64) -> Self {
+        Self { job_id }
+    }
+}
+
+/// Success response from the [Delete Logpush job](https://developers.cloudflare.com/api/operations/delete-accounts-account_id-logpush-jobs-job_id#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteLogpushJobRes;
+
+impl CfReqMeta for DeleteLogpushJob {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteLogpushJobRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty object
+        Ok(DeleteLogpushJobRes)
+    }
+}
+
+impl CfReqAuth for DeleteLogpushJob {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/logpush/jobs/{}", self.job_id)
+    }
+}
+
+impl CfReqZone for DeleteLogpushJob {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/logpush/jobs/{}", self.job_id)
+    }
+}
+
+/// Result of a Logpush validation
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LogpushValidation {
+    /// Whether the validation passed
+    pub valid: bool,
+    /// Reason the validation failed
+    #[serde(default)]
+    pub message: String,
+}
+
+/// [Validate destination](https://developers.cloudflare.com/api/operations/post-accounts-account_id-logpush-validate-destination) API
+#[derive(Serialize)]
+pub struct ValidateLogpushDestination {
+    destination_conf: String,
+}
+
+impl ValidateLogpushDestination {
+    /// Check that cloudflare can push logs to `destination_conf`
+    pub fn new(destination_conf: impl Into<String>) -> Self {
+        Self {
+            destination_conf: destination_conf.into(),
+        }
+    }
+}
+
+/// Success response from the [Validate destination](https://developers.cloudflare.com/api/operations/post-accounts-account_id-logpush-validate-destination#Responses) API
+pub type ValidateLogpushDestinationRes = LogpushValidation;
+
+impl CfReqMeta for ValidateLogpushDestination {
+    const METHOD: Method = Method::POST;
+    type Response = ValidateLogpushDestinationRes;
+}
+
+impl CfReqAuth for ValidateLogpushDestination {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/logpush/validate/destination")
+    }
+}
+
+impl CfReqZone for ValidateLogpushDestination {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/logpush/validate/destination")
+    }
+}
+
+/// [Validate origin](https://developers.cloudflare.com/api/operations/post-accounts-account_id-logpush-validate-origin) API
+#[derive(Serialize)]
+pub struct ValidateLogpushOrigin {
+    logpull_options: String,
+}
+
+impl ValidateLogpushOrigin {
+    /// Check that the legacy `logpull_options` (e.g `fields=RayID,ClientIP&timestamps=rfc3339`) are valid
+    pub fn new(logpull_options: impl Into<String>) -> Self {
+        Self {
+            logpull_options: logpull_options.into(),
+        }
+    }
+}
+
+/// Success response from the [Validate origin](https://developers.cloudflare.com/api/operations/post-accounts-account_id-logpush-validate-origin#Responses) API
+pub type ValidateLogpushOriginRes = LogpushValidation;
+
+impl CfReqMeta for ValidateLogpushOrigin {
+    const METHOD: Method = Method::POST;
+    type Response = ValidateLogpushOriginRes;
+}
+
+impl CfReqAuth for ValidateLogpushOrigin {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/logpush/validate/origin")
+    }
+}
+
+impl CfReqZone for ValidateLogpushOrigin {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/logpush/validate/origin")
+    }
+}
+
+/// [Get ownership challenge](https://developers.cloudflare.com/api/operations/post-accounts-account_id-logpush-ownership) API
+/// Cloudflare writes a file containing the challenge token to the destination,
+/// which must be read back and passed to [CreateLogpushJob]
+#[derive(Serialize)]
+pub struct GetOwnershipChallenge {
+    destination_conf: String,
+}
+
+impl GetOwnershipChallenge {
+    /// Write an ownership challenge file to `destination_conf`
+    pub fn new(destination_conf: impl Into<String>) -> Self {
+        Self {
+            destination_conf: destination_conf.into(),
+        }
+    }
+}
+
+/// Success response from the [Get ownership challenge](https://developers.cloudflare.com/api/operations/post-accounts-account_id-logpush-ownership#Responses) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GetOwnershipChallengeRes {
+    /// Path of the challenge file written to the destination
+    pub filename: String,
+    /// Whether the file was written
+    pub valid: bool,
+    /// Reason the file could not be written
+    #[serde(default)]
+    pub message: String,
+}
+
+impl CfReqMeta for GetOwnershipChallenge {
+    const METHOD: Method = Method::POST;
+    type Response = GetOwnershipChallengeRes;
+}
+
+impl CfReqAuth for GetOwnershipChallenge {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/logpush/ownership")
+    }
+}
+
+impl CfReqZone for GetOwnershipChallenge {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/logpush/ownership")
+    }
+}
+
+/// [Validate ownership challenge](https://developers.cloudflare.com/api/operations/post-accounts-account_id-logpush-ownership-validate) API
+#[derive(Serialize)]
+pub struct ValidateOwnershipChallenge {
+    destination_conf: String,
+    ownership_challenge: String,
+}
+
+impl ValidateOwnershipChallenge {
+    /// Check that `ownership_challenge` is the token written to `destination_conf`
+    pub fn new(
+        destination_conf: impl Into<String>,
+        ownership_challenge: impl Into<String>,
+    ) -> Self {
+        Self {
+            destination_conf: destination_conf.into(),
+            ownership_challenge: ownership_challenge.into(),
+        }
+    }
+}
+
+/// Success response from the [Validate ownership challenge](https://developers.cloudflare.com/api/operations/post-accounts-account_id-logpush-ownership-validate#Responses) API
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ValidateOwnershipChallengeRes {
+    /// Whether the token is valid
+    pub valid: bool,
+}
+
+impl CfReqMeta for ValidateOwnershipChallenge {
+    const METHOD: Method = Method::POST;
+    type Response = ValidateOwnershipChallengeRes;
+}
+
+impl CfReqAuth for ValidateOwnershipChallenge {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/logpush/ownership/validate")
+    }
+}
+
+impl CfReqZone for ValidateOwnershipChallenge {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/logpush/ownership/validate")
+    }
+}
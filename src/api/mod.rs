@@ -1,3 +1,38 @@
 //! Cloudflare API requests
+pub mod access;
+pub mod accounts;
+pub mod cache;
+pub mod d1;
+pub mod dns;
+pub mod email_routing;
+pub mod graphql;
+pub mod healthchecks;
+pub mod hyperdrive;
+pub mod images;
 pub mod kv;
+pub mod load_balancing;
+pub mod logpush;
+pub mod mtls;
+pub mod origin_ca;
+pub mod pages;
+pub mod queues;
+pub mod r2;
+pub mod rulesets;
+pub mod stream_audio;
+pub mod stream_captions;
+pub mod stream_live;
+pub mod stream_signing;
+#[cfg(feature = "stream-tus")]
+pub mod stream_tus;
 pub mod stream_videos;
+pub mod stream_watermarks;
+pub mod stream_webhooks;
+pub mod tokens;
+pub mod tunnels;
+pub mod turnstile;
+pub mod url_scanner;
+pub mod vectorize;
+pub mod waiting_rooms;
+pub mod workers;
+pub mod workers_ai;
+pub mod zones;
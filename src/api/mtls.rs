@@ -0,0 +1,285 @@
+//! Cloudflare mTLS certificate related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/ssl/client-certificates/)
+//! Certificates are account scoped, hostname associations are zone scoped
+//! and sent with [crate::CloudflareZone::send_zone]
+use std::fmt;
+
+use crate::{CfReqAuth, CfReqMeta, CfReqZone, SecretString};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// An mTLS certificate (or CA certificate) uploaded to the account
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MtlsCertificate {
+    /// Certificate ID
+    pub id: String,
+    /// Name of the certificate
+    pub name: Option<String>,
+    /// Whether the certificate is a CA certificate
+    pub ca: bool,
+    /// PEM encoded certificate chain
+    pub certificates: String,
+    /// Issuer of the certificate
+    pub issuer: Option<String>,
+    /// Serial number of the certificate
+    pub serial_number: Option<String>,
+    /// Signature algorithm of the certificate
+    pub signature: Option<String>,
+    /// Expiry time of the certificate
+    pub expires_on: String,
+    /// Upload time
+    pub uploaded_on: Option<String>,
+}
+
+impl fmt::Debug for MtlsCertificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MtlsCertificate")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("ca", &self.ca)
+            .field("certificates", &"<pem>")
+            .field("issuer", &self.issuer)
+            .field("serial_number", &self.serial_number)
+            .field("signature", &self.signature)
+            .field("expires_on", &self.expires_on)
+            .field("uploaded_on", &self.uploaded_on)
+            .finish()
+    }
+}
+
+/// [Upload mTLS certificate](https://developers.cloudflare.com/api/operations/m-tls-certificate-management-upload-m-tls-certificate) API
+#[derive(Serialize)]
+pub struct CreateMtlsCertificate {
+    certificates: String,
+    ca: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private_key: Option<SecretString>,
+}
+
+impl fmt::Debug for CreateMtlsCertificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateMtlsCertificate")
+            .field("certificates", &"<pem>")
+            .field("ca", &self.ca)
+            .field("name", &self.name)
+            .field("private_key", &self.private_key)
+            .finish()
+    }
+}
+
+impl CreateMtlsCertificate {
+    /// Upload the PEM encoded CA certificate `certificates`, used to verify client certificates
+    pub fn ca(certificates: impl Into<String>) -> Self {
+        Self {
+            certificates: certificates.into(),
+            ca: true,
+            name: None,
+            private_key: None,
+        }
+    }
+
+    /// Upload the PEM encoded leaf certificate `certificates` with its PEM encoded `private_key`,
+    /// presented to origins
+    pub fn leaf(certificates: impl Into<String>, private_key: impl Into<String>) -> Self {
+        Self {
+            certificates: certificates.into(),
+            ca: false,
+            name: None,
+            private_key: Some(SecretString::from(private_key.into())),
+        }
+    }
+
+    /// Name of the certificate
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+/// Success response from the [Upload mTLS certificate](https://developers.cloudflare.com/api/operations/m-tls-certificate-management-upload-m-tls-certificate#Responses) API
+pub type CreateMtlsCertificateRes = MtlsCertificate;
+
+impl CfReqMeta for CreateMtlsCertificate {
+    const METHOD: Method = Method::POST;
+    type Response = CreateMtlsCertificateRes;
+}
+
+impl CfReqAuth for CreateMtlsCertificate {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/mtls_certificates")
+    }
+}
+
+/// [List mTLS certificates](https://developers.cloudflare.com/api/operations/m-tls-certificate-management-list-m-tls-certificates) API
+#[derive(Serialize, Default)]
+pub struct ListMtlsCertificates {}
+
+/// Success response from the [List mTLS certificates](https://developers.cloudflare.com/api/operations/m-tls-certificate-management-list-m-tls-certificates#Responses) API
+pub type ListMtlsCertificatesRes = Vec<MtlsCertificate>;
+
+impl CfReqMeta for ListMtlsCertificates {
+    const METHOD: Method = Method::GET;
+    type Response = ListMtlsCertificatesRes;
+}
+
+impl CfReqAuth for ListMtlsCertificates {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/mtls_certificates")
+    }
+}
+
+/// [Get mTLS certificate](https://developers.cloudflare.com/api/operations/m-tls-certificate-management-get-m-tls-certificate) API
+#[derive(Serialize)]
+pub struct GetMtlsCertificate {
+    #[serde(skip)]
+    certificate_id: String,
+}
+
+impl GetMtlsCertificate {
+    /// Get the certificate `certificate_id`
+    pub fn new(certificate_id: impl Into<String>) -> Self {
+        Self {
+            certificate_id: certificate_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get mTLS certificate](https://developers.cloudflare.com/api/operations/m-tls-certificate-management-get-m-tls-certificate#Responses) API
+pub type GetMtlsCertificateRes = MtlsCertificate;
+
+impl CfReqMeta for GetMtlsCertificate {
+    const METHOD: Method = Method::GET;
+    type Response = GetMtlsCertificateRes;
+}
+
+impl CfReqAuth for GetMtlsCertificate {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/mtls_certificates/{}",
+            self.certificate_id
+        )
+    }
+}
+
+/// [Delete mTLS certificate](https://developers.cloudflare.com/api/operations/m-tls-certificate-management-delete-m-tls-certificate) API
+#[derive(Serialize)]
+pub struct DeleteMtlsCertificate {
+    #[serde(skip)]
+    certificate_id: String,
+}
+
+impl DeleteMtlsCertificate {
+    /// Delete the certificate `certificate_id`
+    pub fn new(certificate_id: impl Into<String>) -> Self {
+        Self {
+            certificate_id: certificate_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete mTLS certificate](https://developers.cloudflare.com/api/operations/m-tls-certificate-management-delete-m-tls-certificate#Responses) API
+pub type DeleteMtlsCertificateRes = MtlsCertificate;
+
+impl CfReqMeta for DeleteMtlsCertificate {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteMtlsCertificateRes;
+}
+
+impl CfReqAuth for DeleteMtlsCertificate {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/mtls_certificates/{}",
+            self.certificate_id
+        )
+    }
+}
+
+/// Hostnames whose client certificates are verified by a CA certificate
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct HostnameAssociations {
+    /// Associated hostnames
+    #[serde(default)]
+    pub hostnames: Vec<String>,
+}
+
+/// [List Hostname Associations](https://developers.cloudflare.com/api/operations/client-certificate-for-a-zone-list-hostname-associations) API
+#[derive(Serialize, Default)]
+pub struct ListHostnameAssociations {
+    mtls_certificate_id: Option<String>,
+}
+
+impl ListHostnameAssociations {
+    /// List the hostnames associated with the CA certificate `certificate_id`,
+    /// instead of the cloudflare managed CA
+    pub fn certificate(mut self, certificate_id: impl Into<String>) -> Self {
+        self.mtls_certificate_id = Some(certificate_id.into());
+        self
+    }
+}
+
+/// Success response from the [List Hostname Associations](https://developers.cloudflare.com/api/operations/client-certificate-for-a-zone-list-hostname-associations#Responses) API
+pub type ListHostnameAssociationsRes = HostnameAssociations;
+
+impl CfReqMeta for ListHostnameAssociations {
+    const METHOD: Method = Method::GET;
+    type Response = ListHostnameAssociationsRes;
+}
+
+impl CfReqZone for ListHostnameAssociations {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/certificate_authorities/hostname_associations")
+    }
+}
+
+/// [Replace Hostname Associations](https://developers.cloudflare.com/api/operations/client-certificate-for-a-zone-put-hostname-associations) API
+/// All the associated hostnames are replaced
+#[derive(Serialize)]
+pub struct ReplaceHostnameAssociations {
+    hostnames: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mtls_certificate_id: Option<String>,
+}
+
+impl ReplaceHostnameAssociations {
+    /// Associate `hostnames` with the cloudflare managed CA
+    pub fn new<H: Into<String>>(hostnames: impl IntoIterator<Item = H>) -> Self {
+        Self {
+            hostnames: hostnames.into_iter().map(Into::into).collect(),
+            mtls_certificate_id: None,
+        }
+    }
+
+    /// Associate the hostnames with the CA certificate `certificate_id` instead
+    pub fn certificate(mut self, certificate_id: impl Into<String>) -> Self {
+        self.mtls_certificate_id = Some(certificate_id.into());
+        self
+    }
+}
+
+/// Success response from the [Replace Hostname Associations](https://developers.cloudflare.com/api/operations/client-certificate-for-a-zone-put-hostname-associations#Responses) API
+pub type ReplaceHostnameAssociationsRes = HostnameAssociations;
+
+impl CfReqMeta for ReplaceHostnameAssociations {
+    const METHOD: Method = Method::PUT;
+    type Response = ReplaceHostnameAssociationsRes;
+}
+
+impl CfReqZone for ReplaceHostnameAssociations {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/certificate_authorities/hostname_associations")
+    }
+}
@@ -0,0 +1,259 @@
+//! Cloudflare Origin CA related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/ssl/origin-configuration/origin-ca/)
+//! The client's account is not used. Requests are authenticated with the client's token,
+//! which needs the `Zone SSL and Certificates` permission, or with an Origin CA key given
+//! with `service_key` (e.g [CreateOriginCaCertificate::service_key]), sent alongside the token
+use crate::{CfReqAuth, CfReqMeta, Error, Result, SecretString};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Header carrying the Origin CA key
+const SERVICE_KEY_HEADER: &str = "X-Auth-User-Service-Key";
+
+/// Validity periods (in days) accepted by [CreateOriginCaCertificate]
+pub const ORIGIN_CA_VALIDITY_DAYS: [u32; 7] = [7, 30, 90, 365, 730, 1095, 5475];
+
+fn service_key_header(service_key: &Option<SecretString>) -> Vec<(&'static str, String)> {
+    service_key
+        .iter()
+        .map(|key| (SERVICE_KEY_HEADER, key.expose().to_string()))
+        .collect()
+}
+
+/// Key type of an Origin CA certificate
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum OriginCaRequestType {
+    /// RSA key
+    OriginRsa,
+    /// ECDSA key
+    OriginEcc,
+    /// Keyless SSL
+    KeylessCertificate,
+}
+
+/// An Origin CA certificate
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OriginCaCertificate {
+    /// Certificate ID
+    pub id: String,
+    /// PEM encoded certificate
+    pub certificate: String,
+    /// Hostnames covered by the certificate
+    pub hostnames: Vec<String>,
+    /// Key type of the certificate
+    pub request_type: OriginCaRequestType,
+    /// Validity of the certificate in days
+    pub requested_validity: u32,
+    /// Expiry time of the certificate
+    pub expires_on: String,
+    /// PEM encoded CSR the certificate was issued for
+    #[serde(default)]
+    pub csr: String,
+}
+
+/// [Create Certificate](https://developers.cloudflare.com/api/operations/origin-ca-create-certificate) API
+#[derive(Serialize, Debug)]
+pub struct CreateOriginCaCertificate {
+    csr: String,
+    hostnames: Vec<String>,
+    request_type: OriginCaRequestType,
+    requested_validity: u32,
+    #[serde(skip)]
+    service_key: Option<SecretString>,
+}
+
+impl CreateOriginCaCertificate {
+    /// Issue a certificate for the PEM encoded `csr` covering `hostnames` (e.g `*.example.com`),
+    /// valid for 15 years by default
+    pub fn new<H: Into<String>>(
+        csr: impl Into<String>,
+        hostnames: impl IntoIterator<Item = H>,
+        request_type: OriginCaRequestType,
+    ) -> Self {
+        Self {
+            csr: csr.into(),
+            hostnames: hostnames.into_iter().map(Into::into).collect(),
+            request_type,
+            requested_validity: 5475,
+            service_key: None,
+        }
+    }
+
+    /// Validity of the certificate in days, must be one of [ORIGIN_CA_VALIDITY_DAYS]
+    pub fn requested_validity(mut self, days: u32) -> Result<Self> {
+        if !ORIGIN_CA_VALIDITY_DAYS.contains(&days) {
+            return Err(Error::InvalidRequest(format!(
+                "origin ca certificate validity must be one of {ORIGIN_CA_VALIDITY_DAYS:?} days, got {days}"
+            )));
+        }
+        self.requested_validity = days;
+        Ok(self)
+    }
+
+    /// Authenticate with the Origin CA key `service_key`
+    pub fn service_key(mut self, service_key: impl Into<String>) -> Self {
+        self.service_key = Some(SecretString::from(service_key.into()));
+        self
+    }
+}
+
+/// Success response from the [Create Certificate](https://developers.cloudflare.com/api/operations/origin-ca-create-certificate#Responses) API
+pub type CreateOriginCaCertificateRes = OriginCaCertificate;
+
+impl CfReqMeta for CreateOriginCaCertificate {
+    const METHOD: Method = Method::POST;
+    type Response = CreateOriginCaCertificateRes;
+
+    fn headers(&self) -> Vec<(&'static str, String)> {
+        service_key_header(&self.service_key)
+    }
+}
+
+impl CfReqAuth for CreateOriginCaCertificate {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "certificates".to_string()
+    }
+}
+
+/// [List Certificates](https://developers.cloudflare.com/api/operations/origin-ca-list-certificates) API
+#[derive(Serialize, Debug)]
+pub struct ListOriginCaCertificates {
+    zone_id: String,
+    #[serde(skip)]
+    service_key: Option<SecretString>,
+}
+
+impl ListOriginCaCertificates {
+    /// List the certificates of the zone `zone_id`
+    pub fn new(zone_id: impl Into<String>) -> Self {
+        Self {
+            zone_id: zone_id.into(),
+            service_key: None,
+        }
+    }
+
+    /// Authenticate with the Origin CA key `service_key`
+    pub fn service_key(mut self, service_key: impl Into<String>) -> Self {
+        self.service_key = Some(SecretString::from(service_key.into()));
+        self
+    }
+}
+
+/// Success response from the [List Certificates](https://developers.cloudflare.com/api/operations/origin-ca-list-certificates#Responses) API
+pub type ListOriginCaCertificatesRes = Vec<OriginCaCertificate>;
+
+impl CfReqMeta for ListOriginCaCertificates {
+    const METHOD: Method = Method::GET;
+    type Response = ListOriginCaCertificatesRes;
+
+    fn headers(&self) -> Vec<(&'static str, String)> {
+        service_key_header(&self.service_key)
+    }
+}
+
+impl CfReqAuth for ListOriginCaCertificates {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "certificates".to_string()
+    }
+}
+
+/// [Get Certificate](https://developers.cloudflare.com/api/operations/origin-ca-get-certificate) API
+#[derive(Serialize, Debug)]
+pub struct GetOriginCaCertificate {
+    #[serde(skip)]
+    certificate_id: String,
+    #[serde(skip)]
+    service_key: Option<SecretString>,
+}
+
+impl GetOriginCaCertificate {
+    /// Get the certificate `certificate_id`
+    pub fn new(certificate_id: impl Into<String>) -> Self {
+        Self {
+            certificate_id: certificate_id.into(),
+            service_key: None,
+        }
+    }
+
+    /// Authenticate with the Origin CA key `service_key`
+    pub fn service_key(mut self, service_key: impl Into<String>) -> Self {
+        self.service_key = Some(SecretString::from(service_key.into()));
+        self
+    }
+}
+
+/// Success response from the [Get Certificate](https://developers.cloudflare.com/api/operations/origin-ca-get-certificate#Responses) API
+pub type GetOriginCaCertificateRes = OriginCaCertificate;
+
+impl CfReqMeta for GetOriginCaCertificate {
+    const METHOD: Method = Method::GET;
+    type Response = GetOriginCaCertificateRes;
+
+    fn headers(&self) -> Vec<(&'static str, String)> {
+        service_key_header(&self.service_key)
+    }
+}
+
+impl CfReqAuth for GetOriginCaCertificate {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        format!("certificates/{}", self.certificate_id)
+    }
+}
+
+/// [Revoke Certificate](https://developers.cloudflare.com/api/operations/origin-ca-revoke-certificate) API
+#[derive(Serialize, Debug)]
+pub struct RevokeOriginCaCertificate {
+    #[serde(skip)]
+    certificate_id: String,
+    #[serde(skip)]
+    service_key: Option<SecretString>,
+}
+
+impl RevokeOriginCaCertificate {
+    /// Revoke the certificate `certificate_id`
+    pub fn new(certificate_id: impl Into<String>) -> Self {
+        Self {
+            certificate_id: certificate_id.into(),
+            service_key: None,
+        }
+    }
+
+    /// Authenticate with the Origin CA key `service_key`
+    pub fn service_key(mut self, service_key: impl Into<String>) -> Self {
+        self.service_key = Some(SecretString::from(service_key.into()));
+        self
+    }
+}
+
+/// Success response from the [Revoke Certificate](https://developers.cloudflare.com/api/operations/origin-ca-revoke-certificate#Responses) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RevokeOriginCaCertificateRes {
+    /// ID of the revoked certificate
+    pub id: String,
+    /// Revocation time
+    pub revoked_at: String,
+}
+
+impl CfReqMeta for RevokeOriginCaCertificate {
+    const METHOD: Method = Method::DELETE;
+    type Response = RevokeOriginCaCertificateRes;
+
+    fn headers(&self) -> Vec<(&'static str, String)> {
+        service_key_header(&self.service_key)
+    }
+}
+
+impl CfReqAuth for RevokeOriginCaCertificate {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        format!("certificates/{}", self.certificate_id)
+    }
+}
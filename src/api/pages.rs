@@ -0,0 +1,1078 @@
+//! Cloudflare Pages related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/pages/)
+use std::{collections::BTreeMap, fmt};
+
+use crate::{CfReqAuth, CfReqMeta, CfSuccessResWithInfo, Error, PageInfo, Result};
+use bytes::Bytes;
+use reqwest::{multipart::Form, Method};
+use serde::{Deserialize, Serialize};
+
+/// Cloudflare error code returned when deleting a domain that is still in use by the project
+/// see [crate::Error::has_cf_code]
+pub const PAGES_DOMAIN_IN_USE: u32 = 8000018;
+
+/// Type of a Pages environment variable
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EnvVarType {
+    /// Value readable from the dashboard and API
+    PlainText,
+    /// Encrypted value, never returned by the API
+    SecretText,
+}
+
+/// Environment variable of a Pages project
+#[derive(Serialize, Deserialize, Clone)]
+pub struct EnvVar {
+    /// Type of the variable
+    #[serde(rename = "type")]
+    pub var_type: EnvVarType,
+    /// Value of the variable, empty for secrets returned by cloudflare
+    #[serde(default)]
+    pub value: String,
+}
+
+impl fmt::Debug for EnvVar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnvVar")
+            .field("var_type", &self.var_type)
+            .field("value", &"<redacted>")
+            .finish()
+    }
+}
+
+impl EnvVar {
+    /// Plain text variable
+    pub fn plain_text(value: impl Into<String>) -> Self {
+        Self {
+            var_type: EnvVarType::PlainText,
+            value: value.into(),
+        }
+    }
+
+    /// Secret variable
+    pub fn secret(value: impl Into<String>) -> Self {
+        Self {
+            var_type: EnvVarType::SecretText,
+            value: value.into(),
+        }
+    }
+}
+
+/// Build settings of a Pages project
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct BuildConfig {
+    /// Command building the project (e.g `npm run build`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_command: Option<String>,
+    /// Output directory of the build
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_dir: Option<String>,
+    /// Directory to run the build in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root_dir: Option<String>,
+    /// Whether build caching is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_caching: Option<bool>,
+    /// Web Analytics site tag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_analytics_tag: Option<String>,
+}
+
+/// Settings of a Pages environment (production or preview)
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct DeploymentConfig {
+    /// Environment variables, by name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env_vars: Option<BTreeMap<String, EnvVar>>,
+    /// Compatibility date of the Pages Functions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compatibility_date: Option<String>,
+    /// Compatibility flags of the Pages Functions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compatibility_flags: Option<Vec<String>>,
+}
+
+impl DeploymentConfig {
+    /// Set the environment variable `name`
+    pub fn env_var(mut self, name: impl Into<String>, var: EnvVar) -> Self {
+        self.env_vars
+            .get_or_insert_with(Default::default)
+            .insert(name.into(), var);
+        self
+    }
+}
+
+/// Settings of the environments of a Pages project
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct DeploymentConfigs {
+    /// Production environment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub production: Option<DeploymentConfig>,
+    /// Preview environment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<DeploymentConfig>,
+}
+
+/// A Pages project
+#[derive(Deserialize, Clone, Debug)]
+pub struct PagesProject {
+    /// Project ID
+    pub id: String,
+    /// Name of the project
+    pub name: String,
+    /// `*.pages.dev` subdomain of the project
+    pub subdomain: Option<String>,
+    /// Custom domains of the project
+    #[serde(default)]
+    pub domains: Vec<String>,
+    /// Branch deployed to production
+    pub production_branch: Option<String>,
+    /// Build settings
+    #[serde(default)]
+    pub build_config: BuildConfig,
+    /// Environment settings
+    #[serde(default)]
+    pub deployment_configs: DeploymentConfigs,
+    /// Last deployment of the project
+    pub latest_deployment: Option<Deployment>,
+    /// Deployment currently served in production
+    pub canonical_deployment: Option<Deployment>,
+    /// Creation time
+    pub created_on: String,
+}
+
+/// [Create Project](https://developers.cloudflare.com/api/operations/pages-project-create-project) API
+#[derive(Serialize, Debug)]
+pub struct CreatePagesProject {
+    name: String,
+    production_branch: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    build_config: Option<BuildConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deployment_configs: Option<DeploymentConfigs>,
+}
+
+impl CreatePagesProject {
+    /// Create the project `name` deploying `production_branch` to production
+    pub fn new(name: impl Into<String>, production_branch: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            production_branch: production_branch.into(),
+            build_config: None,
+            deployment_configs: None,
+        }
+    }
+
+    /// Build settings of the project
+    pub fn build_config(mut self, build_config: BuildConfig) -> Self {
+        self.build_config = Some(build_config);
+        self
+    }
+
+    /// Environment settings of the project
+    pub fn deployment_configs(mut self, deployment_configs: DeploymentConfigs) -> Self {
+        self.deployment_configs = Some(deployment_configs);
+        self
+    }
+}
+
+/// Success response from the [Create Project](https://developers.cloudflare.com/api/operations/pages-project-create-project#Responses) API
+pub type CreatePagesProjectRes = PagesProject;
+
+impl CfReqMeta for CreatePagesProject {
+    const METHOD: Method = Method::POST;
+    type Response = CreatePagesProjectRes;
+}
+
+impl CfReqAuth for CreatePagesProject {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/pages/projects")
+    }
+}
+
+/// [Get Projects](https://developers.cloudflare.com/api/operations/pages-project-get-projects) API
+#[derive(Serialize, Default)]
+pub struct ListPagesProjects {
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListPagesProjects {
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of projects per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [Get Projects](https://developers.cloudflare.com/api/operations/pages-project-get-projects#Responses) API
+#[derive(Deserialize)]
+pub struct ListPagesProjectsRes {
+    /// Projects in this page
+    pub projects: Vec<PagesProject>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListPagesProjects {
+    const METHOD: Method = Method::GET;
+    type Response = ListPagesProjectsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (projects, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListPagesProjectsRes {
+            projects,
+            page_info,
+        })
+    }
+}
+
+impl CfReqAuth for ListPagesProjects {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/pages/projects")
+    }
+}
+
+/// [Get Project](https://developers.cloudflare.com/api/operations/pages-project-get-project) API
+#[derive(Serialize)]
+pub struct GetPagesProject {
+    #[serde(skip)]
+    project_name: String,
+}
+
+impl GetPagesProject {
+    /// Get the project `project_name`
+    pub fn new(project_name: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Project](https://developers.cloudflare.com/api/operations/pages-project-get-project#Responses) API
+pub type GetPagesProjectRes = PagesProject;
+
+impl CfReqMeta for GetPagesProject {
+    const METHOD: Method = Method::GET;
+    type Response = GetPagesProjectRes;
+}
+
+impl CfReqAuth for GetPagesProject {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/pages/projects/{}", self.project_name)
+    }
+}
+
+/// [Delete Project](https://developers.cloudflare.com/api/operations/pages-project-delete-project) API
+#[derive(Serialize)]
+pub struct DeletePagesProject {
+    #[serde(skip)]
+    project_name: String,
+}
+
+impl DeletePagesProject {
+    /// Delete the project `project_name` and all its deployments
+    pub fn new(project_name: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeletePagesProject {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(())
+    }
+}
+
+impl CfReqAuth for DeletePagesProject {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/pages/projects/{}", self.project_name)
+    }
+}
+
+/// Environment of a deployment
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeploymentEnvironment {
+    /// Production branch deployment
+    Production,
+    /// Any other branch
+    Preview,
+}
+
+/// Status of a deployment stage
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StageStatus {
+    /// Not started yet
+    Idle,
+    /// Running
+    Active,
+    /// Canceled
+    Canceled,
+    /// Finished successfully
+    Success,
+    /// Failed
+    Failure,
+    /// Skipped
+    Skipped,
+    /// A status unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// A stage of a deployment (queued, initialize, clone_repo, build or deploy)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeploymentStage {
+    /// Name of the stage
+    pub name: String,
+    /// Status of the stage
+    pub status: StageStatus,
+    /// Start time
+    pub started_on: Option<String>,
+    /// End time
+    pub ended_on: Option<String>,
+}
+
+/// Source commit of a deployment
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeploymentTriggerMetadata {
+    /// Branch deployed
+    pub branch: Option<String>,
+    /// Commit deployed
+    pub commit_hash: Option<String>,
+    /// Message of the deployed commit
+    pub commit_message: Option<String>,
+}
+
+/// What triggered a deployment
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeploymentTrigger {
+    /// `push` or `ad_hoc`
+    #[serde(rename = "type")]
+    pub trigger_type: String,
+    /// Source commit
+    pub metadata: DeploymentTriggerMetadata,
+}
+
+/// A deployment of a Pages project
+#[derive(Deserialize, Clone, Debug)]
+pub struct Deployment {
+    /// Deployment ID
+    pub id: String,
+    /// Short ID, used in the deployment URL
+    pub short_id: String,
+    /// Project ID
+    pub project_id: String,
+    /// Project name
+    pub project_name: String,
+    /// Environment of the deployment
+    pub environment: DeploymentEnvironment,
+    /// URL of the deployment
+    pub url: String,
+    /// Aliases of the deployment (e.g the branch URL)
+    pub aliases: Option<Vec<String>>,
+    /// Stage currently running, or the last one
+    pub latest_stage: DeploymentStage,
+    /// All stages of the deployment
+    #[serde(default)]
+    pub stages: Vec<DeploymentStage>,
+    /// What triggered the deployment
+    pub deployment_trigger: DeploymentTrigger,
+    /// Whether the deployment was skipped
+    #[serde(default)]
+    pub is_skipped: bool,
+    /// Creation time
+    pub created_on: String,
+    /// Last update time
+    pub modified_on: String,
+}
+
+impl Deployment {
+    /// Whether the deployment finished successfully
+    pub fn is_success(&self) -> bool {
+        self.latest_stage.name == "deploy" && self.latest_stage.status == StageStatus::Success
+    }
+
+    /// Whether a stage of the deployment failed
+    pub fn is_failure(&self) -> bool {
+        self.latest_stage.status == StageStatus::Failure
+    }
+}
+
+/// [Create Deployment](https://developers.cloudflare.com/api/operations/pages-deployment-create-deployment) API
+/// Without a manifest, the project's git source is deployed.
+/// Files in the manifest must have been uploaded beforehand.
+/// Send with [crate::CloudflareAuth::send_auth_multipart]
+#[derive(Debug)]
+pub struct CreateDeployment {
+    project_name: String,
+    manifest: Option<BTreeMap<String, String>>,
+    branch: Option<String>,
+    commit_hash: Option<String>,
+    commit_message: Option<String>,
+}
+
+impl CreateDeployment {
+    /// Deploy the project `project_name`
+    pub fn new(project_name: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            manifest: None,
+            branch: None,
+            commit_hash: None,
+            commit_message: None,
+        }
+    }
+
+    /// Add the file at `path` (e.g `/index.html`) with the uploaded asset `hash` to the manifest
+    pub fn file(mut self, path: impl Into<String>, hash: impl Into<String>) -> Self {
+        self.manifest
+            .get_or_insert_with(Default::default)
+            .insert(path.into(), hash.into());
+        self
+    }
+
+    /// Branch of the deployment, the production branch deploys to production
+    pub fn branch(mut self, branch: impl Into<String>) -> Self {
+        self.branch = Some(branch.into());
+        self
+    }
+
+    /// Commit of the deployment
+    pub fn commit(mut self, hash: impl Into<String>, message: impl Into<String>) -> Self {
+        self.commit_hash = Some(hash.into());
+        self.commit_message = Some(message.into());
+        self
+    }
+}
+
+impl TryFrom<CreateDeployment> for Form {
+    type Error = Error;
+
+    fn try_from(value: CreateDeployment) -> Result<Self> {
+        let mut form = Form::new();
+        if let Some(manifest) = value.manifest {
+            form = form.text("manifest", serde_json::to_string(&manifest)?);
+        }
+        if let Some(branch) = value.branch {
+            form = form.text("branch", branch);
+        }
+        if let Some(commit_hash) = value.commit_hash {
+            form = form.text("commit_hash", commit_hash);
+        }
+        if let Some(commit_message) = value.commit_message {
+            form = form.text("commit_message", commit_message);
+        }
+        Ok(form)
+    }
+}
+
+/// Success response from the [Create Deployment](https://developers.cloudflare.com/api/operations/pages-deployment-create-deployment#Responses) API
+pub type CreateDeploymentRes = Deployment;
+
+impl CfReqMeta for CreateDeployment {
+    const METHOD: Method = Method::POST;
+    type Response = CreateDeploymentRes;
+}
+
+impl CfReqAuth for CreateDeployment {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/deployments",
+            self.project_name
+        )
+    }
+}
+
+/// [Get Deployments](https://developers.cloudflare.com/api/operations/pages-deployment-get-deployments) API
+#[derive(Serialize)]
+pub struct ListDeployments {
+    #[serde(skip)]
+    project_name: String,
+    env: Option<DeploymentEnvironment>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListDeployments {
+    /// List the deployments of the project `project_name`, latest first
+    pub fn new(project_name: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            env: None,
+            page: None,
+            per_page: None,
+        }
+    }
+
+    /// Only list deployments of `env`
+    pub fn env(mut self, env: DeploymentEnvironment) -> Self {
+        self.env = Some(env);
+        self
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of deployments per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [Get Deployments](https://developers.cloudflare.com/api/operations/pages-deployment-get-deployments#Responses) API
+#[derive(Deserialize)]
+pub struct ListDeploymentsRes {
+    /// Deployments in this page
+    pub deployments: Vec<Deployment>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListDeployments {
+    const METHOD: Method = Method::GET;
+    type Response = ListDeploymentsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (deployments, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListDeploymentsRes {
+            deployments,
+            page_info,
+        })
+    }
+}
+
+impl CfReqAuth for ListDeployments {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/deployments",
+            self.project_name
+        )
+    }
+}
+
+/// [Get Deployment Info](https://developers.cloudflare.com/api/operations/pages-deployment-get-deployment-info) API
+#[derive(Serialize)]
+pub struct GetDeployment {
+    #[serde(skip)]
+    project_name: String,
+    #[serde(skip)]
+    deployment_id: String,
+}
+
+impl GetDeployment {
+    /// Get the deployment `deployment_id` of the project `project_name`
+    pub fn new(project_name: impl Into<String>, deployment_id: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            deployment_id: deployment_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Deployment Info](https://developers.cloudflare.com/api/operations/pages-deployment-get-deployment-info#Responses) API
+pub type GetDeploymentRes = Deployment;
+
+impl CfReqMeta for GetDeployment {
+    const METHOD: Method = Method::GET;
+    type Response = GetDeploymentRes;
+}
+
+impl CfReqAuth for GetDeployment {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/deployments/{}",
+            self.project_name, self.deployment_id
+        )
+    }
+}
+
+/// A line of a deployment's logs
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeploymentLogLine {
+    /// Time of the line
+    pub ts: String,
+    /// Content of the line
+    pub line: String,
+}
+
+/// [Get Deployment Logs](https://developers.cloudflare.com/api/operations/pages-deployment-get-deployment-logs) API
+#[derive(Serialize)]
+pub struct GetDeploymentLogs {
+    #[serde(skip)]
+    project_name: String,
+    #[serde(skip)]
+    deployment_id: String,
+}
+
+impl GetDeploymentLogs {
+    /// Get the build logs of the deployment `deployment_id` of the project `project_name`
+    pub fn new(project_name: impl Into<String>, deployment_id: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            deployment_id: deployment_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Deployment Logs](https://developers.cloudflare.com/api/operations/pages-deployment-get-deployment-logs#Responses) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GetDeploymentLogsRes {
+    /// Number of lines
+    pub total: u32,
+    /// Log lines, oldest first
+    pub data: Vec<DeploymentLogLine>,
+}
+
+impl CfReqMeta for GetDeploymentLogs {
+    const METHOD: Method = Method::GET;
+    type Response = GetDeploymentLogsRes;
+}
+
+impl CfReqAuth for GetDeploymentLogs {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/deployments/{}/history/logs",
+            self.project_name, self.deployment_id
+        )
+    }
+}
+
+/// [Retry Deployment](https://developers.cloudflare.com/api/operations/pages-deployment-retry-deployment) API
+#[derive(Serialize)]
+pub struct RetryDeployment {
+    #[serde(skip)]
+    project_name: String,
+    #[serde(skip)]
+    deployment_id: String,
+}
+
+impl RetryDeployment {
+    /// Retry the deployment `deployment_id` of the project `project_name`
+    pub fn new(project_name: impl Into<String>, deployment_id: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            deployment_id: deployment_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Retry Deployment](https://developers.cloudflare.com/api/operations/pages-deployment-retry-deployment#Responses) API
+/// The retry is a new deployment
+pub type RetryDeploymentRes = Deployment;
+
+impl CfReqMeta for RetryDeployment {
+    const METHOD: Method = Method::POST;
+    type Response = RetryDeploymentRes;
+}
+
+impl CfReqAuth for RetryDeployment {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/deployments/{}/retry",
+            self.project_name, self.deployment_id
+        )
+    }
+}
+
+/// [Rollback Deployment](https://developers.cloudflare.com/api/operations/pages-deployment-rollback-deployment) API
+/// Only successful production deployments can be rolled back to
+#[derive(Serialize)]
+pub struct RollbackDeployment {
+    #[serde(skip)]
+    project_name: String,
+    #[serde(skip)]
+    deployment_id: String,
+}
+
+impl RollbackDeployment {
+    /// Serve the deployment `deployment_id` of the project `project_name` in production
+    pub fn new(project_name: impl Into<String>, deployment_id: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            deployment_id: deployment_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Rollback Deployment](https://developers.cloudflare.com/api/operations/pages-deployment-rollback-deployment#Responses) API
+pub type RollbackDeploymentRes = Deployment;
+
+impl CfReqMeta for RollbackDeployment {
+    const METHOD: Method = Method::POST;
+    type Response = RollbackDeploymentRes;
+}
+
+impl CfReqAuth for RollbackDeployment {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/deployments/{}/rollback",
+            self.project_name, self.deployment_id
+        )
+    }
+}
+
+/// [Delete Deployment](https://developers.cloudflare.com/api/operations/pages-deployment-delete-deployment) API
+#[derive(Serialize)]
+pub struct DeleteDeployment {
+    #[serde(skip)]
+    project_name: String,
+    #[serde(skip)]
+    deployment_id: String,
+    #[serde(skip)]
+    force: bool,
+}
+
+impl DeleteDeployment {
+    /// Delete the deployment `deployment_id` of the project `project_name`
+    pub fn new(project_name: impl Into<String>, deployment_id: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            deployment_id: deployment_id.into(),
+            force: false,
+        }
+    }
+
+    /// Also delete the deployment if it has aliases (e.g it is the latest of its branch)
+    pub fn force(mut self) -> Self {
+        self.force = true;
+        self
+    }
+}
+
+impl CfReqMeta for DeleteDeployment {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(())
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        if self.force {
+            vec![("force", "true".to_string())]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl CfReqAuth for DeleteDeployment {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/deployments/{}",
+            self.project_name, self.deployment_id
+        )
+    }
+}
+
+/// Status of a Pages custom domain
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PagesDomainStatus {
+    /// Domain was just added
+    Initializing,
+    /// Waiting for validation
+    Pending,
+    /// Domain is served by the project
+    Active,
+    /// Domain was deactivated
+    Deactivated,
+    /// Domain is blocked
+    Blocked,
+    /// Validation failed, see [PagesDomain::validation_data]
+    Error,
+    /// A status unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Method used to validate a Pages custom domain
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DomainValidationMethod {
+    /// HTTP request to the domain
+    Http,
+    /// TXT record on the domain
+    Txt,
+}
+
+/// Validation state of a Pages custom domain
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DomainValidationData {
+    /// Status of the validation
+    pub status: PagesDomainStatus,
+    /// Validation method
+    pub method: DomainValidationMethod,
+    /// Name of the TXT record to create, for [DomainValidationMethod::Txt]
+    pub txt_name: Option<String>,
+    /// Value of the TXT record to create, for [DomainValidationMethod::Txt]
+    pub txt_value: Option<String>,
+    /// Why the validation failed
+    pub error_message: Option<String>,
+}
+
+/// Ownership verification state of a Pages custom domain
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DomainVerificationData {
+    /// Status of the verification
+    pub status: PagesDomainStatus,
+    /// Why the verification failed
+    pub error_message: Option<String>,
+}
+
+/// A custom domain of a Pages project
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PagesDomain {
+    /// Domain ID
+    pub id: String,
+    /// Domain name
+    pub name: String,
+    /// Status of the domain
+    pub status: PagesDomainStatus,
+    /// Validation state, poll until [PagesDomain::status] is [PagesDomainStatus::Active]
+    pub validation_data: DomainValidationData,
+    /// Ownership verification state
+    pub verification_data: DomainVerificationData,
+    /// ID of the cloudflare zone of the domain, if any
+    pub zone_tag: Option<String>,
+    /// Creation time
+    pub created_on: String,
+}
+
+impl PagesDomain {
+    /// Whether the domain is served by the project
+    pub fn is_active(&self) -> bool {
+        self.status == PagesDomainStatus::Active
+    }
+}
+
+/// [Add domain](https://developers.cloudflare.com/api/operations/pages-domains-add-domain) API
+#[derive(Serialize)]
+pub struct AddPagesDomain {
+    #[serde(skip)]
+    project_name: String,
+    name: String,
+}
+
+impl AddPagesDomain {
+    /// Attach the domain `name` to the project `project_name`
+    pub fn new(project_name: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            name: name.into(),
+        }
+    }
+}
+
+/// Success response from the [Add domain](https://developers.cloudflare.com/api/operations/pages-domains-add-domain#Responses) API
+pub type AddPagesDomainRes = PagesDomain;
+
+impl CfReqMeta for AddPagesDomain {
+    const METHOD: Method = Method::POST;
+    type Response = AddPagesDomainRes;
+}
+
+impl CfReqAuth for AddPagesDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/domains",
+            self.project_name
+        )
+    }
+}
+
+/// [Get domains](https://developers.cloudflare.com/api/operations/pages-domains-get-domains) API
+#[derive(Serialize)]
+pub struct ListPagesDomains {
+    #[serde(skip)]
+    project_name: String,
+}
+
+impl ListPagesDomains {
+    /// List the domains of the project `project_name`
+    pub fn new(project_name: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Get domains](https://developers.cloudflare.com/api/operations/pages-domains-get-domains#Responses) API
+pub type ListPagesDomainsRes = Vec<PagesDomain>;
+
+impl CfReqMeta for ListPagesDomains {
+    const METHOD: Method = Method::GET;
+    type Response = ListPagesDomainsRes;
+}
+
+impl CfReqAuth for ListPagesDomains {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/domains",
+            self.project_name
+        )
+    }
+}
+
+/// [Get domain](https://developers.cloudflare.com/api/operations/pages-domains-get-domain) API
+#[derive(Serialize)]
+pub struct GetPagesDomain {
+    #[serde(skip)]
+    project_name: String,
+    #[serde(skip)]
+    domain_name: String,
+}
+
+impl GetPagesDomain {
+    /// Get the domain `domain_name` of the project `project_name`
+    pub fn new(project_name: impl Into<String>, domain_name: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            domain_name: domain_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Get domain](https://developers.cloudflare.com/api/operations/pages-domains-get-domain#Responses) API
+pub type GetPagesDomainRes = PagesDomain;
+
+impl CfReqMeta for GetPagesDomain {
+    const METHOD: Method = Method::GET;
+    type Response = GetPagesDomainRes;
+}
+
+impl CfReqAuth for GetPagesDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/domains/{}",
+            self.project_name, self.domain_name
+        )
+    }
+}
+
+/// [Patch domain](https://developers.cloudflare.com/api/operations/pages-domains-patch-domain) API
+/// Retries the validation of a domain
+#[derive(Serialize)]
+pub struct RevalidatePagesDomain {
+    #[serde(skip)]
+    project_name: String,
+    #[serde(skip)]
+    domain_name: String,
+}
+
+impl RevalidatePagesDomain {
+    /// Retry the validation of the domain `domain_name` of the project `project_name`
+    pub fn new(project_name: impl Into<String>, domain_name: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            domain_name: domain_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Patch domain](https://developers.cloudflare.com/api/operations/pages-domains-patch-domain#Responses) API
+pub type RevalidatePagesDomainRes = PagesDomain;
+
+impl CfReqMeta for RevalidatePagesDomain {
+    const METHOD: Method = Method::PATCH;
+    type Response = RevalidatePagesDomainRes;
+}
+
+impl CfReqAuth for RevalidatePagesDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/domains/{}",
+            self.project_name, self.domain_name
+        )
+    }
+}
+
+/// [Delete domain](https://developers.cloudflare.com/api/operations/pages-domains-delete-domain) API
+/// A domain still in use results in an error for which [crate::Error::has_cf_code] is true with [PAGES_DOMAIN_IN_USE]
+#[derive(Serialize)]
+pub struct DeletePagesDomain {
+    #[serde(skip)]
+    project_name: String,
+    #[serde(skip)]
+    domain_name: String,
+}
+
+impl DeletePagesDomain {
+    /// Detach the domain `domain_name` from the project `project_name`
+    pub fn new(project_name: impl Into<String>, domain_name: impl Into<String>) -> Self {
+        Self {
+            project_name: project_name.into(),
+            domain_name: domain_name.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeletePagesDomain {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(())
+    }
+}
+
+impl CfReqAuth for DeletePagesDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/pages/projects/{}/domains/{}",
+            self.project_name, self.domain_name
+        )
+    }
+}
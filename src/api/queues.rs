@@ -0,0 +1,1014 @@
+//! Cloudflare Queues related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/queues/)
+use std::{collections::HashMap, time::Duration};
+
+use crate::{CfReqAuth, CfReqMeta, CfSuccessResWithInfo, PageInfo, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Queue helper
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Queue {
+    /// Queue ID
+    queue_id: String,
+}
+
+impl Queue {
+    /// Create a new queue helper
+    pub fn new(queue_id: String) -> Self {
+        Self { queue_id }
+    }
+
+    /// Queue ID
+    pub fn id(&self) -> &str {
+        &self.queue_id
+    }
+
+    /// Push a single message to the queue
+    pub fn push(&self, message: impl Into<QueueMessage>) -> PushMessage {
+        PushMessage {
+            queue_id: self.queue_id.clone(),
+            message: message.into(),
+        }
+    }
+
+    /// Push a batch of messages to the queue
+    pub fn push_batch(&self) -> PushMessages {
+        PushMessages {
+            queue_id: self.queue_id.clone(),
+            messages: Vec::new(),
+            delay_seconds: None,
+        }
+    }
+
+    /// Pull messages from the queue, requires a `http_pull` consumer
+    pub fn pull(&self) -> PullMessages {
+        PullMessages {
+            queue_id: self.queue_id.clone(),
+            batch_size: None,
+            visibility_timeout_ms: None,
+        }
+    }
+
+    /// Acknowledge or retry messages pulled from the queue
+    pub fn ack(&self) -> AckMessages {
+        AckMessages {
+            queue_id: self.queue_id.clone(),
+            acks: Vec::new(),
+            retries: Vec::new(),
+        }
+    }
+}
+
+/// Settings of a queue
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct QueueSettings {
+    /// Seconds messages are delayed before being delivered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_delay: Option<u64>,
+    /// Seconds messages are kept before being deleted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_retention_period: Option<u64>,
+    /// Whether delivery to consumers is paused
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_paused: Option<bool>,
+}
+
+impl QueueSettings {
+    /// Delay messages before delivering them
+    pub fn delivery_delay(mut self, delay: Duration) -> Self {
+        self.delivery_delay = Some(delay.as_secs());
+        self
+    }
+
+    /// Keep undelivered messages for the given duration
+    pub fn message_retention_period(mut self, period: Duration) -> Self {
+        self.message_retention_period = Some(period.as_secs());
+        self
+    }
+
+    /// Pause or resume delivery to consumers
+    pub fn delivery_paused(mut self, paused: bool) -> Self {
+        self.delivery_paused = Some(paused);
+        self
+    }
+}
+
+/// A producer sending messages to a queue
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct QueueProducer {
+    /// Type of the producer (e.g `worker`, `r2_bucket`)
+    #[serde(rename = "type")]
+    pub producer_type: String,
+    /// Name of the producing worker
+    pub script: Option<String>,
+    /// Name of the producing bucket
+    pub bucket_name: Option<String>,
+}
+
+/// A queue
+#[derive(Serialize, Deserialize)]
+pub struct QueueDetails {
+    /// Identifier of the queue
+    pub queue_id: String,
+    /// Name of the queue
+    pub queue_name: String,
+    /// RFC3339 timestamp of when the queue was created
+    pub created_on: Option<String>,
+    /// RFC3339 timestamp of when the queue was last modified
+    pub modified_on: Option<String>,
+    /// Producers of the queue
+    #[serde(default)]
+    pub producers: Vec<QueueProducer>,
+    /// Consumers of the queue
+    #[serde(default)]
+    pub consumers: Vec<QueueConsumer>,
+    /// Settings of the queue
+    #[serde(default)]
+    pub settings: QueueSettings,
+}
+
+impl From<QueueDetails> for Queue {
+    fn from(value: QueueDetails) -> Self {
+        Self::new(value.queue_id)
+    }
+}
+
+/// [Create Queue](https://developers.cloudflare.com/api/operations/queues-create) API
+#[derive(Serialize)]
+pub struct CreateQueue {
+    queue_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<QueueSettings>,
+}
+
+impl CreateQueue {
+    /// Create a queue with the given name
+    pub fn new(queue_name: impl Into<String>) -> Self {
+        Self {
+            queue_name: queue_name.into(),
+            settings: None,
+        }
+    }
+
+    /// Settings of the queue
+    pub fn settings(mut self, settings: QueueSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+}
+
+/// Success response from the [Create Queue](https://developers.cloudflare.com/api/operations/queues-create#Responses) API
+pub type CreateQueueRes = QueueDetails;
+
+impl CfReqMeta for CreateQueue {
+    const METHOD: Method = Method::POST;
+    type Response = CreateQueueRes;
+}
+
+impl CfReqAuth for CreateQueue {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/queues")
+    }
+}
+
+/// [List Queues](https://developers.cloudflare.com/api/operations/queues-list) API
+#[derive(Serialize, Default)]
+pub struct ListQueues {
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListQueues {
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of queues per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List Queues](https://developers.cloudflare.com/api/operations/queues-list#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListQueuesRes {
+    /// Queues in this page
+    pub queues: Vec<QueueDetails>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListQueues {
+    const METHOD: Method = Method::GET;
+    type Response = ListQueuesRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (queues, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListQueuesRes { queues, page_info })
+    }
+}
+
+impl CfReqAuth for ListQueues {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/queues")
+    }
+}
+
+/// [Get Queue](https://developers.cloudflare.com/api/operations/queues-get) API
+#[derive(Serialize)]
+pub struct GetQueue {
+    #[serde(skip)]
+    queue_id: String,
+}
+
+impl GetQueue {
+    /// Retrieve the queue with the given id
+    pub fn new(queue_id: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Queue](https://developers.cloudflare.com/api/operations/queues-get#Responses) API
+pub type GetQueueRes = QueueDetails;
+
+impl CfReqMeta for GetQueue {
+    const METHOD: Method = Method::GET;
+    type Response = GetQueueRes;
+}
+
+impl CfReqAuth for GetQueue {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/queues/{}", self.queue_id)
+    }
+}
+
+/// [Update Queue](https://developers.cloudflare.com/api/operations/queues-update) API
+#[derive(Serialize)]
+pub struct UpdateQueue {
+    #[serde(skip)]
+    queue_id: String,
+    queue_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<QueueSettings>,
+}
+
+impl UpdateQueue {
+    /// Update the queue with the given id, renaming it to `queue_name`
+    pub fn new(queue_id: impl Into<String>, queue_name: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+            queue_name: queue_name.into(),
+            settings: None,
+        }
+    }
+
+    /// Settings of the queue, only the settings set are changed
+    pub fn settings(mut self, settings: QueueSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+}
+
+/// Success response from the [Update Queue](https://developers.cloudflare.com/api/operations/queues-update#Responses) API
+pub type UpdateQueueRes = QueueDetails;
+
+impl CfReqMeta for UpdateQueue {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateQueueRes;
+}
+
+impl CfReqAuth for UpdateQueue {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/queues/{}", self.queue_id)
+    }
+}
+
+/// [Delete Queue](https://developers.cloudflare.com/api/operations/queues-delete) API
+#[derive(Serialize)]
+pub struct DeleteQueue {
+    #[serde(skip)]
+    queue_id: String,
+}
+
+impl DeleteQueue {
+    /// Delete the queue with the given id
+    pub fn new(queue_id: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Queue](https://developers.cloudflare.com/api/operations/queues-delete#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteQueueRes;
+
+impl CfReqMeta for DeleteQueue {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteQueueRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(DeleteQueueRes)
+    }
+}
+
+impl CfReqAuth for DeleteQueue {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/queues/{}", self.queue_id)
+    }
+}
+
+/// Content type of a queue message
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageContentType {
+    /// JSON value
+    Json,
+    /// Plain text
+    Text,
+    /// V8 serialized value, base64 encoded
+    V8,
+    /// Raw bytes, base64 encoded
+    Bytes,
+}
+
+/// Body of a queue message
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum MessageBody {
+    /// Text body, also used for base64 encoded `v8` and `bytes` bodies
+    Text(String),
+    /// JSON body
+    Json(serde_json::Value),
+}
+
+impl MessageBody {
+    /// Content type matching this body
+    pub fn content_type(&self) -> MessageContentType {
+        match self {
+            Self::Text(_) => MessageContentType::Text,
+            Self::Json(_) => MessageContentType::Json,
+        }
+    }
+}
+
+impl From<String> for MessageBody {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for MessageBody {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<serde_json::Value> for MessageBody {
+    fn from(value: serde_json::Value) -> Self {
+        Self::Json(value)
+    }
+}
+
+/// A message to be sent to a queue
+#[derive(Serialize, Clone, Debug)]
+pub struct QueueMessage {
+    body: MessageBody,
+    content_type: MessageContentType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delay_seconds: Option<u64>,
+}
+
+impl QueueMessage {
+    /// Create a message, the content type is inferred from the body
+    pub fn new(body: impl Into<MessageBody>) -> Self {
+        let body = body.into();
+        Self {
+            content_type: body.content_type(),
+            body,
+            delay_seconds: None,
+        }
+    }
+
+    /// Override the content type of the message (e.g for `v8` or `bytes` bodies)
+    pub fn content_type(mut self, content_type: MessageContentType) -> Self {
+        self.content_type = content_type;
+        self
+    }
+
+    /// Delay the delivery of this message
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay_seconds = Some(delay.as_secs());
+        self
+    }
+}
+
+impl<T: Into<MessageBody>> From<T> for QueueMessage {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// [Push Message](https://developers.cloudflare.com/api/operations/queues-push-message) API
+#[derive(Serialize)]
+pub struct PushMessage {
+    #[serde(skip)]
+    queue_id: String,
+    #[serde(flatten)]
+    message: QueueMessage,
+}
+
+impl PushMessage {
+    /// Push a message to the queue with the given id
+    pub fn new(queue_id: impl Into<String>, message: impl Into<QueueMessage>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Success response from the [Push Message](https://developers.cloudflare.com/api/operations/queues-push-message#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct PushMessageRes;
+
+impl CfReqMeta for PushMessage {
+    const METHOD: Method = Method::POST;
+    type Response = PushMessageRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(PushMessageRes)
+    }
+}
+
+impl CfReqAuth for PushMessage {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/queues/{}/messages", self.queue_id)
+    }
+}
+
+/// [Push Message Batch](https://developers.cloudflare.com/api/operations/queues-push-messages) API
+#[derive(Serialize)]
+pub struct PushMessages {
+    #[serde(skip)]
+    queue_id: String,
+    messages: Vec<QueueMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delay_seconds: Option<u64>,
+}
+
+impl PushMessages {
+    /// Push a batch of messages to the queue with the given id
+    pub fn new(queue_id: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+            messages: Vec::new(),
+            delay_seconds: None,
+        }
+    }
+
+    /// Add a message to the batch
+    pub fn add_message(mut self, message: impl Into<QueueMessage>) -> Self {
+        self.messages.push(message.into());
+        self
+    }
+
+    /// Delay the delivery of messages without their own delay
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay_seconds = Some(delay.as_secs());
+        self
+    }
+}
+
+/// Success response from the [Push Message Batch](https://developers.cloudflare.com/api/operations/queues-push-messages#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct PushMessagesRes;
+
+impl CfReqMeta for PushMessages {
+    const METHOD: Method = Method::POST;
+    type Response = PushMessagesRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(PushMessagesRes)
+    }
+}
+
+impl CfReqAuth for PushMessages {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/queues/{}/messages/batch",
+            self.queue_id
+        )
+    }
+}
+
+/// [Pull Messages](https://developers.cloudflare.com/api/operations/queues-pull-messages) API
+#[derive(Serialize)]
+pub struct PullMessages {
+    #[serde(skip)]
+    queue_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    batch_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility_timeout_ms: Option<u64>,
+}
+
+impl PullMessages {
+    /// Pull messages from the queue with the given id
+    pub fn new(queue_id: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+            batch_size: None,
+            visibility_timeout_ms: None,
+        }
+    }
+
+    /// Maximum number of messages to pull
+    pub fn batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Duration after which pulled but unacknowledged messages are redelivered
+    pub fn visibility_timeout(mut self, timeout: Duration) -> Self {
+        self.visibility_timeout_ms = Some(timeout.as_millis() as u64);
+        self
+    }
+}
+
+/// A message pulled from a queue
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PulledMessage {
+    /// Message ID
+    pub id: String,
+    /// Lease of the message, used to acknowledge or retry it with [AckMessages]
+    pub lease_id: String,
+    /// Message body, JSON bodies are encoded as a string
+    pub body: String,
+    /// Number of delivery attempts
+    pub attempts: u32,
+    /// Unix timestamp of when the message was sent, in milliseconds
+    pub timestamp_ms: u64,
+    /// Message metadata
+    #[serde(default)]
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+impl PulledMessage {
+    /// Content type of the message
+    pub fn content_type(&self) -> Option<MessageContentType> {
+        self.metadata
+            .get("CF-Content-Type")
+            .and_then(|ty| serde_json::from_value(ty.clone()).ok())
+    }
+}
+
+/// Success response from the [Pull Messages](https://developers.cloudflare.com/api/operations/queues-pull-messages#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct PullMessagesRes {
+    /// Pulled messages
+    pub messages: Vec<PulledMessage>,
+    /// Approximate number of messages left in the queue
+    pub message_backlog_count: Option<u64>,
+}
+
+impl CfReqMeta for PullMessages {
+    const METHOD: Method = Method::POST;
+    type Response = PullMessagesRes;
+}
+
+impl CfReqAuth for PullMessages {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/queues/{}/messages/pull",
+            self.queue_id
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct MessageAck {
+    lease_id: String,
+}
+
+#[derive(Serialize)]
+struct MessageRetry {
+    lease_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delay_seconds: Option<u64>,
+}
+
+/// [Acknowledge Messages](https://developers.cloudflare.com/api/operations/queues-ack-messages) API
+#[derive(Serialize)]
+pub struct AckMessages {
+    #[serde(skip)]
+    queue_id: String,
+    acks: Vec<MessageAck>,
+    retries: Vec<MessageRetry>,
+}
+
+impl AckMessages {
+    /// Acknowledge or retry messages pulled from the queue with the given id
+    pub fn new(queue_id: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+            acks: Vec::new(),
+            retries: Vec::new(),
+        }
+    }
+
+    /// Acknowledge a pulled message, removing it from the queue
+    pub fn ack(self, message: &PulledMessage) -> Self {
+        self.ack_lease(message.lease_id.clone())
+    }
+
+    /// Acknowledge the message holding the given lease
+    pub fn ack_lease(mut self, lease_id: impl Into<String>) -> Self {
+        self.acks.push(MessageAck {
+            lease_id: lease_id.into(),
+        });
+        self
+    }
+
+    /// Retry a pulled message, optionally delaying its redelivery
+    pub fn retry(self, message: &PulledMessage, delay: Option<Duration>) -> Self {
+        self.retry_lease(message.lease_id.clone(), delay)
+    }
+
+    /// Retry the message holding the given lease, optionally delaying its redelivery
+    pub fn retry_lease(mut self, lease_id: impl Into<String>, delay: Option<Duration>) -> Self {
+        self.retries.push(MessageRetry {
+            lease_id: lease_id.into(),
+            delay_seconds: delay.map(|d| d.as_secs()),
+        });
+        self
+    }
+}
+
+/// Success response from the [Acknowledge Messages](https://developers.cloudflare.com/api/operations/queues-ack-messages#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct AckMessagesRes {
+    /// Number of messages acknowledged
+    #[serde(rename = "ackCount")]
+    pub ack_count: u64,
+    /// Number of messages retried
+    #[serde(rename = "retryCount")]
+    pub retry_count: u64,
+    /// Leases that could not be acknowledged or retried
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+impl CfReqMeta for AckMessages {
+    const METHOD: Method = Method::POST;
+    type Response = AckMessagesRes;
+}
+
+impl CfReqAuth for AckMessages {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/queues/{}/messages/ack",
+            self.queue_id
+        )
+    }
+}
+
+/// Type of a queue consumer
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsumerType {
+    /// Worker receiving batches of messages
+    Worker,
+    /// HTTP client pulling messages with [PullMessages]
+    HttpPull,
+}
+
+/// Settings of a queue consumer, only the settings set are sent
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct QueueConsumerSettings {
+    /// Maximum number of messages per batch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_size: Option<u32>,
+    /// Maximum number of retries before a message is dropped or sent to the dead letter queue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
+    /// Milliseconds to wait for a batch to fill up
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_wait_time_ms: Option<u64>,
+    /// Maximum number of concurrent worker invocations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrency: Option<u32>,
+    /// Seconds retried messages are delayed before being redelivered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_delay: Option<u64>,
+    /// Milliseconds pulled messages stay invisible, for `http_pull` consumers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility_timeout_ms: Option<u64>,
+}
+
+impl QueueConsumerSettings {
+    /// Maximum number of messages per batch
+    pub fn batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Maximum number of retries for a message
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Maximum time to wait for a batch to fill up
+    pub fn max_wait_time(mut self, wait: Duration) -> Self {
+        self.max_wait_time_ms = Some(wait.as_millis() as u64);
+        self
+    }
+
+    /// Maximum number of concurrent worker invocations
+    pub fn max_concurrency(mut self, max_concurrency: u32) -> Self {
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+
+    /// Delay before retried messages are redelivered
+    pub fn retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = Some(delay.as_secs());
+        self
+    }
+
+    /// Duration pulled messages stay invisible to other pulls
+    pub fn visibility_timeout(mut self, timeout: Duration) -> Self {
+        self.visibility_timeout_ms = Some(timeout.as_millis() as u64);
+        self
+    }
+}
+
+/// A queue consumer
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct QueueConsumer {
+    /// Consumer ID
+    pub consumer_id: String,
+    /// Type of the consumer
+    #[serde(rename = "type")]
+    pub consumer_type: ConsumerType,
+    /// Name of the consuming worker
+    #[serde(alias = "script_name")]
+    pub script: Option<String>,
+    /// Name of the queue receiving messages which ran out of retries
+    pub dead_letter_queue: Option<String>,
+    /// RFC3339 timestamp of when the consumer was created
+    pub created_on: Option<String>,
+    /// Settings of the consumer
+    #[serde(default)]
+    pub settings: QueueConsumerSettings,
+}
+
+/// [Create Queue Consumer](https://developers.cloudflare.com/api/operations/queues-create-consumer) API
+#[derive(Serialize)]
+pub struct CreateQueueConsumer {
+    #[serde(skip)]
+    queue_id: String,
+    #[serde(rename = "type")]
+    consumer_type: ConsumerType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dead_letter_queue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<QueueConsumerSettings>,
+}
+
+impl CreateQueueConsumer {
+    /// Add a worker consumer to the queue with the given id
+    pub fn worker(queue_id: impl Into<String>, script_name: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+            consumer_type: ConsumerType::Worker,
+            script_name: Some(script_name.into()),
+            dead_letter_queue: None,
+            settings: None,
+        }
+    }
+
+    /// Add a HTTP pull consumer to the queue with the given id
+    pub fn http_pull(queue_id: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+            consumer_type: ConsumerType::HttpPull,
+            script_name: None,
+            dead_letter_queue: None,
+            settings: None,
+        }
+    }
+
+    /// Name of the queue receiving messages which ran out of retries
+    pub fn dead_letter_queue(mut self, queue_name: impl Into<String>) -> Self {
+        self.dead_letter_queue = Some(queue_name.into());
+        self
+    }
+
+    /// Settings of the consumer
+    pub fn settings(mut self, settings: QueueConsumerSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+}
+
+/// Success response from the [Create Queue Consumer](https://developers.cloudflare.com/api/operations/queues-create-consumer#Responses) API
+pub type CreateQueueConsumerRes = QueueConsumer;
+
+impl CfReqMeta for CreateQueueConsumer {
+    const METHOD: Method = Method::POST;
+    type Response = CreateQueueConsumerRes;
+}
+
+impl CfReqAuth for CreateQueueConsumer {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/queues/{}/consumers", self.queue_id)
+    }
+}
+
+/// [List Queue Consumers](https://developers.cloudflare.com/api/operations/queues-list-consumers) API
+#[derive(Serialize)]
+pub struct ListQueueConsumers {
+    #[serde(skip)]
+    queue_id: String,
+}
+
+impl ListQueueConsumers {
+    /// List consumers of the queue with the given id
+    pub fn new(queue_id: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+        }
+    }
+}
+
+/// Success response from the [List Queue Consumers](https://developers.cloudflare.com/api/operations/queues-list-consumers#Responses) API
+pub type ListQueueConsumersRes = Vec<QueueConsumer>;
+
+impl CfReqMeta for ListQueueConsumers {
+    const METHOD: Method = Method::GET;
+    type Response = ListQueueConsumersRes;
+}
+
+impl CfReqAuth for ListQueueConsumers {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/queues/{}/consumers", self.queue_id)
+    }
+}
+
+/// [Update Queue Consumer](https://developers.cloudflare.com/api/operations/queues-update-consumer) API
+#[derive(Serialize)]
+pub struct UpdateQueueConsumer {
+    #[serde(skip)]
+    queue_id: String,
+    #[serde(skip)]
+    consumer_id: String,
+    #[serde(rename = "type")]
+    consumer_type: ConsumerType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dead_letter_queue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<QueueConsumerSettings>,
+}
+
+impl UpdateQueueConsumer {
+    /// Update a worker consumer of the queue with the given id
+    pub fn worker(
+        queue_id: impl Into<String>,
+        consumer_id: impl Into<String>,
+        script_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+            consumer_id: consumer_id.into(),
+            consumer_type: ConsumerType::Worker,
+            script_name: Some(script_name.into()),
+            dead_letter_queue: None,
+            settings: None,
+        }
+    }
+
+    /// Update a HTTP pull consumer of the queue with the given id
+    pub fn http_pull(queue_id: impl Into<String>, consumer_id: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+            consumer_id: consumer_id.into(),
+            consumer_type: ConsumerType::HttpPull,
+            script_name: None,
+            dead_letter_queue: None,
+            settings: None,
+        }
+    }
+
+    /// Name of the queue receiving messages which ran out of retries
+    pub fn dead_letter_queue(mut self, queue_name: impl Into<String>) -> Self {
+        self.dead_letter_queue = Some(queue_name.into());
+        self
+    }
+
+    /// Settings of the consumer, only the settings set are changed
+    pub fn settings(mut self, settings: QueueConsumerSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+}
+
+/// Success response from the [Update Queue Consumer](https://developers.cloudflare.com/api/operations/queues-update-consumer#Responses) API
+pub type UpdateQueueConsumerRes = QueueConsumer;
+
+impl CfReqMeta for UpdateQueueConsumer {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateQueueConsumerRes;
+}
+
+impl CfReqAuth for UpdateQueueConsumer {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/queues/{}/consumers/{}",
+            self.queue_id, self.consumer_id
+        )
+    }
+}
+
+/// [Delete Queue Consumer](https://developers.cloudflare.com/api/operations/queues-delete-consumer) API
+#[derive(Serialize)]
+pub struct DeleteQueueConsumer {
+    #[serde(skip)]
+    queue_id: String,
+    #[serde(skip)]
+    consumer_id: String,
+}
+
+impl DeleteQueueConsumer {
+    /// Delete the consumer with the given id from the queue
+    pub fn new(queue_id: impl Into<String>, consumer_id: impl Into<String>) -> Self {
+        Self {
+            queue_id: queue_id.into(),
+            consumer_id: consumer_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Queue Consumer](https://developers.cloudflare.com/api/operations/queues-delete-consumer#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteQueueConsumerRes;
+
+impl CfReqMeta for DeleteQueueConsumer {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteQueueConsumerRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(DeleteQueueConsumerRes)
+    }
+}
+
+impl CfReqAuth for DeleteQueueConsumer {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/queues/{}/consumers/{}",
+            self.queue_id, self.consumer_id
+        )
+    }
+}
@@ -0,0 +1,1298 @@
+//! Cloudflare R2 related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/r2/)
+use std::{
+    fmt,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    request::rfc3339, CfReqAuth, CfReqMeta, CfSuccessRes, CfSuccessResWithInfo, CursorInfo, Error,
+    Result,
+};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+fn check_bucket_name(name: &str) -> Result<()> {
+    let valid_chars = name
+        .bytes()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-');
+    let valid_ends = !name.starts_with('-') && !name.ends_with('-');
+    if !(3..=63).contains(&name.len()) || !valid_chars || !valid_ends {
+        return Err(Error::InvalidRequest(format!(
+            "invalid r2 bucket name {name:?}: must be 3-63 characters of lowercase letters, digits and hyphens, starting and ending with a letter or digit"
+        )));
+    }
+    Ok(())
+}
+
+/// Region an R2 bucket is placed close to
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LocationHint {
+    /// Asia-Pacific
+    Apac,
+    /// Eastern Europe
+    Eeur,
+    /// Eastern North America
+    Enam,
+    /// Western Europe
+    Weur,
+    /// Western North America
+    Wnam,
+    /// Oceania
+    Oc,
+}
+
+/// Default storage class of objects in an R2 bucket
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageClass {
+    /// Standard storage
+    Standard,
+    /// Infrequent access storage, cheaper to store but charged on retrieval
+    InfrequentAccess,
+}
+
+/// An R2 bucket
+#[derive(Serialize, Deserialize)]
+pub struct Bucket {
+    /// Name of the bucket
+    pub name: String,
+    /// RFC3339 timestamp of when the bucket was created
+    pub creation_date: Option<String>,
+    /// Location of the bucket
+    pub location: Option<String>,
+    /// Default storage class of the bucket
+    pub storage_class: Option<StorageClass>,
+}
+
+/// [Create Bucket](https://developers.cloudflare.com/api/operations/r2-create-bucket) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBucket {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_hint: Option<LocationHint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    storage_class: Option<StorageClass>,
+}
+
+impl CreateBucket {
+    /// Create a bucket with the given name
+    /// Fails if the name is not a valid bucket name
+    pub fn new(name: impl Into<String>) -> Result<Self> {
+        let name = name.into();
+        check_bucket_name(&name)?;
+        Ok(Self {
+            name,
+            location_hint: None,
+            storage_class: None,
+        })
+    }
+
+    /// Place the bucket close to the given region
+    pub fn location_hint(mut self, location_hint: LocationHint) -> Self {
+        self.location_hint = Some(location_hint);
+        self
+    }
+
+    /// Default storage class of objects in the bucket
+    pub fn storage_class(mut self, storage_class: StorageClass) -> Self {
+        self.storage_class = Some(storage_class);
+        self
+    }
+}
+
+/// Success response from the [Create Bucket](https://developers.cloudflare.com/api/operations/r2-create-bucket#Responses) API
+pub type CreateBucketRes = Bucket;
+
+impl CfReqMeta for CreateBucket {
+    const METHOD: Method = Method::POST;
+    type Response = CreateBucketRes;
+}
+
+impl CfReqAuth for CreateBucket {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/r2/buckets")
+    }
+}
+
+/// [List Buckets](https://developers.cloudflare.com/api/operations/r2-list-buckets) API
+#[derive(Serialize, Default)]
+pub struct ListBuckets {
+    name_contains: Option<String>,
+    per_page: Option<u32>,
+    cursor: Option<String>,
+}
+
+impl ListBuckets {
+    /// Only return buckets whose name contains the given string
+    pub fn name_contains(mut self, name_contains: impl Into<String>) -> Self {
+        self.name_contains = Some(name_contains.into());
+        self
+    }
+
+    /// Maximum number of buckets to return
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Continue listing from the cursor returned by a previous [ListBucketsRes]
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+/// Success response from the [List Buckets](https://developers.cloudflare.com/api/operations/r2-list-buckets#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListBucketsRes {
+    /// Buckets in this page
+    pub buckets: Vec<Bucket>,
+    /// Cursor for fetching the next page, `None` if this is the last page
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct BucketsResult {
+    buckets: Vec<Bucket>,
+}
+
+impl CfReqMeta for ListBuckets {
+    const METHOD: Method = Method::GET;
+    type Response = ListBucketsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (res, info): (BucketsResult, CursorInfo) = CfSuccessResWithInfo::from_body(&body)?;
+        let cursor = info.cursor.filter(|cursor| !cursor.is_empty());
+        Ok(ListBucketsRes {
+            buckets: res.buckets,
+            cursor,
+        })
+    }
+}
+
+impl CfReqAuth for ListBuckets {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/r2/buckets")
+    }
+}
+
+/// [Get Bucket](https://developers.cloudflare.com/api/operations/r2-get-bucket) API
+#[derive(Serialize)]
+pub struct GetBucket {
+    #[serde(skip)]
+    bucket: String,
+}
+
+impl GetBucket {
+    /// Retrieve a bucket by name
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Bucket](https://developers.cloudflare.com/api/operations/r2-get-bucket#Responses) API
+pub type GetBucketRes = Bucket;
+
+impl CfReqMeta for GetBucket {
+    const METHOD: Method = Method::GET;
+    type Response = GetBucketRes;
+}
+
+impl CfReqAuth for GetBucket {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/r2/buckets/{}", self.bucket)
+    }
+}
+
+/// [Delete Bucket](https://developers.cloudflare.com/api/operations/r2-delete-bucket) API
+/// Only empty buckets can be deleted
+#[derive(Serialize)]
+pub struct DeleteBucket {
+    #[serde(skip)]
+    bucket: String,
+}
+
+impl DeleteBucket {
+    /// Delete a bucket by name
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Bucket](https://developers.cloudflare.com/api/operations/r2-delete-bucket#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteBucketRes;
+
+impl CfReqMeta for DeleteBucket {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteBucketRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty object
+        Ok(DeleteBucketRes)
+    }
+}
+
+impl CfReqAuth for DeleteBucket {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/r2/buckets/{}", self.bucket)
+    }
+}
+
+/// HTTP methods allowed by a [CorsRule]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CorsMethod {
+    /// GET requests
+    Get,
+    /// PUT requests
+    Put,
+    /// POST requests
+    Post,
+    /// DELETE requests
+    Delete,
+    /// HEAD requests
+    Head,
+}
+
+/// What a [CorsRule] allows
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CorsAllowed {
+    /// Origins allowed to make requests, `*` allows all origins
+    pub origins: Vec<String>,
+    /// Methods allowed in requests
+    pub methods: Vec<CorsMethod>,
+    /// Headers allowed in requests
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<String>,
+}
+
+/// A CORS rule of an R2 bucket
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CorsRule {
+    /// Identifier of the rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Origins, methods and headers allowed
+    pub allowed: CorsAllowed,
+    /// Response headers exposed to the browser
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expose_headers: Vec<String>,
+    /// How long browsers may cache the preflight response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age_seconds: Option<u64>,
+}
+
+/// CORS configuration of an R2 bucket
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CorsConfig {
+    /// CORS rules, requests must match at least one rule
+    #[serde(default)]
+    pub rules: Vec<CorsRule>,
+}
+
+/// [Get Bucket CORS Policy](https://developers.cloudflare.com/api/operations/r2-get-bucket-cors-policy) API
+#[derive(Serialize)]
+pub struct GetBucketCors {
+    #[serde(skip)]
+    bucket: String,
+}
+
+impl GetBucketCors {
+    /// Retrieve the CORS configuration of a bucket
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Bucket CORS Policy](https://developers.cloudflare.com/api/operations/r2-get-bucket-cors-policy#Responses) API
+pub type GetBucketCorsRes = CorsConfig;
+
+impl CfReqMeta for GetBucketCors {
+    const METHOD: Method = Method::GET;
+    type Response = GetBucketCorsRes;
+}
+
+impl CfReqAuth for GetBucketCors {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/r2/buckets/{}/cors", self.bucket)
+    }
+}
+
+/// [Put Bucket CORS Policy](https://developers.cloudflare.com/api/operations/r2-put-bucket-cors-policy) API
+#[derive(Serialize)]
+pub struct PutBucketCors {
+    #[serde(skip)]
+    bucket: String,
+    rules: Vec<CorsRule>,
+}
+
+impl PutBucketCors {
+    /// Replace the CORS configuration of a bucket
+    /// An empty list of rules is sent as is and blocks all cross-origin requests,
+    /// use [DeleteBucketCors] to remove the configuration instead
+    pub fn new(bucket: impl Into<String>, rules: Vec<CorsRule>) -> Self {
+        Self {
+            bucket: bucket.into(),
+            rules,
+        }
+    }
+
+    /// Add a rule to the configuration
+    pub fn add_rule(mut self, rule: CorsRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+/// Success response from the [Put Bucket CORS Policy](https://developers.cloudflare.com/api/operations/r2-put-bucket-cors-policy#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct PutBucketCorsRes;
+
+impl CfReqMeta for PutBucketCors {
+    const METHOD: Method = Method::PUT;
+    type Response = PutBucketCorsRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty object
+        Ok(PutBucketCorsRes)
+    }
+}
+
+impl CfReqAuth for PutBucketCors {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/r2/buckets/{}/cors", self.bucket)
+    }
+}
+
+/// [Delete Bucket CORS Policy](https://developers.cloudflare.com/api/operations/r2-delete-bucket-cors-policy) API
+#[derive(Serialize)]
+pub struct DeleteBucketCors {
+    #[serde(skip)]
+    bucket: String,
+}
+
+impl DeleteBucketCors {
+    /// Remove the CORS configuration of a bucket
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Bucket CORS Policy](https://developers.cloudflare.com/api/operations/r2-delete-bucket-cors-policy#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteBucketCorsRes;
+
+impl CfReqMeta for DeleteBucketCors {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteBucketCorsRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty object
+        Ok(DeleteBucketCorsRes)
+    }
+}
+
+impl CfReqAuth for DeleteBucketCors {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/r2/buckets/{}/cors", self.bucket)
+    }
+}
+
+/// When a lifecycle transition applies
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum LifecycleCondition {
+    /// After objects reach the given age
+    Age {
+        /// Age in seconds
+        #[serde(rename = "maxAge")]
+        max_age: u64,
+    },
+    /// After the given date
+    Date {
+        /// RFC3339 timestamp
+        date: String,
+    },
+}
+
+/// A transition of a [LifecycleRule]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LifecycleTransition {
+    /// When the transition applies
+    pub condition: LifecycleCondition,
+}
+
+/// Conditions selecting the objects a [LifecycleRule] applies to
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct LifecycleRuleConditions {
+    /// Only objects whose key starts with this prefix, empty for all objects
+    #[serde(default)]
+    pub prefix: String,
+}
+
+/// An object lifecycle rule of an R2 bucket
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleRule {
+    /// Identifier of the rule
+    pub id: String,
+    /// Whether the rule is active
+    pub enabled: bool,
+    /// Objects the rule applies to
+    pub conditions: LifecycleRuleConditions,
+    /// When matching objects are deleted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_objects_transition: Option<LifecycleTransition>,
+    /// When incomplete multipart uploads are aborted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abort_multipart_uploads_transition: Option<LifecycleTransition>,
+}
+
+impl LifecycleRule {
+    /// An enabled rule applying to all objects, without any transitions
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            enabled: true,
+            conditions: Default::default(),
+            delete_objects_transition: None,
+            abort_multipart_uploads_transition: None,
+        }
+    }
+
+    /// Whether the rule is active
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Only apply the rule to objects whose key starts with the given prefix
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.conditions.prefix = prefix.into();
+        self
+    }
+
+    /// Delete objects once they reach the given age
+    pub fn delete_after(mut self, max_age: Duration) -> Self {
+        self.delete_objects_transition = Some(LifecycleTransition {
+            condition: LifecycleCondition::Age {
+                max_age: max_age.as_secs(),
+            },
+        });
+        self
+    }
+
+    /// Delete objects at the given time
+    pub fn delete_at(mut self, at: SystemTime) -> Self {
+        self.delete_objects_transition = Some(LifecycleTransition {
+            condition: LifecycleCondition::Date { date: rfc3339(at) },
+        });
+        self
+    }
+
+    /// Abort incomplete multipart uploads once they reach the given age
+    pub fn abort_multipart_uploads_after(mut self, max_age: Duration) -> Self {
+        self.abort_multipart_uploads_transition = Some(LifecycleTransition {
+            condition: LifecycleCondition::Age {
+                max_age: max_age.as_secs(),
+            },
+        });
+        self
+    }
+}
+
+/// Object lifecycle configuration of an R2 bucket
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct LifecycleConfig {
+    /// Lifecycle rules
+    #[serde(default)]
+    pub rules: Vec<LifecycleRule>,
+}
+
+/// [Get Object Lifecycle Rules](https://developers.cloudflare.com/api/operations/r2-get-bucket-lifecycle-configuration) API
+#[derive(Serialize)]
+pub struct GetBucketLifecycle {
+    #[serde(skip)]
+    bucket: String,
+}
+
+impl GetBucketLifecycle {
+    /// Retrieve the lifecycle rules of a bucket
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Object Lifecycle Rules](https://developers.cloudflare.com/api/operations/r2-get-bucket-lifecycle-configuration#Responses) API
+pub type GetBucketLifecycleRes = LifecycleConfig;
+
+impl CfReqMeta for GetBucketLifecycle {
+    const METHOD: Method = Method::GET;
+    type Response = GetBucketLifecycleRes;
+}
+
+impl CfReqAuth for GetBucketLifecycle {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/r2/buckets/{}/lifecycle", self.bucket)
+    }
+}
+
+/// [Put Object Lifecycle Rules](https://developers.cloudflare.com/api/operations/r2-put-bucket-lifecycle-configuration) API
+#[derive(Serialize)]
+pub struct PutBucketLifecycle {
+    #[serde(skip)]
+    bucket: String,
+    rules: Vec<LifecycleRule>,
+}
+
+impl PutBucketLifecycle {
+    /// Replace the lifecycle rules of a bucket, add rules with [Self::add_rule]
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Add a rule to the configuration
+    pub fn add_rule(mut self, rule: LifecycleRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+/// Success response from the [Put Object Lifecycle Rules](https://developers.cloudflare.com/api/operations/r2-put-bucket-lifecycle-configuration#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct PutBucketLifecycleRes;
+
+impl CfReqMeta for PutBucketLifecycle {
+    const METHOD: Method = Method::PUT;
+    type Response = PutBucketLifecycleRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty object
+        Ok(PutBucketLifecycleRes)
+    }
+}
+
+impl CfReqAuth for PutBucketLifecycle {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/r2/buckets/{}/lifecycle", self.bucket)
+    }
+}
+
+/// Minimum TLS version accepted by a custom domain
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MinTls {
+    /// TLS 1.0
+    #[serde(rename = "1.0")]
+    Tls1_0,
+    /// TLS 1.1
+    #[serde(rename = "1.1")]
+    Tls1_1,
+    /// TLS 1.2
+    #[serde(rename = "1.2")]
+    Tls1_2,
+    /// TLS 1.3
+    #[serde(rename = "1.3")]
+    Tls1_3,
+}
+
+/// Progress of a custom domain's ownership verification or certificate
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DomainStatus {
+    /// Certificate is being set up
+    Initializing,
+    /// Waiting for verification or issuance
+    Pending,
+    /// Ready to serve traffic
+    Active,
+    /// Disabled
+    Deactivated,
+    /// Blocked by cloudflare
+    Blocked,
+    /// Setup failed
+    Error,
+    /// A status unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status of a custom domain
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct CustomDomainStatus {
+    /// Ownership verification status
+    pub ownership: DomainStatus,
+    /// Certificate status
+    pub ssl: DomainStatus,
+}
+
+/// A custom domain attached to an R2 bucket
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomDomain {
+    /// Domain name
+    pub domain: String,
+    /// Whether the domain serves the bucket's objects
+    pub enabled: bool,
+    /// Verification and certificate status
+    pub status: CustomDomainStatus,
+    /// Minimum TLS version accepted
+    #[serde(rename = "minTLS")]
+    pub min_tls: Option<MinTls>,
+    /// Zone the domain belongs to
+    pub zone_id: Option<String>,
+    /// Name of the zone the domain belongs to
+    pub zone_name: Option<String>,
+}
+
+/// [Attach Custom Domain To Bucket](https://developers.cloudflare.com/api/operations/r2-add-custom-domain) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCustomDomain {
+    #[serde(skip)]
+    bucket: String,
+    domain: String,
+    zone_id: String,
+    enabled: bool,
+    #[serde(rename = "minTLS", skip_serializing_if = "Option::is_none")]
+    min_tls: Option<MinTls>,
+}
+
+impl CreateCustomDomain {
+    /// Attach `domain`, part of the zone `zone_id`, to a bucket
+    pub fn new(
+        bucket: impl Into<String>,
+        domain: impl Into<String>,
+        zone_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            bucket: bucket.into(),
+            domain: domain.into(),
+            zone_id: zone_id.into(),
+            enabled: true,
+            min_tls: None,
+        }
+    }
+
+    /// Whether the domain serves the bucket's objects (defaults to true)
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Minimum TLS version accepted by the domain
+    pub fn min_tls(mut self, min_tls: MinTls) -> Self {
+        self.min_tls = Some(min_tls);
+        self
+    }
+}
+
+/// Success response from the [Attach Custom Domain To Bucket](https://developers.cloudflare.com/api/operations/r2-add-custom-domain#Responses) API
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCustomDomainRes {
+    /// Domain name
+    pub domain: String,
+    /// Whether the domain serves the bucket's objects
+    pub enabled: bool,
+    /// Minimum TLS version accepted
+    #[serde(rename = "minTLS")]
+    pub min_tls: Option<MinTls>,
+}
+
+impl CfReqMeta for CreateCustomDomain {
+    const METHOD: Method = Method::POST;
+    type Response = CreateCustomDomainRes;
+}
+
+impl CfReqAuth for CreateCustomDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/r2/buckets/{}/domains/custom",
+            self.bucket
+        )
+    }
+}
+
+/// [List Custom Domains of Bucket](https://developers.cloudflare.com/api/operations/r2-list-custom-domains) API
+#[derive(Serialize)]
+pub struct ListCustomDomains {
+    #[serde(skip)]
+    bucket: String,
+}
+
+impl ListCustomDomains {
+    /// List the custom domains of a bucket
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+        }
+    }
+}
+
+/// Success response from the [List Custom Domains of Bucket](https://developers.cloudflare.com/api/operations/r2-list-custom-domains#Responses) API
+pub type ListCustomDomainsRes = Vec<CustomDomain>;
+
+#[derive(Deserialize)]
+struct DomainsResult {
+    domains: ListCustomDomainsRes,
+}
+
+impl CfReqMeta for ListCustomDomains {
+    const METHOD: Method = Method::GET;
+    type Response = ListCustomDomainsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let res: CfSuccessRes<DomainsResult> = serde_json::from_slice(&body)?;
+        Ok(res.result.domains)
+    }
+}
+
+impl CfReqAuth for ListCustomDomains {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/r2/buckets/{}/domains/custom",
+            self.bucket
+        )
+    }
+}
+
+/// [Get Custom Domain Settings](https://developers.cloudflare.com/api/operations/r2-get-custom-domain-settings) API
+#[derive(Serialize)]
+pub struct GetCustomDomain {
+    #[serde(skip)]
+    bucket: String,
+    #[serde(skip)]
+    domain: String,
+}
+
+impl GetCustomDomain {
+    /// Retrieve a custom domain of a bucket
+    pub fn new(bucket: impl Into<String>, domain: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+            domain: domain.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Custom Domain Settings](https://developers.cloudflare.com/api/operations/r2-get-custom-domain-settings#Responses) API
+pub type GetCustomDomainRes = CustomDomain;
+
+impl CfReqMeta for GetCustomDomain {
+    const METHOD: Method = Method::GET;
+    type Response = GetCustomDomainRes;
+}
+
+impl CfReqAuth for GetCustomDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/r2/buckets/{}/domains/custom/{}",
+            self.bucket, self.domain
+        )
+    }
+}
+
+/// [Remove Custom Domain From Bucket](https://developers.cloudflare.com/api/operations/r2-delete-custom-domain) API
+#[derive(Serialize)]
+pub struct DeleteCustomDomain {
+    #[serde(skip)]
+    bucket: String,
+    #[serde(skip)]
+    domain: String,
+}
+
+impl DeleteCustomDomain {
+    /// Detach a custom domain from a bucket
+    pub fn new(bucket: impl Into<String>, domain: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+            domain: domain.into(),
+        }
+    }
+}
+
+/// Success response from the [Remove Custom Domain From Bucket](https://developers.cloudflare.com/api/operations/r2-delete-custom-domain#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteCustomDomainRes {
+    /// The removed domain
+    pub domain: String,
+}
+
+impl CfReqMeta for DeleteCustomDomain {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteCustomDomainRes;
+}
+
+impl CfReqAuth for DeleteCustomDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/r2/buckets/{}/domains/custom/{}",
+            self.bucket, self.domain
+        )
+    }
+}
+
+/// The r2.dev subdomain of a bucket
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedDomain {
+    /// Identifier of the bucket
+    pub bucket_id: String,
+    /// The r2.dev domain name
+    pub domain: String,
+    /// Whether the bucket is publicly reachable through the domain
+    pub enabled: bool,
+}
+
+/// [Get r2.dev Domain of Bucket](https://developers.cloudflare.com/api/operations/r2-get-bucket-public-policy) API
+#[derive(Serialize)]
+pub struct GetManagedDomain {
+    #[serde(skip)]
+    bucket: String,
+}
+
+impl GetManagedDomain {
+    /// Retrieve the r2.dev domain of a bucket
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+        }
+    }
+}
+
+/// Success response from the [Get r2.dev Domain of Bucket](https://developers.cloudflare.com/api/operations/r2-get-bucket-public-policy#Responses) API
+pub type GetManagedDomainRes = ManagedDomain;
+
+impl CfReqMeta for GetManagedDomain {
+    const METHOD: Method = Method::GET;
+    type Response = GetManagedDomainRes;
+}
+
+impl CfReqAuth for GetManagedDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/r2/buckets/{}/domains/managed",
+            self.bucket
+        )
+    }
+}
+
+/// [Update r2.dev Domain of Bucket](https://developers.cloudflare.com/api/operations/r2-put-bucket-public-policy) API
+#[derive(Serialize)]
+pub struct UpdateManagedDomain {
+    #[serde(skip)]
+    bucket: String,
+    enabled: bool,
+}
+
+impl UpdateManagedDomain {
+    /// Enable or disable public access to a bucket through its r2.dev domain
+    pub fn new(bucket: impl Into<String>, enabled: bool) -> Self {
+        Self {
+            bucket: bucket.into(),
+            enabled,
+        }
+    }
+}
+
+/// Success response from the [Update r2.dev Domain of Bucket](https://developers.cloudflare.com/api/operations/r2-put-bucket-public-policy#Responses) API
+pub type UpdateManagedDomainRes = ManagedDomain;
+
+impl CfReqMeta for UpdateManagedDomain {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateManagedDomainRes;
+}
+
+impl CfReqAuth for UpdateManagedDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/r2/buckets/{}/domains/managed",
+            self.bucket
+        )
+    }
+}
+
+/// Object events that trigger a notification
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventAction {
+    /// An object was uploaded
+    PutObject,
+    /// An object was copied
+    CopyObject,
+    /// An object was deleted
+    DeleteObject,
+    /// A multipart upload was completed
+    CompleteMultipartUpload,
+    /// An object was deleted by a lifecycle rule
+    LifecycleDeletion,
+}
+
+/// A rule selecting the events sent to a queue
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EventNotificationRule {
+    /// Events that trigger a notification
+    pub actions: Vec<EventAction>,
+    /// Only objects whose key starts with this prefix
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// Only objects whose key ends with this suffix
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+    /// Description of the rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Identifier of the rule, set by cloudflare
+    #[serde(skip_serializing)]
+    pub rule_id: Option<String>,
+    /// RFC3339 timestamp of when the rule was created, set by cloudflare
+    #[serde(skip_serializing)]
+    pub created_at: Option<String>,
+}
+
+impl EventNotificationRule {
+    /// A rule matching the given events on all objects
+    pub fn new(actions: Vec<EventAction>) -> Self {
+        Self {
+            actions,
+            prefix: None,
+            suffix: None,
+            description: None,
+            rule_id: None,
+            created_at: None,
+        }
+    }
+
+    /// Only match objects whose key starts with the given prefix
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Only match objects whose key ends with the given suffix
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Describe the rule
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// [Create Event Notification Rule](https://developers.cloudflare.com/api/operations/event-notifications-r2-put-configuration) API
+#[derive(Serialize)]
+pub struct PutR2EventNotification {
+    #[serde(skip)]
+    bucket: String,
+    #[serde(skip)]
+    queue_id: String,
+    rules: Vec<EventNotificationRule>,
+}
+
+impl PutR2EventNotification {
+    /// Send events of a bucket to the queue `queue_id`, add rules with [Self::add_rule]
+    pub fn new(bucket: impl Into<String>, queue_id: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+            queue_id: queue_id.into(),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Add a rule to the configuration
+    pub fn add_rule(mut self, rule: EventNotificationRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+/// Success response from the [Create Event Notification Rule](https://developers.cloudflare.com/api/operations/event-notifications-r2-put-configuration#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct PutR2EventNotificationRes;
+
+impl CfReqMeta for PutR2EventNotification {
+    const METHOD: Method = Method::PUT;
+    type Response = PutR2EventNotificationRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty object
+        Ok(PutR2EventNotificationRes)
+    }
+}
+
+impl CfReqAuth for PutR2EventNotification {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/event_notifications/r2/{}/configuration/queues/{}",
+            self.bucket, self.queue_id
+        )
+    }
+}
+
+/// Event notification rules of a bucket for a single queue
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueEventNotifications {
+    /// Identifier of the queue
+    pub queue_id: String,
+    /// Name of the queue
+    pub queue_name: Option<String>,
+    /// Rules selecting the events sent to the queue
+    pub rules: Vec<EventNotificationRule>,
+}
+
+/// [Get Event Notification Rules](https://developers.cloudflare.com/api/operations/event-notifications-r2-get-configuration) API
+#[derive(Serialize)]
+pub struct GetR2EventNotificationConfig {
+    #[serde(skip)]
+    bucket: String,
+}
+
+impl GetR2EventNotificationConfig {
+    /// Retrieve the event notification rules of a bucket
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Event Notification Rules](https://developers.cloudflare.com/api/operations/event-notifications-r2-get-configuration#Responses) API
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetR2EventNotificationConfigRes {
+    /// Name of the bucket
+    pub bucket_name: Option<String>,
+    /// Rules per queue
+    #[serde(default)]
+    pub queues: Vec<QueueEventNotifications>,
+}
+
+impl CfReqMeta for GetR2EventNotificationConfig {
+    const METHOD: Method = Method::GET;
+    type Response = GetR2EventNotificationConfigRes;
+}
+
+impl CfReqAuth for GetR2EventNotificationConfig {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/event_notifications/r2/{}/configuration",
+            self.bucket
+        )
+    }
+}
+
+/// [Delete Event Notification Rules](https://developers.cloudflare.com/api/operations/event-notifications-r2-delete-configuration) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteR2EventNotification {
+    #[serde(skip)]
+    bucket: String,
+    #[serde(skip)]
+    queue_id: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rule_ids: Vec<String>,
+}
+
+impl DeleteR2EventNotification {
+    /// Stop sending events of a bucket to the queue `queue_id`
+    pub fn new(bucket: impl Into<String>, queue_id: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+            queue_id: queue_id.into(),
+            rule_ids: Vec::new(),
+        }
+    }
+
+    /// Only delete the rule with the given id, instead of all rules for the queue
+    pub fn add_rule_id(mut self, rule_id: impl Into<String>) -> Self {
+        self.rule_ids.push(rule_id.into());
+        self
+    }
+}
+
+/// Success response from the [Delete Event Notification Rules](https://developers.cloudflare.com/api/operations/event-notifications-r2-delete-configuration#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteR2EventNotificationRes;
+
+impl CfReqMeta for DeleteR2EventNotification {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteR2EventNotificationRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty object
+        Ok(DeleteR2EventNotificationRes)
+    }
+}
+
+impl CfReqAuth for DeleteR2EventNotification {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/event_notifications/r2/{}/configuration/queues/{}",
+            self.bucket, self.queue_id
+        )
+    }
+}
+
+/// Maximum lifetime of credentials created by [CreateTempAccessCredentials]
+pub const TEMP_CREDENTIALS_MAX_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Permission granted by temporary access credentials
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TempCredentialsPermission {
+    /// Read and write objects and bucket configuration
+    AdminReadWrite,
+    /// Read objects and bucket configuration
+    AdminReadOnly,
+    /// Read and write objects
+    ObjectReadWrite,
+    /// Read objects
+    ObjectReadOnly,
+}
+
+/// [Create temporary access credentials](https://developers.cloudflare.com/api/operations/r2-create-temp-access-credentials) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTempAccessCredentials {
+    bucket: String,
+    parent_access_key_id: String,
+    permission: TempCredentialsPermission,
+    ttl_seconds: u64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    prefixes: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    objects: Vec<String>,
+}
+
+impl CreateTempAccessCredentials {
+    /// Create credentials for a bucket derived from the R2 access key `parent_access_key_id`
+    /// `ttl` must not exceed [TEMP_CREDENTIALS_MAX_TTL]
+    pub fn new(
+        bucket: impl Into<String>,
+        parent_access_key_id: impl Into<String>,
+        permission: TempCredentialsPermission,
+        ttl: Duration,
+    ) -> Result<Self> {
+        if ttl > TEMP_CREDENTIALS_MAX_TTL {
+            return Err(Error::InvalidRequest(format!(
+                "temporary credentials ttl must be at most {}s, got {}s",
+                TEMP_CREDENTIALS_MAX_TTL.as_secs(),
+                ttl.as_secs()
+            )));
+        }
+        Ok(Self {
+            bucket: bucket.into(),
+            parent_access_key_id: parent_access_key_id.into(),
+            permission,
+            ttl_seconds: ttl.as_secs(),
+            prefixes: Vec::new(),
+            objects: Vec::new(),
+        })
+    }
+
+    /// Restrict the credentials to objects whose key starts with the given prefix
+    pub fn add_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefixes.push(prefix.into());
+        self
+    }
+
+    /// Restrict the credentials to the object with the given key
+    pub fn add_object(mut self, object: impl Into<String>) -> Self {
+        self.objects.push(object.into());
+        self
+    }
+}
+
+/// Success response from the [Create temporary access credentials](https://developers.cloudflare.com/api/operations/r2-create-temp-access-credentials#Responses) API
+/// S3 compatible credentials
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTempAccessCredentialsRes {
+    /// Access key id
+    pub access_key_id: String,
+    /// Secret access key
+    pub secret_access_key: String,
+    /// Session token
+    pub session_token: String,
+}
+
+impl fmt::Debug for CreateTempAccessCredentialsRes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateTempAccessCredentialsRes")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"<redacted>")
+            .field("session_token", &"<redacted>")
+            .finish()
+    }
+}
+
+impl CfReqMeta for CreateTempAccessCredentials {
+    const METHOD: Method = Method::POST;
+    type Response = CreateTempAccessCredentialsRes;
+}
+
+impl CfReqAuth for CreateTempAccessCredentials {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/r2/temp-access-credentials")
+    }
+}
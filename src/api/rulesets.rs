@@ -0,0 +1,848 @@
+//! Cloudflare Rulesets related APIs (WAF custom rules, transform rules, ...)
+//! See [Cloudflare Docs](https://developers.cloudflare.com/ruleset-engine/)
+//! All requests act on zone rulesets, send them with [crate::CloudflareZone::send_zone]
+use std::collections::HashMap;
+
+use crate::{CfReqMeta, CfReqZone, Error, Result};
+use reqwest::Method;
+use serde::{
+    de::{self, Deserializer},
+    ser::{self, Serializer},
+    Deserialize, Serialize,
+};
+
+/// Phase a ruleset runs in
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum RulesetPhase {
+    /// WAF custom rules
+    HttpRequestFirewallCustom,
+    /// URL rewrite rules
+    HttpRequestTransform,
+    /// Request header modification rules
+    HttpRequestLateTransform,
+    /// Response header modification rules
+    HttpResponseHeadersTransform,
+    /// Rate limiting rules
+    HttpRatelimit,
+    /// Single redirect rules
+    HttpRequestDynamicRedirect,
+    /// Cache rules
+    HttpRequestCacheSettings,
+    /// A phase unknown to this library
+    Other(String),
+}
+
+impl RulesetPhase {
+    /// The phase as used by cloudflare
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::HttpRequestFirewallCustom => "http_request_firewall_custom",
+            Self::HttpRequestTransform => "http_request_transform",
+            Self::HttpRequestLateTransform => "http_request_late_transform",
+            Self::HttpResponseHeadersTransform => "http_response_headers_transform",
+            Self::HttpRatelimit => "http_ratelimit",
+            Self::HttpRequestDynamicRedirect => "http_request_dynamic_redirect",
+            Self::HttpRequestCacheSettings => "http_request_cache_settings",
+            Self::Other(phase) => phase,
+        }
+    }
+}
+
+impl From<String> for RulesetPhase {
+    fn from(phase: String) -> Self {
+        match phase.as_str() {
+            "http_request_firewall_custom" => Self::HttpRequestFirewallCustom,
+            "http_request_transform" => Self::HttpRequestTransform,
+            "http_request_late_transform" => Self::HttpRequestLateTransform,
+            "http_response_headers_transform" => Self::HttpResponseHeadersTransform,
+            "http_ratelimit" => Self::HttpRatelimit,
+            "http_request_dynamic_redirect" => Self::HttpRequestDynamicRedirect,
+            "http_request_cache_settings" => Self::HttpRequestCacheSettings,
+            _ => Self::Other(phase),
+        }
+    }
+}
+
+impl From<RulesetPhase> for String {
+    fn from(phase: RulesetPhase) -> Self {
+        match phase {
+            RulesetPhase::Other(phase) => phase,
+            phase => phase.as_str().to_string(),
+        }
+    }
+}
+
+/// Kind of a ruleset
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RulesetKind {
+    /// Ruleset managed by cloudflare
+    Managed,
+    /// Custom ruleset of an account
+    Custom,
+    /// Entrypoint ruleset of an account
+    Root,
+    /// Entrypoint ruleset of a zone
+    Zone,
+}
+
+/// A value set by a rewrite, either static or computed by an expression
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RewriteValue {
+    /// Static value
+    Value(String),
+    /// Expression evaluated for each request (e.g `concat("/v2", http.request.uri.path)`)
+    Expression(String),
+}
+
+/// URL rewrite of a [RuleAction::Rewrite]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UriRewrite {
+    /// New path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<RewriteValue>,
+    /// New query string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<RewriteValue>,
+}
+
+/// Header modification of a [RuleAction::Rewrite]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "operation", rename_all = "lowercase")]
+pub enum HeaderRewrite {
+    /// Set the header, replacing existing values
+    Set(RewriteValue),
+    /// Add a value to the header
+    Add(RewriteValue),
+    /// Remove the header
+    Remove,
+}
+
+/// Parameters of a [RuleAction::Rewrite]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RewriteParameters {
+    /// URL rewrite
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<UriRewrite>,
+    /// Header modifications, by header name
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub headers: HashMap<String, HeaderRewrite>,
+}
+
+/// Parameters of a [RuleAction::Skip]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SkipParameters {
+    /// `current` to skip the remaining rules of the ruleset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ruleset: Option<String>,
+    /// Phases to skip
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phases: Option<Vec<RulesetPhase>>,
+    /// Security products to skip (e.g `waf`, `bic`, `uaBlock`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products: Option<Vec<String>>,
+}
+
+impl SkipParameters {
+    /// Skip the remaining rules of the current ruleset
+    pub fn current_ruleset() -> Self {
+        Self {
+            ruleset: Some("current".to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Action of a rule
+#[derive(Clone, Debug, PartialEq)]
+pub enum RuleAction {
+    /// Block the request
+    Block,
+    /// Show an interactive challenge
+    Challenge,
+    /// Let cloudflare pick the challenge
+    ManagedChallenge,
+    /// Show a non-interactive javascript challenge
+    JsChallenge,
+    /// Only log the request
+    Log,
+    /// Skip other rules, phases or products
+    Skip(SkipParameters),
+    /// Rewrite the URL or headers of the request
+    Rewrite(RewriteParameters),
+    /// An action without typed parameters,
+    /// also used for the actions above when they have parameters (e.g a custom block response)
+    Other {
+        /// Name of the action (e.g `redirect`, `set_cache_settings`)
+        action: String,
+        /// Parameters of the action
+        parameters: Option<serde_json::Value>,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawRuleAction {
+    action: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    action_parameters: Option<serde_json::Value>,
+}
+
+impl Serialize for RuleAction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (action, action_parameters) = match self {
+            Self::Block => ("block", None),
+            Self::Challenge => ("challenge", None),
+            Self::ManagedChallenge => ("managed_challenge", None),
+            Self::JsChallenge => ("js_challenge", None),
+            Self::Log => ("log", None),
+            Self::Skip(skip) => (
+                "skip",
+                Some(serde_json::to_value(skip).map_err(ser::Error::custom)?),
+            ),
+            Self::Rewrite(rewrite) => (
+                "rewrite",
+                Some(serde_json::to_value(rewrite).map_err(ser::Error::custom)?),
+            ),
+            Self::Other { action, parameters } => (action.as_str(), parameters.clone()),
+        };
+        RawRuleAction {
+            action: action.to_string(),
+            action_parameters,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RuleAction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawRuleAction::deserialize(deserializer)?;
+        let params = raw.action_parameters.unwrap_or_default();
+        // actions without typed parameters keep theirs (e.g a custom block response)
+        // as `Other`, so that updating a fetched ruleset does not drop them
+        let has_params = !params.is_null();
+        Ok(match raw.action.as_str() {
+            "block" if !has_params => Self::Block,
+            "challenge" if !has_params => Self::Challenge,
+            "managed_challenge" if !has_params => Self::ManagedChallenge,
+            "js_challenge" if !has_params => Self::JsChallenge,
+            "log" if !has_params => Self::Log,
+            "skip" => Self::Skip(serde_json::from_value(params).map_err(de::Error::custom)?),
+            "rewrite" => Self::Rewrite(serde_json::from_value(params).map_err(de::Error::custom)?),
+            _ => Self::Other {
+                action: raw.action,
+                parameters: has_params.then_some(params),
+            },
+        })
+    }
+}
+
+/// Periods (in seconds) accepted by [RateLimitParameters]
+pub const RATELIMIT_PERIODS: [u32; 4] = [10, 60, 600, 3600];
+
+/// Rate limiting of a rule in the [RulesetPhase::HttpRatelimit] phase
+/// The rule's action is taken once a client exceeds the rate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitParameters {
+    characteristics: Vec<String>,
+    period: u32,
+    requests_per_period: u32,
+    mitigation_timeout: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    counting_expression: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requests_to_origin: Option<bool>,
+}
+
+impl RateLimitParameters {
+    /// Limit clients to `requests_per_period` requests every `period` seconds
+    /// `period` must be one of [RATELIMIT_PERIODS]
+    /// Clients are counted per datacenter (`cf.colo.id`), add characteristics to tell them apart
+    pub fn new(period: u32, requests_per_period: u32) -> Result<Self> {
+        if !RATELIMIT_PERIODS.contains(&period) {
+            return Err(Error::InvalidRequest(format!(
+                "rate limit period must be one of {RATELIMIT_PERIODS:?}, got {period}"
+            )));
+        }
+        Ok(Self {
+            characteristics: vec!["cf.colo.id".to_string()],
+            period,
+            requests_per_period,
+            mitigation_timeout: 0,
+            counting_expression: None,
+            requests_to_origin: None,
+        })
+    }
+
+    /// Count requests per value of `characteristic`
+    /// (e.g `ip.src` or `http.request.headers["x-api-key"]`)
+    pub fn characteristic(mut self, characteristic: impl Into<String>) -> Self {
+        self.characteristics.push(characteristic.into());
+        self
+    }
+
+    /// Count requests per client IP
+    pub fn per_ip(self) -> Self {
+        self.characteristic("ip.src")
+    }
+
+    /// Keep applying the action for `mitigation_timeout` seconds once the rate is exceeded
+    /// Defaults to 0, the action is only applied while the rate is exceeded
+    pub fn mitigation_timeout(mut self, mitigation_timeout: u32) -> Self {
+        self.mitigation_timeout = mitigation_timeout;
+        self
+    }
+
+    /// Only count requests matching `expression`
+    /// Defaults to the rule's expression
+    pub fn counting_expression(mut self, expression: impl Into<String>) -> Self {
+        self.counting_expression = Some(expression.into());
+        self
+    }
+
+    /// Only count requests that reach the origin (i.e not served from cache)
+    pub fn requests_to_origin(mut self, requests_to_origin: bool) -> Self {
+        self.requests_to_origin = Some(requests_to_origin);
+        self
+    }
+
+    /// Characteristics requests are counted by
+    pub fn characteristics(&self) -> &[String] {
+        &self.characteristics
+    }
+
+    /// Period in seconds
+    pub fn period(&self) -> u32 {
+        self.period
+    }
+
+    /// Requests allowed per period
+    pub fn requests_per_period(&self) -> u32 {
+        self.requests_per_period
+    }
+}
+
+/// A rule of a ruleset
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Rule {
+    /// Rule ID, set by cloudflare
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Expression matching the requests the rule applies to
+    /// (e.g `ip.src.country eq "XX"`)
+    pub expression: String,
+    /// Action taken when the expression matches
+    #[serde(flatten)]
+    pub action: RuleAction,
+    /// Description of the rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the rule is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Stable reference of the rule, kept across updates
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// Rate limiting, only for rules in the [RulesetPhase::HttpRatelimit] phase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ratelimit: Option<RateLimitParameters>,
+    /// Version of the rule, set by cloudflare
+    #[serde(skip_serializing)]
+    pub version: Option<String>,
+    /// Last update of the rule, set by cloudflare
+    #[serde(skip_serializing)]
+    pub last_updated: Option<String>,
+}
+
+impl Rule {
+    /// Rule taking `action` on requests matching `expression`
+    pub fn new(expression: impl Into<String>, action: RuleAction) -> Self {
+        Self {
+            id: None,
+            expression: expression.into(),
+            action,
+            description: None,
+            enabled: None,
+            reference: None,
+            ratelimit: None,
+            version: None,
+            last_updated: None,
+        }
+    }
+
+    /// Description of the rule
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Enable or disable the rule
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Stable reference of the rule
+    pub fn reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Rate limiting of the rule
+    pub fn ratelimit(mut self, ratelimit: RateLimitParameters) -> Self {
+        self.ratelimit = Some(ratelimit);
+        self
+    }
+}
+
+/// A ruleset
+/// Rules are not included when listing rulesets
+#[derive(Deserialize, Clone, Debug)]
+pub struct Ruleset {
+    /// Ruleset ID
+    pub id: String,
+    /// Name of the ruleset
+    pub name: String,
+    /// Description of the ruleset
+    #[serde(default)]
+    pub description: String,
+    /// Kind of the ruleset
+    pub kind: RulesetKind,
+    /// Phase of the ruleset
+    pub phase: RulesetPhase,
+    /// Version of the ruleset
+    pub version: String,
+    /// Last update of the ruleset
+    pub last_updated: String,
+    /// Rules of the ruleset
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+/// [List zone rulesets](https://developers.cloudflare.com/api/operations/listZoneRulesets) API
+#[derive(Serialize, Default)]
+pub struct ListRulesets {}
+
+/// Success response from the [List zone rulesets](https://developers.cloudflare.com/api/operations/listZoneRulesets#Responses) API
+pub type ListRulesetsRes = Vec<Ruleset>;
+
+impl CfReqMeta for ListRulesets {
+    const METHOD: Method = Method::GET;
+    type Response = ListRulesetsRes;
+}
+
+impl CfReqZone for ListRulesets {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/rulesets")
+    }
+}
+
+/// [Get a zone ruleset](https://developers.cloudflare.com/api/operations/getZoneRuleset) API
+#[derive(Serialize)]
+pub struct GetRuleset {
+    #[serde(skip)]
+    ruleset_id: String,
+}
+
+impl GetRuleset {
+    /// Get the ruleset `ruleset_id`
+    pub fn new(ruleset_id: impl Into<String>) -> Self {
+        Self {
+            ruleset_id: ruleset_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get a zone ruleset](https://developers.cloudflare.com/api/operations/getZoneRuleset#Responses) API
+pub type GetRulesetRes = Ruleset;
+
+impl CfReqMeta for GetRuleset {
+    const METHOD: Method = Method::GET;
+    type Response = GetRulesetRes;
+}
+
+impl CfReqZone for GetRuleset {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/rulesets/{}", self.ruleset_id)
+    }
+}
+
+/// [Create a zone ruleset](https://developers.cloudflare.com/api/operations/createZoneRuleset) API
+/// A zone may only have one ruleset of kind zone per phase, prefer [UpdatePhaseEntrypoint]
+#[derive(Serialize)]
+pub struct CreateRuleset {
+    name: String,
+    kind: RulesetKind,
+    phase: RulesetPhase,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    rules: Vec<Rule>,
+}
+
+impl CreateRuleset {
+    /// Create a ruleset `name` of kind zone in `phase`
+    pub fn new(name: impl Into<String>, phase: RulesetPhase) -> Self {
+        Self {
+            name: name.into(),
+            kind: RulesetKind::Zone,
+            phase,
+            description: None,
+            rules: vec![],
+        }
+    }
+
+    /// Description of the ruleset
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a rule to the ruleset
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+/// Success response from the [Create a zone ruleset](https://developers.cloudflare.com/api/operations/createZoneRuleset#Responses) API
+pub type CreateRulesetRes = Ruleset;
+
+impl CfReqMeta for CreateRuleset {
+    const METHOD: Method = Method::POST;
+    type Response = CreateRulesetRes;
+}
+
+impl CfReqZone for CreateRuleset {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/rulesets")
+    }
+}
+
+/// [Update a zone ruleset](https://developers.cloudflare.com/api/operations/updateZoneRuleset) API
+/// All the rules of the ruleset are replaced, use [CreateRule], [UpdateRule] and [DeleteRule]
+/// to change a single rule
+#[derive(Serialize)]
+pub struct UpdateRuleset {
+    #[serde(skip)]
+    ruleset_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    rules: Vec<Rule>,
+}
+
+impl UpdateRuleset {
+    /// Replace the rules of the ruleset `ruleset_id`
+    pub fn new(ruleset_id: impl Into<String>, rules: Vec<Rule>) -> Self {
+        Self {
+            ruleset_id: ruleset_id.into(),
+            description: None,
+            rules,
+        }
+    }
+
+    /// Description of the ruleset
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Success response from the [Update a zone ruleset](https://developers.cloudflare.com/api/operations/updateZoneRuleset#Responses) API
+pub type UpdateRulesetRes = Ruleset;
+
+impl CfReqMeta for UpdateRuleset {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateRulesetRes;
+}
+
+impl CfReqZone for UpdateRuleset {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/rulesets/{}", self.ruleset_id)
+    }
+}
+
+/// [Get a zone entry point ruleset](https://developers.cloudflare.com/api/operations/getZoneEntrypointRuleset) API
+#[derive(Serialize)]
+pub struct GetPhaseEntrypoint {
+    #[serde(skip)]
+    phase: RulesetPhase,
+}
+
+impl GetPhaseEntrypoint {
+    /// Get the entry point ruleset of `phase`
+    pub fn new(phase: RulesetPhase) -> Self {
+        Self { phase }
+    }
+}
+
+/// Success response from the [Get a zone entry point ruleset](https://developers.cloudflare.com/api/operations/getZoneEntrypointRuleset#Responses) API
+pub type GetPhaseEntrypointRes = Ruleset;
+
+impl CfReqMeta for GetPhaseEntrypoint {
+    const METHOD: Method = Method::GET;
+    type Response = GetPhaseEntrypointRes;
+}
+
+impl CfReqZone for GetPhaseEntrypoint {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!(
+            "zones/{zone_id}/rulesets/phases/{}/entrypoint",
+            self.phase.as_str()
+        )
+    }
+}
+
+/// [Update a zone entry point ruleset](https://developers.cloudflare.com/api/operations/updateZoneEntrypointRuleset) API
+/// The entry point ruleset is created if it doesn't exist.
+/// All the rules of the ruleset are replaced, use [CreateRule], [UpdateRule] and [DeleteRule]
+/// to change a single rule
+#[derive(Serialize)]
+pub struct UpdatePhaseEntrypoint {
+    #[serde(skip)]
+    phase: RulesetPhase,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    rules: Vec<Rule>,
+}
+
+impl UpdatePhaseEntrypoint {
+    /// Replace the rules of the entry point ruleset of `phase`
+    pub fn new(phase: RulesetPhase, rules: Vec<Rule>) -> Self {
+        Self {
+            phase,
+            description: None,
+            rules,
+        }
+    }
+
+    /// Description of the ruleset
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Success response from the [Update a zone entry point ruleset](https://developers.cloudflare.com/api/operations/updateZoneEntrypointRuleset#Responses) API
+pub type UpdatePhaseEntrypointRes = Ruleset;
+
+impl CfReqMeta for UpdatePhaseEntrypoint {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdatePhaseEntrypointRes;
+}
+
+impl CfReqZone for UpdatePhaseEntrypoint {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!(
+            "zones/{zone_id}/rulesets/phases/{}/entrypoint",
+            self.phase.as_str()
+        )
+    }
+}
+
+/// Position of a rule added with [CreateRule]
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RulePosition {
+    /// Before the rule with the given ID
+    Before(String),
+    /// After the rule with the given ID
+    After(String),
+    /// At the given (1-based) index
+    Index(u32),
+}
+
+/// [Create a zone ruleset rule](https://developers.cloudflare.com/api/operations/createZoneRulesetRule) API
+/// The rule is added last unless a position is given
+#[derive(Serialize)]
+pub struct CreateRule {
+    #[serde(skip)]
+    ruleset_id: String,
+    #[serde(flatten)]
+    rule: Rule,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<RulePosition>,
+}
+
+impl CreateRule {
+    /// Add `rule` to the ruleset `ruleset_id`
+    pub fn new(ruleset_id: impl Into<String>, rule: Rule) -> Self {
+        Self {
+            ruleset_id: ruleset_id.into(),
+            rule,
+            position: None,
+        }
+    }
+
+    /// Position of the rule in the ruleset
+    pub fn position(mut self, position: RulePosition) -> Self {
+        self.position = Some(position);
+        self
+    }
+}
+
+/// Success response from the [Create a zone ruleset rule](https://developers.cloudflare.com/api/operations/createZoneRulesetRule#Responses) API
+/// The whole updated ruleset is returned
+pub type CreateRuleRes = Ruleset;
+
+impl CfReqMeta for CreateRule {
+    const METHOD: Method = Method::POST;
+    type Response = CreateRuleRes;
+}
+
+impl CfReqZone for CreateRule {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/rulesets/{}/rules", self.ruleset_id)
+    }
+}
+
+/// [Update a zone ruleset rule](https://developers.cloudflare.com/api/operations/updateZoneRulesetRule) API
+#[derive(Serialize)]
+pub struct UpdateRule {
+    #[serde(skip)]
+    ruleset_id: String,
+    #[serde(skip)]
+    rule_id: String,
+    #[serde(flatten)]
+    rule: Rule,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<RulePosition>,
+}
+
+impl UpdateRule {
+    /// Replace the rule `rule_id` of the ruleset `ruleset_id` with `rule`
+    pub fn new(ruleset_id: impl Into<String>, rule_id: impl Into<String>, rule: Rule) -> Self {
+        Self {
+            ruleset_id: ruleset_id.into(),
+            rule_id: rule_id.into(),
+            rule: Rule { id: None, ..rule },
+            position: None,
+        }
+    }
+
+    /// Move the rule to `position`
+    pub fn position(mut self, position: RulePosition) -> Self {
+        self.position = Some(position);
+        self
+    }
+}
+
+/// Success response from the [Update a zone ruleset rule](https://developers.cloudflare.com/api/operations/updateZoneRulesetRule#Responses) API
+/// The whole updated ruleset is returned
+pub type UpdateRuleRes = Ruleset;
+
+impl CfReqMeta for UpdateRule {
+    const METHOD: Method = Method::PATCH;
+    type Response = UpdateRuleRes;
+}
+
+impl CfReqZone for UpdateRule {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!(
+            "zones/{zone_id}/rulesets/{}/rules/{}",
+            self.ruleset_id, self.rule_id
+        )
+    }
+}
+
+/// [Delete a zone ruleset rule](https://developers.cloudflare.com/api/operations/deleteZoneRulesetRule) API
+#[derive(Serialize)]
+pub struct DeleteRule {
+    #[serde(skip)]
+    ruleset_id: String,
+    #[serde(skip)]
+    rule_id: String,
+}
+
+impl DeleteRule {
+    /// Delete the rule `rule_id` of the ruleset `ruleset_id`
+    pub fn new(ruleset_id: impl Into<String>, rule_id: impl Into<String>) -> Self {
+        Self {
+            ruleset_id: ruleset_id.into(),
+            rule_id: rule_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete a zone ruleset rule](https://developers.cloudflare.com/api/operations/deleteZoneRulesetRule#Responses) API
+/// The whole updated ruleset is returned
+pub type DeleteRuleRes = Ruleset;
+
+impl CfReqMeta for DeleteRule {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteRuleRes;
+}
+
+impl CfReqZone for DeleteRule {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!(
+            "zones/{zone_id}/rulesets/{}/rules/{}",
+            self.ruleset_id, self.rule_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    #[test]
+    fn block_rule_keeps_custom_response() {
+        let rule = json!({
+            "id": "3a03d665bac047339bb530ecb439a90d",
+            "version": "1",
+            "action": "block",
+            "action_parameters": {
+                "response": {
+                    "status_code": 403,
+                    "content": "{\"error\": \"blocked\"}",
+                    "content_type": "application/json"
+                }
+            },
+            "expression": "ip.src.country eq \"XX\"",
+            "description": "Block country XX",
+            "enabled": true,
+            "last_updated": "2024-05-02T13:42:16.513597Z"
+        });
+        let parsed: Rule = serde_json::from_value(rule.clone()).unwrap();
+        let mut expected = rule;
+        let object = expected.as_object_mut().unwrap();
+        object.remove("version");
+        object.remove("last_updated");
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), expected);
+    }
+
+    #[test]
+    fn block_rule_without_parameters() {
+        let rule: Rule = serde_json::from_value(json!({
+            "action": "block",
+            "expression": "true"
+        }))
+        .unwrap();
+        assert_eq!(rule.action, RuleAction::Block);
+        let value = serde_json::to_value(&rule).unwrap();
+        assert_eq!(value["action"], "block");
+        assert_eq!(value.get("action_parameters"), None::<&Value>);
+    }
+}
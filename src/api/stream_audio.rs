@@ -0,0 +1,215 @@
+//! Cloudflare Stream audio track APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/stream/edit-videos/adding-additional-audio-tracks/)
+use crate::{CfReqAuth, CfReqMeta, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Processing status of an audio track
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioTrackStatus {
+    /// Waiting to be processed
+    Queued,
+    /// Ready for playback
+    Ready,
+    /// Processing failed
+    Error,
+    /// A status unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// An additional audio track of a video
+#[derive(Serialize, Deserialize)]
+pub struct AudioTrack {
+    /// Unique identifier for the audio track
+    pub uid: String,
+    /// Label of the audio track shown in the player
+    pub label: String,
+    /// Whether this is the default track for playback
+    #[serde(default)]
+    pub default: bool,
+    /// Processing status
+    pub status: Option<AudioTrackStatus>,
+}
+
+/// [List additional audio tracks on a video](https://developers.cloudflare.com/api/operations/list-audio-tracks) API
+#[derive(Serialize)]
+pub struct ListAudioTracks {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl ListAudioTracks {
+    /// List the additional audio tracks of a video
+    /// identifier is the video's uid
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [List additional audio tracks on a video](https://developers.cloudflare.com/api/operations/list-audio-tracks#Responses) API
+pub type ListAudioTracksRes = Vec<AudioTrack>;
+
+impl CfReqMeta for ListAudioTracks {
+    const METHOD: Method = Method::GET;
+    type Response = ListAudioTracksRes;
+}
+
+impl CfReqAuth for ListAudioTracks {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/{}/audio", self.identifier)
+    }
+}
+
+/// [Add audio tracks to a video](https://developers.cloudflare.com/api/operations/add-audio-track) API
+#[derive(Serialize)]
+pub struct AddAudioTrackFromUrl {
+    #[serde(skip)]
+    identifier: String,
+    url: String,
+    label: String,
+}
+
+impl AddAudioTrackFromUrl {
+    /// Add the audio file available at the given URL as a track of the video
+    /// identifier is the video's uid
+    pub fn new(
+        identifier: impl Into<String>,
+        url: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            identifier: identifier.into(),
+            url: url.into(),
+            label: label.into(),
+        }
+    }
+}
+
+/// Success response from the [Add audio tracks to a video](https://developers.cloudflare.com/api/operations/add-audio-track#Responses) API
+pub type AddAudioTrackFromUrlRes = AudioTrack;
+
+impl CfReqMeta for AddAudioTrackFromUrl {
+    const METHOD: Method = Method::POST;
+    type Response = AddAudioTrackFromUrlRes;
+}
+
+impl CfReqAuth for AddAudioTrackFromUrl {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/{}/audio/copy",
+            self.identifier
+        )
+    }
+}
+
+/// [Edit additional audio tracks on a video](https://developers.cloudflare.com/api/operations/edit-audio-tracks) API
+/// Only the fields that are set are sent
+#[derive(Serialize)]
+pub struct EditAudioTrack {
+    #[serde(skip)]
+    identifier: String,
+    #[serde(skip)]
+    audio_identifier: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default: Option<bool>,
+}
+
+impl EditAudioTrack {
+    /// Edit an audio track of a video
+    /// identifier is the video's uid and audio_identifier is the audio track's uid
+    pub fn new(identifier: impl Into<String>, audio_identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+            audio_identifier: audio_identifier.into(),
+            label: None,
+            default: None,
+        }
+    }
+
+    /// Label of the audio track shown in the player
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Make this the default track for playback, unsetting any other default
+    pub fn default(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self
+    }
+}
+
+/// Success response from the [Edit additional audio tracks on a video](https://developers.cloudflare.com/api/operations/edit-audio-tracks#Responses) API
+pub type EditAudioTrackRes = AudioTrack;
+
+impl CfReqMeta for EditAudioTrack {
+    const METHOD: Method = Method::PATCH;
+    type Response = EditAudioTrackRes;
+}
+
+impl CfReqAuth for EditAudioTrack {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/{}/audio/{}",
+            self.identifier, self.audio_identifier
+        )
+    }
+}
+
+/// [Delete additional audio tracks on a video](https://developers.cloudflare.com/api/operations/delete-audio-tracks) API
+#[derive(Serialize)]
+pub struct DeleteAudioTrack {
+    #[serde(skip)]
+    identifier: String,
+    #[serde(skip)]
+    audio_identifier: String,
+}
+
+impl DeleteAudioTrack {
+    /// Delete an audio track of a video
+    /// identifier is the video's uid and audio_identifier is the audio track's uid
+    pub fn new(identifier: impl Into<String>, audio_identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+            audio_identifier: audio_identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete additional audio tracks on a video](https://developers.cloudflare.com/api/operations/delete-audio-tracks#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteAudioTrackRes;
+
+impl CfReqMeta for DeleteAudioTrack {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteAudioTrackRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is a plain "ok" string
+        Ok(DeleteAudioTrackRes)
+    }
+}
+
+impl CfReqAuth for DeleteAudioTrack {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/{}/audio/{}",
+            self.identifier, self.audio_identifier
+        )
+    }
+}
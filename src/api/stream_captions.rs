@@ -0,0 +1,308 @@
+//! Cloudflare Stream caption (subtitle) APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/stream/edit-videos/adding-captions/)
+use crate::{
+    request::{encode_segment, file_part},
+    CfReqAuth, CfReqMeta, Error, Result,
+};
+use bytes::Bytes;
+use reqwest::{multipart::Form, Method};
+use serde::{Deserialize, Serialize};
+
+fn check_language(language: &str) -> Result<()> {
+    if language.trim().is_empty() {
+        return Err(Error::InvalidRequest(
+            "caption language must be a non-empty BCP-47 tag".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Status of generated captions
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptionStatus {
+    /// Captions are being generated
+    Inprogress,
+    /// Captions are ready
+    Ready,
+    /// Caption generation failed
+    Error,
+    /// A status unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// A caption track of a video
+#[derive(Serialize, Deserialize)]
+pub struct Caption {
+    /// BCP-47 language tag
+    pub language: String,
+    /// Label of the caption track shown in the player
+    pub label: String,
+    /// Whether the caption was generated by cloudflare
+    pub generated: Option<bool>,
+    /// Status of generated captions
+    pub status: Option<CaptionStatus>,
+}
+
+/// [Upload captions or subtitles](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-upload-captions-or-subtitles) API
+/// Sent via [crate::CloudflareAuth::send_auth_multipart]
+pub struct UploadCaption {
+    identifier: String,
+    language: String,
+    file: Bytes,
+}
+
+impl UploadCaption {
+    /// Upload a WebVTT caption file for the video
+    /// identifier is the video's uid and language is a BCP-47 tag
+    pub fn new(
+        identifier: impl Into<String>,
+        language: &str,
+        file: impl Into<Bytes>,
+    ) -> Result<Self> {
+        check_language(language)?;
+        Ok(Self {
+            identifier: identifier.into(),
+            language: language.to_string(),
+            file: file.into(),
+        })
+    }
+}
+
+/// Success response from the [Upload captions or subtitles](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-upload-captions-or-subtitles#Responses) API
+pub type UploadCaptionRes = Caption;
+
+impl CfReqMeta for UploadCaption {
+    const METHOD: Method = Method::PUT;
+    type Response = UploadCaptionRes;
+}
+
+impl CfReqAuth for UploadCaption {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/{}/captions/{}",
+            self.identifier,
+            encode_segment(&self.language)
+        )
+    }
+}
+
+impl TryFrom<UploadCaption> for Form {
+    type Error = Error;
+
+    fn try_from(value: UploadCaption) -> Result<Self> {
+        let file_name = format!("{}.vtt", value.language);
+        Ok(Form::new().part("file", file_part(value.file, file_name)?))
+    }
+}
+
+/// [List captions or subtitles](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-list-captions-or-subtitles) API
+#[derive(Serialize)]
+pub struct ListCaptions {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl ListCaptions {
+    /// List the captions of the video
+    /// identifier is the video's uid
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [List captions or subtitles](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-list-captions-or-subtitles#Responses) API
+pub type ListCaptionsRes = Vec<Caption>;
+
+impl CfReqMeta for ListCaptions {
+    const METHOD: Method = Method::GET;
+    type Response = ListCaptionsRes;
+}
+
+impl CfReqAuth for ListCaptions {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/{}/captions", self.identifier)
+    }
+}
+
+/// [Return WebVTT captions for a provided language](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-get-vtt-caption-or-subtitle) API
+#[derive(Serialize)]
+pub struct GetCaptionFile {
+    #[serde(skip)]
+    identifier: String,
+    #[serde(skip)]
+    language: String,
+}
+
+impl GetCaptionFile {
+    /// Get the WebVTT file of the video's captions in the given language
+    /// identifier is the video's uid and language is a BCP-47 tag
+    pub fn new(identifier: impl Into<String>, language: &str) -> Result<Self> {
+        check_language(language)?;
+        Ok(Self {
+            identifier: identifier.into(),
+            language: language.to_string(),
+        })
+    }
+}
+
+/// WebVTT caption file
+pub type GetCaptionFileRes = String;
+
+impl CfReqMeta for GetCaptionFile {
+    const METHOD: Method = Method::GET;
+    type Response = GetCaptionFileRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        Ok(std::str::from_utf8(&body)?.to_string())
+    }
+}
+
+impl CfReqAuth for GetCaptionFile {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/{}/captions/{}/vtt",
+            self.identifier,
+            encode_segment(&self.language)
+        )
+    }
+}
+
+/// [Delete captions or subtitles](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-delete-captions-or-subtitles) API
+#[derive(Serialize)]
+pub struct DeleteCaption {
+    #[serde(skip)]
+    identifier: String,
+    #[serde(skip)]
+    language: String,
+}
+
+impl DeleteCaption {
+    /// Delete the video's captions in the given language
+    /// identifier is the video's uid and language is a BCP-47 tag
+    pub fn new(identifier: impl Into<String>, language: &str) -> Result<Self> {
+        check_language(language)?;
+        Ok(Self {
+            identifier: identifier.into(),
+            language: language.to_string(),
+        })
+    }
+}
+
+/// Success response from the [Delete captions or subtitles](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-delete-captions-or-subtitles#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteCaptionRes;
+
+impl CfReqMeta for DeleteCaption {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteCaptionRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty string
+        Ok(DeleteCaptionRes)
+    }
+}
+
+impl CfReqAuth for DeleteCaption {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/{}/captions/{}",
+            self.identifier,
+            encode_segment(&self.language)
+        )
+    }
+}
+
+/// [Generate captions or subtitles](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-generate-caption-or-subtitle-for-provided-language-via-ai) API
+/// The video must be ready to stream, poll the generation status with [GetCaption]
+#[derive(Serialize)]
+pub struct GenerateCaption {
+    #[serde(skip)]
+    identifier: String,
+    #[serde(skip)]
+    language: String,
+}
+
+impl GenerateCaption {
+    /// Generate captions for the video in the given language
+    /// identifier is the video's uid and language is a BCP-47 tag
+    pub fn new(identifier: impl Into<String>, language: &str) -> Result<Self> {
+        check_language(language)?;
+        Ok(Self {
+            identifier: identifier.into(),
+            language: language.to_string(),
+        })
+    }
+}
+
+/// Success response from the [Generate captions or subtitles](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-generate-caption-or-subtitle-for-provided-language-via-ai#Responses) API
+pub type GenerateCaptionRes = Caption;
+
+impl CfReqMeta for GenerateCaption {
+    const METHOD: Method = Method::POST;
+    type Response = GenerateCaptionRes;
+}
+
+impl CfReqAuth for GenerateCaption {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/{}/captions/{}/generate",
+            self.identifier,
+            encode_segment(&self.language)
+        )
+    }
+}
+
+/// [List captions or subtitles for a provided language](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-get-caption-or-subtitle-for-language) API
+#[derive(Serialize)]
+pub struct GetCaption {
+    #[serde(skip)]
+    identifier: String,
+    #[serde(skip)]
+    language: String,
+}
+
+impl GetCaption {
+    /// Get the video's caption track in the given language
+    /// identifier is the video's uid and language is a BCP-47 tag
+    pub fn new(identifier: impl Into<String>, language: &str) -> Result<Self> {
+        check_language(language)?;
+        Ok(Self {
+            identifier: identifier.into(),
+            language: language.to_string(),
+        })
+    }
+}
+
+/// Success response from the [List captions or subtitles for a provided language](https://developers.cloudflare.com/api/operations/stream-subtitles/captions-get-caption-or-subtitle-for-language#Responses) API
+pub type GetCaptionRes = Caption;
+
+impl CfReqMeta for GetCaption {
+    const METHOD: Method = Method::GET;
+    type Response = GetCaptionRes;
+}
+
+impl CfReqAuth for GetCaption {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/{}/captions/{}",
+            self.identifier,
+            encode_segment(&self.language)
+        )
+    }
+}
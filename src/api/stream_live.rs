@@ -0,0 +1,621 @@
+//! Cloudflare Stream Live Inputs APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/stream/stream-live/)
+use std::{collections::HashMap, fmt, time::Duration};
+
+use crate::{api::stream_videos::Video, CfReqAuth, CfReqMeta, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Recording mode of a live input
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordingMode {
+    /// Broadcasts are not recorded
+    Off,
+    /// Broadcasts are recorded as videos once they start
+    Automatic,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct RecordingSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<RecordingMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout_seconds: Option<u64>,
+    #[serde(rename = "requireSignedURLs", skip_serializing_if = "Option::is_none")]
+    require_signed_urls: Option<bool>,
+}
+
+/// Configuration of a live input, used by [CreateLiveInput] and [UpdateLiveInput]
+/// Only the fields that are set are sent
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recording: Option<RecordingSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_recording_after_days: Option<u32>,
+}
+
+impl LiveInput {
+    /// Add metadata to the live input
+    pub fn add_meta(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.meta
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Whether broadcasts are recorded
+    pub fn recording_mode(mut self, mode: RecordingMode) -> Self {
+        self.recording.get_or_insert_with(Default::default).mode = Some(mode);
+        self
+    }
+
+    /// How long to wait after the broadcaster disconnects before the recording ends
+    pub fn recording_timeout(mut self, timeout: Duration) -> Self {
+        self.recording
+            .get_or_insert_with(Default::default)
+            .timeout_seconds = Some(timeout.as_secs());
+        self
+    }
+
+    /// Require signed URLs for playback of the recordings
+    pub fn recording_require_signed_urls(mut self, require_signed_urls: bool) -> Self {
+        self.recording
+            .get_or_insert_with(Default::default)
+            .require_signed_urls = Some(require_signed_urls);
+        self
+    }
+
+    /// Delete recordings after the given number of days
+    pub fn delete_recording_after_days(mut self, days: u32) -> Self {
+        self.delete_recording_after_days = Some(days);
+        self
+    }
+}
+
+/// Recording settings of a live input
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Recording {
+    /// Recording mode
+    pub mode: RecordingMode,
+    /// Seconds to wait after the broadcaster disconnects before the recording ends
+    pub timeout_seconds: Option<u64>,
+    /// Whether signed URLs are required for playback of the recordings
+    #[serde(rename = "requireSignedURLs")]
+    pub require_signed_urls: Option<bool>,
+    /// Origins allowed to embed the recordings
+    pub allowed_origins: Option<Vec<String>>,
+}
+
+/// RTMPS connection details
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RtmpsConnection {
+    /// RTMPS URL
+    pub url: String,
+    /// Secret key for the connection
+    pub stream_key: String,
+}
+
+/// SRT connection details
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SrtConnection {
+    /// SRT URL
+    pub url: String,
+    /// Stream ID for the connection
+    pub stream_id: String,
+    /// Secret passphrase for the connection
+    pub passphrase: String,
+}
+
+/// WebRTC connection details
+#[derive(Serialize, Deserialize)]
+pub struct WebRtcConnection {
+    /// WHIP/WHEP URL
+    pub url: String,
+}
+
+/// A live input with its connection details
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveInputDetails {
+    /// Unique identifier for the live input
+    pub uid: String,
+    /// RFC3339 timestamp of when the live input was created
+    pub created: Option<String>,
+    /// RFC3339 timestamp of when the live input was last modified
+    pub modified: Option<String>,
+    /// Metadata for the live input
+    #[serde(default)]
+    pub meta: HashMap<String, String>,
+    /// Recording settings
+    pub recording: Option<Recording>,
+    /// Days after which recordings are deleted
+    pub delete_recording_after_days: Option<u32>,
+    /// RTMPS details for broadcasting
+    pub rtmps: Option<RtmpsConnection>,
+    /// RTMPS details for playback
+    pub rtmps_playback: Option<RtmpsConnection>,
+    /// SRT details for broadcasting
+    pub srt: Option<SrtConnection>,
+    /// SRT details for playback
+    pub srt_playback: Option<SrtConnection>,
+    /// WebRTC (WHIP) details for broadcasting
+    #[serde(rename = "webRTC")]
+    pub web_rtc: Option<WebRtcConnection>,
+    /// WebRTC (WHEP) details for playback
+    #[serde(rename = "webRTCPlayback")]
+    pub web_rtc_playback: Option<WebRtcConnection>,
+}
+
+/// [Create a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-create-a-live-input) API
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct CreateLiveInput {
+    input: LiveInput,
+}
+
+impl CreateLiveInput {
+    /// Create a live input with the given configuration
+    pub fn new(input: LiveInput) -> Self {
+        Self { input }
+    }
+}
+
+/// Success response from the [Create a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-create-a-live-input#Responses) API
+pub type CreateLiveInputRes = LiveInputDetails;
+
+impl CfReqMeta for CreateLiveInput {
+    const METHOD: Method = Method::POST;
+    type Response = CreateLiveInputRes;
+}
+
+impl CfReqAuth for CreateLiveInput {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/live_inputs")
+    }
+}
+
+/// [Retrieve a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-retrieve-a-live-input) API
+#[derive(Serialize)]
+pub struct GetLiveInput {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl GetLiveInput {
+    /// Retrieve details of a live input
+    /// identifier is the live input's uid
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Retrieve a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-retrieve-a-live-input#Responses) API
+pub type GetLiveInputRes = LiveInputDetails;
+
+impl CfReqMeta for GetLiveInput {
+    const METHOD: Method = Method::GET;
+    type Response = GetLiveInputRes;
+}
+
+impl CfReqAuth for GetLiveInput {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/live_inputs/{}",
+            self.identifier
+        )
+    }
+}
+
+/// [Update a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-update-a-live-input) API
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct UpdateLiveInput {
+    #[serde(skip)]
+    identifier: String,
+    input: LiveInput,
+}
+
+impl UpdateLiveInput {
+    /// Update a live input with the given configuration
+    /// identifier is the live input's uid
+    pub fn new(identifier: impl Into<String>, input: LiveInput) -> Self {
+        Self {
+            identifier: identifier.into(),
+            input,
+        }
+    }
+}
+
+/// Success response from the [Update a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-update-a-live-input#Responses) API
+pub type UpdateLiveInputRes = LiveInputDetails;
+
+impl CfReqMeta for UpdateLiveInput {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateLiveInputRes;
+}
+
+impl CfReqAuth for UpdateLiveInput {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/live_inputs/{}",
+            self.identifier
+        )
+    }
+}
+
+/// [Delete a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-delete-a-live-input) API
+#[derive(Serialize)]
+pub struct DeleteLiveInput {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl DeleteLiveInput {
+    /// Delete a live input
+    /// identifier is the live input's uid
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-delete-a-live-input#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteLiveInputRes;
+
+impl CfReqMeta for DeleteLiveInput {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteLiveInputRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // cloudflare responds with an empty body
+        Ok(DeleteLiveInputRes)
+    }
+}
+
+impl CfReqAuth for DeleteLiveInput {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/live_inputs/{}",
+            self.identifier
+        )
+    }
+}
+
+/// [List live inputs](https://developers.cloudflare.com/api/operations/stream-live-inputs-list-live-inputs) API
+#[derive(Serialize, Default)]
+pub struct ListLiveInputs {
+    include_counts: Option<bool>,
+}
+
+impl ListLiveInputs {
+    /// Include the total number of live inputs in the response
+    pub fn include_counts(mut self, include_counts: bool) -> Self {
+        self.include_counts = Some(include_counts);
+        self
+    }
+}
+
+/// A live input returned by [ListLiveInputs], without connection details
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveInputSummary {
+    /// Unique identifier for the live input
+    pub uid: String,
+    /// RFC3339 timestamp of when the live input was created
+    pub created: Option<String>,
+    /// RFC3339 timestamp of when the live input was last modified
+    pub modified: Option<String>,
+    /// Metadata for the live input
+    #[serde(default)]
+    pub meta: HashMap<String, String>,
+    /// Days after which recordings are deleted
+    pub delete_recording_after_days: Option<u32>,
+}
+
+/// Success response from the [List live inputs](https://developers.cloudflare.com/api/operations/stream-live-inputs-list-live-inputs#Responses) API
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListLiveInputsRes {
+    /// Live inputs
+    pub live_inputs: Vec<LiveInputSummary>,
+    /// Number of live inputs in the response
+    pub range: Option<u64>,
+    /// Total number of live inputs, only present with [ListLiveInputs::include_counts]
+    pub total: Option<u64>,
+}
+
+impl CfReqMeta for ListLiveInputs {
+    const METHOD: Method = Method::GET;
+    type Response = ListLiveInputsRes;
+}
+
+impl CfReqAuth for ListLiveInputs {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/live_inputs")
+    }
+}
+
+/// An output (simulcast destination) of a live input
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    /// Unique identifier for the output
+    pub uid: String,
+    /// RTMP(S) URL of the destination
+    pub url: String,
+    /// Secret key for the destination
+    pub stream_key: String,
+    /// Whether the output is active
+    pub enabled: bool,
+}
+
+impl fmt::Debug for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Output")
+            .field("uid", &self.uid)
+            .field("url", &self.url)
+            .field("stream_key", &"<redacted>")
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+/// [Create a new output, connected to a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-create-a-new-output,-connected-to-a-live-input) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOutput {
+    #[serde(skip)]
+    live_input: String,
+    url: String,
+    stream_key: String,
+    enabled: bool,
+}
+
+impl CreateOutput {
+    /// Restream the live input to the given RTMP(S) URL
+    /// live_input is the live input's uid
+    pub fn new(
+        live_input: impl Into<String>,
+        url: impl Into<String>,
+        stream_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            live_input: live_input.into(),
+            url: url.into(),
+            stream_key: stream_key.into(),
+            enabled: true,
+        }
+    }
+
+    /// Whether the output is active (defaults to true)
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl fmt::Debug for CreateOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateOutput")
+            .field("live_input", &self.live_input)
+            .field("url", &self.url)
+            .field("stream_key", &"<redacted>")
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+/// Success response from the [Create a new output, connected to a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-create-a-new-output,-connected-to-a-live-input#Responses) API
+pub type CreateOutputRes = Output;
+
+impl CfReqMeta for CreateOutput {
+    const METHOD: Method = Method::POST;
+    type Response = CreateOutputRes;
+}
+
+impl CfReqAuth for CreateOutput {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/live_inputs/{}/outputs",
+            self.live_input
+        )
+    }
+}
+
+/// [List all outputs associated with a specified live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-list-all-outputs-associated-with-a-specified-live-input) API
+#[derive(Serialize)]
+pub struct ListOutputs {
+    #[serde(skip)]
+    live_input: String,
+}
+
+impl ListOutputs {
+    /// List the outputs of a live input
+    /// live_input is the live input's uid
+    pub fn new(live_input: impl Into<String>) -> Self {
+        Self {
+            live_input: live_input.into(),
+        }
+    }
+}
+
+/// Success response from the [List all outputs associated with a specified live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-list-all-outputs-associated-with-a-specified-live-input#Responses) API
+pub type ListOutputsRes = Vec<Output>;
+
+impl CfReqMeta for ListOutputs {
+    const METHOD: Method = Method::GET;
+    type Response = ListOutputsRes;
+}
+
+impl CfReqAuth for ListOutputs {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/live_inputs/{}/outputs",
+            self.live_input
+        )
+    }
+}
+
+/// [Update an output](https://developers.cloudflare.com/api/operations/stream-live-inputs-update-an-output) API
+/// Serializes to exactly `{"enabled": <bool>}`
+#[derive(Serialize, Debug)]
+pub struct ToggleOutput {
+    #[serde(skip)]
+    live_input: String,
+    #[serde(skip)]
+    output: String,
+    enabled: bool,
+}
+
+impl ToggleOutput {
+    /// Enable or disable an output
+    /// live_input is the live input's uid and output is the output's uid
+    pub fn new(live_input: impl Into<String>, output: impl Into<String>, enabled: bool) -> Self {
+        Self {
+            live_input: live_input.into(),
+            output: output.into(),
+            enabled,
+        }
+    }
+}
+
+/// Success response from the [Update an output](https://developers.cloudflare.com/api/operations/stream-live-inputs-update-an-output#Responses) API
+pub type ToggleOutputRes = Output;
+
+impl CfReqMeta for ToggleOutput {
+    const METHOD: Method = Method::PUT;
+    type Response = ToggleOutputRes;
+}
+
+impl CfReqAuth for ToggleOutput {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/live_inputs/{}/outputs/{}",
+            self.live_input, self.output
+        )
+    }
+}
+
+/// [Delete an output](https://developers.cloudflare.com/api/operations/stream-live-inputs-delete-an-output) API
+#[derive(Serialize, Debug)]
+pub struct DeleteOutput {
+    #[serde(skip)]
+    live_input: String,
+    #[serde(skip)]
+    output: String,
+}
+
+impl DeleteOutput {
+    /// Delete an output
+    /// live_input is the live input's uid and output is the output's uid
+    pub fn new(live_input: impl Into<String>, output: impl Into<String>) -> Self {
+        Self {
+            live_input: live_input.into(),
+            output: output.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete an output](https://developers.cloudflare.com/api/operations/stream-live-inputs-delete-an-output#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteOutputRes;
+
+impl CfReqMeta for DeleteOutput {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteOutputRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // cloudflare responds with an empty body
+        Ok(DeleteOutputRes)
+    }
+}
+
+impl CfReqAuth for DeleteOutput {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/live_inputs/{}/outputs/{}",
+            self.live_input, self.output
+        )
+    }
+}
+
+/// [List videos associated with a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-list-videos-associated-with-a-live-input) API
+/// Recordings still in progress have a duration of -1
+#[derive(Serialize)]
+pub struct ListLiveInputVideos {
+    #[serde(skip)]
+    live_input: String,
+}
+
+impl ListLiveInputVideos {
+    /// List the recordings of a live input
+    /// live_input is the live input's uid
+    pub fn new(live_input: impl Into<String>) -> Self {
+        Self {
+            live_input: live_input.into(),
+        }
+    }
+}
+
+/// Success response from the [List videos associated with a live input](https://developers.cloudflare.com/api/operations/stream-live-inputs-list-videos-associated-with-a-live-input#Responses) API
+pub type ListLiveInputVideosRes = Vec<Video>;
+
+impl CfReqMeta for ListLiveInputVideos {
+    const METHOD: Method = Method::GET;
+    type Response = ListLiveInputVideosRes;
+}
+
+impl CfReqAuth for ListLiveInputVideos {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/live_inputs/{}/videos",
+            self.live_input
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_output_only_sends_enabled() -> Result<()> {
+        let req = ToggleOutput::new("live-input-uid", "output-uid", false);
+        assert_eq!(serde_json::to_string(&req)?, r#"{"enabled":false}"#);
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+//! Resumable uploads of large Stream videos via the TUS protocol
+//! See [Cloudflare Docs](https://developers.cloudflare.com/stream/uploading-videos/resumable-uploads/)
+use std::time::Duration;
+
+use base64::Engine;
+use bytes::Bytes;
+use reqwest::{header::HeaderMap, RequestBuilder, Response, StatusCode, Url};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{CloudflareAuth, Error, Result};
+
+/// Minimum chunk size accepted by cloudflare (except for the last chunk)
+pub const TUS_MIN_CHUNK_SIZE: usize = 5 * 1024 * 1024;
+/// Chunk sizes must be a multiple of this (except for the last chunk)
+pub const TUS_CHUNK_ALIGNMENT: usize = 256 * 1024;
+
+const TUS_VERSION: &str = "1.0.0";
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Where to upload the video
+pub enum TusTarget<'a> {
+    /// Create the upload in the client's account
+    Account,
+    /// Upload to an existing TUS upload URL, e.g one handed out for a direct creator upload
+    UploadUrl(&'a str),
+}
+
+/// Options for [upload_tus]
+pub struct TusOptions {
+    chunk_size: usize,
+    metadata: Vec<(String, String)>,
+    max_retries: u32,
+    progress: Option<Box<dyn FnMut(u64, u64) + Send>>,
+}
+
+impl Default for TusOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: 50 * 1024 * 1024,
+            metadata: Vec::new(),
+            max_retries: 3,
+            progress: None,
+        }
+    }
+}
+
+impl TusOptions {
+    /// Size of each uploaded chunk (defaults to 50MiB)
+    /// Must be at least [TUS_MIN_CHUNK_SIZE] and a multiple of [TUS_CHUNK_ALIGNMENT]
+    pub fn chunk_size(mut self, chunk_size: usize) -> Result<Self> {
+        if chunk_size < TUS_MIN_CHUNK_SIZE || !chunk_size.is_multiple_of(TUS_CHUNK_ALIGNMENT) {
+            return Err(Error::InvalidRequest(format!(
+                "tus chunk size must be at least {TUS_MIN_CHUNK_SIZE} bytes and a multiple of {TUS_CHUNK_ALIGNMENT}, got {chunk_size}"
+            )));
+        }
+        self.chunk_size = chunk_size;
+        Ok(self)
+    }
+
+    /// Add an `Upload-Metadata` entry (e.g `name`, `requiresignedurls`, `maxDurationSeconds`)
+    /// Only used when creating the upload ([TusTarget::Account])
+    pub fn add_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.push((key.into(), value.into()));
+        self
+    }
+
+    /// Number of times a failing chunk is retried (defaults to 3)
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Called with the number of bytes uploaded and the total size after every chunk
+    pub fn on_progress(mut self, progress: impl FnMut(u64, u64) + Send + 'static) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn media_id(headers: &HeaderMap) -> Option<String> {
+    header(headers, "stream-media-id").map(str::to_string)
+}
+
+fn upload_offset(resp: &Response) -> Result<u64> {
+    header(resp.headers(), "Upload-Offset")
+        .and_then(|offset| offset.parse().ok())
+        .ok_or_else(|| Error::Tus("missing Upload-Offset header".into()))
+}
+
+fn check_status(resp: &Response, expected: StatusCode) -> Result<()> {
+    if resp.status() != expected {
+        return Err(Error::Tus(format!(
+            "expected status {expected}, got {}",
+            resp.status()
+        )));
+    }
+    Ok(())
+}
+
+struct TusSession<'a> {
+    client: &'a CloudflareAuth,
+    url: Url,
+    auth: bool,
+}
+
+impl TusSession<'_> {
+    fn request(&self, method: reqwest::Method) -> RequestBuilder {
+        let creds = self.auth.then_some(&*self.client.creds);
+        self.client
+            .inner
+            .req_builder(method, self.url.clone(), creds)
+            .header("Tus-Resumable", TUS_VERSION)
+    }
+
+    async fn patch(&self, offset: u64, data: Bytes) -> Result<(u64, Option<String>)> {
+        let resp = self
+            .request(reqwest::Method::PATCH)
+            .header("Upload-Offset", offset)
+            .header("Content-Type", "application/offset+octet-stream")
+            .body(data)
+            .send()
+            .await?;
+        check_status(&resp, StatusCode::NO_CONTENT)?;
+        Ok((upload_offset(&resp)?, media_id(resp.headers())))
+    }
+
+    async fn offset(&self) -> Result<u64> {
+        let resp = self.request(reqwest::Method::HEAD).send().await?;
+        check_status(&resp, StatusCode::OK)?;
+        upload_offset(&resp)
+    }
+}
+
+async fn create<'a>(
+    client: &'a CloudflareAuth,
+    len: u64,
+    metadata: &[(String, String)],
+) -> Result<(TusSession<'a>, Option<String>)> {
+    let url = client
+        .inner
+        .base_url
+        .join(&format!("accounts/{}/stream", client.creds.account_id))?;
+    let metadata = metadata
+        .iter()
+        .map(|(key, value)| {
+            let value = base64::engine::general_purpose::STANDARD.encode(value);
+            format!("{key} {value}")
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let mut reqb = client
+        .inner
+        .req_builder(reqwest::Method::POST, url.clone(), Some(&client.creds))
+        .header("Tus-Resumable", TUS_VERSION)
+        .header("Upload-Length", len);
+    if !metadata.is_empty() {
+        reqb = reqb.header("Upload-Metadata", metadata);
+    }
+    let resp = reqb.send().await?;
+    check_status(&resp, StatusCode::CREATED)?;
+    let location = header(resp.headers(), "Location")
+        .ok_or_else(|| Error::Tus("missing Location header".into()))?;
+    let session = TusSession {
+        client,
+        url: url.join(location)?,
+        auth: true,
+    };
+    Ok((session, media_id(resp.headers())))
+}
+
+async fn read_chunk(reader: &mut (impl AsyncRead + Unpin), buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let read = reader.read(&mut buf[filled..]).await?;
+        if read == 0 {
+            break;
+        }
+        filled += read;
+    }
+    Ok(filled)
+}
+
+/// Upload `len` bytes from `reader` as a Stream video using the TUS protocol
+/// Failed chunks are retried after re-querying the upload offset from cloudflare.
+/// Returns the uid of the uploaded video
+pub async fn upload_tus(
+    client: &CloudflareAuth,
+    target: TusTarget<'_>,
+    mut reader: impl AsyncRead + Unpin,
+    len: u64,
+    mut options: TusOptions,
+) -> Result<String> {
+    let (session, mut video_uid) = match target {
+        TusTarget::Account => create(client, len, &options.metadata).await?,
+        TusTarget::UploadUrl(url) => {
+            let session = TusSession {
+                client,
+                url: url.parse()?,
+                auth: false,
+            };
+            (session, None)
+        }
+    };
+
+    let mut chunk = vec![0; options.chunk_size];
+    let mut offset = 0;
+    while offset < len {
+        let chunk_len = read_chunk(&mut reader, &mut chunk).await?;
+        if chunk_len == 0 {
+            return Err(Error::Tus(format!(
+                "reader ended after {offset} bytes, expected {len}"
+            )));
+        }
+        let chunk_start = offset;
+        let chunk_end = chunk_start + chunk_len as u64;
+        let mut retries = 0;
+        while offset < chunk_end {
+            let data = Bytes::copy_from_slice(&chunk[(offset - chunk_start) as usize..chunk_len]);
+            let err = match session.patch(offset, data).await {
+                Ok((new_offset, uid)) => {
+                    // a stuck offset would resend the slice forever and one past the chunk
+                    // would upload the next chunk at the wrong offset
+                    if new_offset <= offset || new_offset > chunk_end {
+                        return Err(Error::Tus(format!(
+                            "server moved the offset from {offset} to {new_offset}, expected ({offset}, {chunk_end}]"
+                        )));
+                    }
+                    offset = new_offset;
+                    video_uid = video_uid.or(uid);
+                    continue;
+                }
+                Err(err) => err,
+            };
+            if retries >= options.max_retries {
+                return Err(err);
+            }
+            retries += 1;
+            tokio::time::sleep(RETRY_DELAY * retries).await;
+            // the offset is re-queried so that retrying never uploads a byte twice
+            if let Ok(server_offset) = session.offset().await {
+                offset = server_offset.clamp(chunk_start, chunk_end);
+            }
+        }
+        if let Some(progress) = &mut options.progress {
+            progress(offset, len);
+        }
+    }
+
+    video_uid.ok_or_else(|| Error::Tus("missing stream-media-id header".into()))
+}
@@ -1,9 +1,21 @@
 //! Cloudflare Stream Videos related APIs
 //! See [Cloudflare Docs](https://developers.cloudflare.com/stream/)
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
 
-use crate::{CfReqAuth, CfReqMeta};
-use reqwest::Method;
+use crate::{
+    request::{file_part, not_found_error, rfc3339},
+    CfApiErr, CfReqAuth, CfReqMeta, CfSuccessResWithInfo, Error, Result,
+};
+#[cfg(feature = "stream-wait")]
+use crate::{types::Backoff, CloudflareAuth};
+use bytes::Bytes;
+use reqwest::{
+    multipart::{Form, Part},
+    Method, StatusCode,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize)]
@@ -19,8 +31,23 @@ pub struct DirectUpload {
     max_duration_seconds: Option<u64>,
     meta: HashMap<String, String>,
     watermark: Option<Watermark>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiry: Option<String>,
+    #[serde(rename = "requireSignedURLs", skip_serializing_if = "Option::is_none")]
+    require_signed_urls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_origins: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumbnail_timestamp_pct: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_deletion: Option<String>,
 }
 
+/// Minimum time from now at which a [DirectUpload] URL can expire
+pub const DIRECT_UPLOAD_MIN_EXPIRY: Duration = Duration::from_secs(2 * 60);
+/// Maximum time from now at which a [DirectUpload] URL can expire
+pub const DIRECT_UPLOAD_MAX_EXPIRY: Duration = Duration::from_secs(6 * 60 * 60);
+
 /// Success response from the [Direct Upload API](https://developers.cloudflare.com/api/operations/stream-videos-upload-videos-via-direct-upload-ur-ls#Responses)
 #[derive(Serialize, Deserialize)]
 pub struct DirectUploadRes {
@@ -56,6 +83,52 @@ impl DirectUpload {
         self.watermark = Some(Watermark { uid: uid.into() });
         self
     }
+
+    /// Time at which the upload URL stops working
+    /// Must be between [DIRECT_UPLOAD_MIN_EXPIRY] and [DIRECT_UPLOAD_MAX_EXPIRY] from now
+    pub fn expiry(self, at: SystemTime) -> Result<Self> {
+        let expires_in = at.duration_since(SystemTime::now()).unwrap_or_default();
+        self.expiry_in(expires_in)
+    }
+
+    /// Duration from now after which the upload URL stops working
+    /// Must be between [DIRECT_UPLOAD_MIN_EXPIRY] and [DIRECT_UPLOAD_MAX_EXPIRY]
+    pub fn expiry_in(mut self, expires_in: Duration) -> Result<Self> {
+        if !(DIRECT_UPLOAD_MIN_EXPIRY..=DIRECT_UPLOAD_MAX_EXPIRY).contains(&expires_in) {
+            return Err(Error::InvalidRequest(format!(
+                "direct upload expiry must be between {}s and {}s from now, got {}s",
+                DIRECT_UPLOAD_MIN_EXPIRY.as_secs(),
+                DIRECT_UPLOAD_MAX_EXPIRY.as_secs(),
+                expires_in.as_secs()
+            )));
+        }
+        self.expiry = Some(rfc3339(SystemTime::now() + expires_in));
+        Ok(self)
+    }
+
+    /// Require signed URLs for playback
+    pub fn require_signed_urls(mut self, require_signed_urls: bool) -> Self {
+        self.require_signed_urls = Some(require_signed_urls);
+        self
+    }
+
+    /// Origins allowed to embed the video, an empty list allows all origins
+    pub fn allowed_origins(mut self, allowed_origins: Vec<String>) -> Self {
+        self.allowed_origins = Some(allowed_origins);
+        self
+    }
+
+    /// Position of the default thumbnail as a fraction (0.0 - 1.0) of the video's duration
+    pub fn thumbnail_timestamp_pct(mut self, pct: f64) -> Self {
+        self.thumbnail_timestamp_pct = Some(pct);
+        self
+    }
+
+    /// Schedule the video for deletion at the given time
+    pub fn scheduled_deletion(mut self, at: SystemTime) -> Self {
+        self.scheduled_deletion = Some(rfc3339(at));
+        self
+    }
 }
 
 impl CfReqMeta for DirectUpload {
@@ -78,9 +151,24 @@ pub struct CreateDownloads {
     identifier: String,
 }
 
+/// Status of an MP4 download
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Download {
+    /// one of `inprogress`, `ready`, `error`
+    pub status: String,
+    /// URL of the MP4, only usable once the status is `ready`
+    pub url: String,
+    /// Percentage of the download that has been generated
+    pub percent_complete: f64,
+}
+
 /// Success response from the [Create Download link](https://developers.cloudflare.com/api/operations/stream-m-p-4-downloads-create-downloads#Responses) API
 #[derive(Serialize, Deserialize)]
-pub struct CreateDownloadsRes {}
+pub struct CreateDownloadsRes {
+    /// Default MP4 download
+    pub default: Download,
+}
 
 impl CreateDownloads {
     /// Create a download link for the video
@@ -123,20 +211,82 @@ pub struct VideoStatus {
     /// size of the entire upload in bytes
     pub pct_complete: Option<String>,
     /// the processing status for all quality levels for a video
-    /// one of `pendingupload`, `downloading`, `queued`, `inprogress`, `ready`, `error`
-    pub state: String,
+    pub state: VideoState,
 }
 
-/// Success response from the [Retrieve Video Details](https://developers.cloudflare.com/api/operations/stream-videos-retrieve-video-details#Responses) API
-/// Note: This response is not complete, only the status and meta is returned
-#[derive(Serialize, Deserialize)]
-pub struct VideoDetailsRes {
-    /// Video status
-    pub status: VideoStatus,
-    /// Metadata for video is a map. This maybe set differently by different clients.
-    pub meta:  HashMap<String, String>
+/// Processing state of a video
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum VideoState {
+    /// Waiting for the video to be uploaded
+    PendingUpload,
+    /// Cloudflare is downloading the video
+    Downloading,
+    /// Queued for encoding
+    Queued,
+    /// Being encoded
+    Inprogress,
+    /// Ready to stream
+    Ready,
+    /// Encoding failed, see [VideoStatus::error_reason_code]
+    Error,
+    /// A state unknown to this library
+    Other(String),
+}
+
+impl VideoState {
+    /// The state as returned by cloudflare
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::PendingUpload => "pendingupload",
+            Self::Downloading => "downloading",
+            Self::Queued => "queued",
+            Self::Inprogress => "inprogress",
+            Self::Ready => "ready",
+            Self::Error => "error",
+            Self::Other(state) => state,
+        }
+    }
+
+    /// Whether the video is done processing, successfully or not
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Ready | Self::Error)
+    }
+
+    /// Whether the video failed to process
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Error)
+    }
 }
 
+impl From<String> for VideoState {
+    fn from(state: String) -> Self {
+        match state.as_str() {
+            "pendingupload" => Self::PendingUpload,
+            "downloading" => Self::Downloading,
+            "queued" => Self::Queued,
+            "inprogress" => Self::Inprogress,
+            "ready" => Self::Ready,
+            "error" => Self::Error,
+            _ => Self::Other(state),
+        }
+    }
+}
+
+impl From<VideoState> for String {
+    fn from(state: VideoState) -> Self {
+        match state {
+            VideoState::Other(state) => state,
+            state => state.as_str().to_string(),
+        }
+    }
+}
+
+/// Success response from the [Retrieve Video Details](https://developers.cloudflare.com/api/operations/stream-videos-retrieve-video-details#Responses) API
+/// Note: This response is not complete
+pub type VideoDetailsRes = Video;
+
 impl VideoDetails {
     /// Retrieve details of a video
     /// identifier is the video's uid
@@ -160,52 +310,801 @@ impl CfReqAuth for VideoDetails {
     }
 }
 
+#[cfg(feature = "stream-wait")]
+pub use crate::PollOptions;
+
+#[cfg(feature = "stream-wait")]
+impl CloudflareAuth {
+    /// Poll [VideoDetails] until the video is ready to stream
+    /// Fails with [Error::VideoFailed] if encoding fails
+    /// and [Error::Timeout] if the video is not ready after `options.timeout`.
+    /// Polling stops when the returned future is dropped
+    pub async fn wait_for_video_ready(
+        &self,
+        uid: impl Into<String>,
+        options: PollOptions,
+    ) -> Result<VideoDetailsRes> {
+        let uid = uid.into();
+        let mut backoff = Backoff::new(options);
+        loop {
+            let video = self.send_auth(VideoDetails::new(uid.clone())).await?;
+            if video.status.state.is_failed() {
+                return Err(Error::VideoFailed {
+                    uid,
+                    error_reason_code: video.status.error_reason_code,
+                    error_reason_text: video.status.error_reason_text,
+                });
+            }
+            if video.status.state == VideoState::Ready {
+                return Ok(video);
+            }
+            backoff.wait().await?;
+        }
+    }
+}
 
 /// [Edit video details](https://developers.cloudflare.com/api/operations/stream-videos-update-video-details) API
+/// Only the fields that are set are sent, the rest are left unchanged
 #[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct EditVideoDetails {
     #[serde(skip)]
     identifier: String,
-    meta: HashMap<String,String>
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<HashMap<String, String>>,
+    #[serde(rename = "requireSignedURLs", skip_serializing_if = "Option::is_none")]
+    require_signed_urls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_origins: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumbnail_timestamp_pct: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_deletion: Option<String>,
 }
 
-impl EditVideoDetails{
-     /// Edit Video Metadata
+impl EditVideoDetails {
+    /// Edit Video Metadata
     /// identifier is the video's uid
-    pub fn new(identifier: impl Into<String>, meta:   HashMap<String,String> ) -> Self {
+    pub fn new(identifier: impl Into<String>, meta: HashMap<String, String>) -> Self {
         Self {
             identifier: identifier.into(),
-            meta
-        } 
-     }
+            meta: Some(meta),
+            ..Default::default()
+        }
+    }
 
     /// Add metadata to the video
     pub fn add_meta(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
-        self.meta.insert(key.into(), value.into());
+        self.meta
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Require signed URLs for playback
+    pub fn require_signed_urls(mut self, require_signed_urls: bool) -> Self {
+        self.require_signed_urls = Some(require_signed_urls);
+        self
+    }
+
+    /// Origins allowed to embed the video, an empty list allows all origins
+    pub fn allowed_origins(mut self, allowed_origins: Vec<String>) -> Self {
+        self.allowed_origins = Some(allowed_origins);
+        self
+    }
+
+    /// Creator of the video
+    pub fn creator(mut self, creator: impl Into<String>) -> Self {
+        self.creator = Some(creator.into());
+        self
+    }
+
+    /// Position of the default thumbnail as a fraction (0.0 - 1.0) of the video's duration
+    pub fn thumbnail_timestamp_pct(mut self, pct: f64) -> Self {
+        self.thumbnail_timestamp_pct = Some(pct);
         self
     }
 
+    /// Schedule the video for deletion at the given time
+    pub fn scheduled_deletion(mut self, at: SystemTime) -> Self {
+        self.scheduled_deletion = Some(rfc3339(at));
+        self
+    }
 }
+
 /// Success response from the [Edit video details](https://developers.cloudflare.com/api/operations/stream-videos-update-video-details) API
-/// Note: This response is not complete
+pub type EditVideoDetailsRes = VideoDetailsRes;
+
+impl CfReqMeta for EditVideoDetails {
+    const METHOD: Method = Method::POST;
+    type Response = EditVideoDetailsRes;
+}
+
+impl CfReqAuth for EditVideoDetails {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/{}", self.identifier)
+    }
+}
+
+/// [List videos](https://developers.cloudflare.com/api/operations/stream-videos-list-videos) API
+/// Videos are returned newest first by default, to fetch the next page
+/// pass the `created` timestamp of the last video to [Self::end] (or [Self::start] when using [Self::asc])
+#[derive(Serialize, Default)]
+pub struct ListVideos {
+    search: Option<String>,
+    creator: Option<String>,
+    status: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    asc: Option<bool>,
+    limit: Option<u32>,
+}
+
+impl ListVideos {
+    /// Only return videos whose name contains the given string
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+
+    /// Only return videos by the given creator
+    pub fn creator(mut self, creator: impl Into<String>) -> Self {
+        self.creator = Some(creator.into());
+        self
+    }
+
+    /// Only return videos in the given processing state (see [VideoState::as_str])
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// Only return videos created at or after the given RFC3339 timestamp
+    pub fn start(mut self, start: impl Into<String>) -> Self {
+        self.start = Some(start.into());
+        self
+    }
+
+    /// Only return videos created before the given RFC3339 timestamp
+    pub fn end(mut self, end: impl Into<String>) -> Self {
+        self.end = Some(end.into());
+        self
+    }
+
+    /// Return videos in ascending order of creation
+    pub fn asc(mut self, asc: bool) -> Self {
+        self.asc = Some(asc);
+        self
+    }
+
+    /// Maximum number of videos to return
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// Playback URLs of a video
+#[derive(Serialize, Deserialize)]
+pub struct Playback {
+    /// HLS manifest URL
+    pub hls: Option<String>,
+    /// DASH manifest URL
+    pub dash: Option<String>,
+}
+
+/// A Stream video
+/// Most fields are optional as they are not available until the video is processed
 #[derive(Serialize, Deserialize)]
-pub struct EditVideoDetailsRes {
+#[serde(rename_all = "camelCase")]
+pub struct Video {
+    /// Unique identifier for the video
+    pub uid: String,
     /// Video status
     pub status: VideoStatus,
+    /// RFC3339 timestamp of when the video was created
+    pub created: Option<String>,
+    /// RFC3339 timestamp of when the video was last modified
+    pub modified: Option<String>,
+    /// Duration of the video in seconds, -1 if unknown
+    pub duration: Option<f64>,
+    /// Size of the video in bytes
+    pub size: Option<u64>,
+    /// Thumbnail URL
+    pub thumbnail: Option<String>,
+    /// Playback URLs
+    pub playback: Option<Playback>,
+    /// Whether the video can be played back
+    pub ready_to_stream: Option<bool>,
+    /// Whether signed URLs are required for playback
+    #[serde(rename = "requireSignedURLs")]
+    pub require_signed_urls: Option<bool>,
+    /// uid of the live input the video was recorded from
+    pub live_input: Option<String>,
+    /// RFC3339 timestamp of when the video is scheduled to be deleted
+    pub scheduled_deletion: Option<String>,
     /// Metadata for video is a map. This maybe set differently by different clients.
-    pub meta:  HashMap<String, String>
+    #[serde(default)]
+    pub meta: HashMap<String, String>,
 }
 
+#[derive(Deserialize, Default)]
+struct VideoRange {
+    range: Option<u64>,
+    total: Option<u64>,
+}
 
-impl CfReqMeta for EditVideoDetails {
-    const METHOD: Method = Method::POST;
-    type Response = EditVideoDetailsRes;
+/// Success response from the [List videos](https://developers.cloudflare.com/api/operations/stream-videos-list-videos#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListVideosRes {
+    /// Videos in this page
+    pub videos: Vec<Video>,
+    /// Number of videos in this page
+    pub range: Option<u64>,
+    /// Total number of videos matching the filters
+    pub total: Option<u64>,
 }
 
-impl CfReqAuth for EditVideoDetails {
+impl CfReqMeta for ListVideos {
+    const METHOD: Method = Method::GET;
+    type Response = ListVideosRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (videos, info): (_, VideoRange) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListVideosRes {
+            videos,
+            range: info.range,
+            total: info.total,
+        })
+    }
+}
+
+impl CfReqAuth for ListVideos {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream")
+    }
+}
+
+/// [Delete video](https://developers.cloudflare.com/api/operations/stream-videos-delete-video) API
+/// A missing video results in an error for which [crate::Error::is_not_found] is true
+#[derive(Serialize)]
+pub struct DeleteVideo {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl DeleteVideo {
+    /// Delete a video
+    /// identifier is the video's uid
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete video](https://developers.cloudflare.com/api/operations/stream-videos-delete-video#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteVideoRes;
+
+impl CfReqMeta for DeleteVideo {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteVideoRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // cloudflare responds with an empty body
+        Ok(DeleteVideoRes)
+    }
+
+    fn handle_error(status: StatusCode, errors: Vec<CfApiErr>) -> Result<Self::Response> {
+        not_found_error(status, errors)
+    }
+}
+
+impl CfReqAuth for DeleteVideo {
     type Url = String;
 
     fn path(&self, account_id: &str) -> String {
         format!("accounts/{account_id}/stream/{}", self.identifier)
     }
 }
+
+/// [Upload videos from a URL](https://developers.cloudflare.com/api/operations/stream-videos-upload-videos-from-a-url) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadFromUrl {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    creator: Option<String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    meta: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    watermark: Option<Watermark>,
+    #[serde(rename = "requireSignedURLs", skip_serializing_if = "Option::is_none")]
+    require_signed_urls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_origins: Option<Vec<String>>,
+}
+
+impl UploadFromUrl {
+    /// Upload the video available at the given URL
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            creator: None,
+            meta: HashMap::new(),
+            watermark: None,
+            require_signed_urls: None,
+            allowed_origins: None,
+        }
+    }
+
+    /// Creator of the video
+    pub fn creator(mut self, creator: impl Into<String>) -> Self {
+        self.creator = Some(creator.into());
+        self
+    }
+
+    /// Add metadata to the video
+    pub fn add_meta(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.meta.insert(key.into(), value.into());
+        self
+    }
+
+    /// Add a watermark by UID to the video
+    /// see [Cloudflare docs](https://developers.cloudflare.com/stream/edit-videos/applying-watermarks/)
+    pub fn watermark(mut self, uid: impl Into<String>) -> Self {
+        self.watermark = Some(Watermark { uid: uid.into() });
+        self
+    }
+
+    /// Require signed URLs for playback
+    pub fn require_signed_urls(mut self, require_signed_urls: bool) -> Self {
+        self.require_signed_urls = Some(require_signed_urls);
+        self
+    }
+
+    /// Origins allowed to embed the video, an empty list allows all origins
+    pub fn allowed_origins(mut self, allowed_origins: Vec<String>) -> Self {
+        self.allowed_origins = Some(allowed_origins);
+        self
+    }
+}
+
+/// Success response from the [Upload videos from a URL](https://developers.cloudflare.com/api/operations/stream-videos-upload-videos-from-a-url#Responses) API
+pub type UploadFromUrlRes = Video;
+
+impl CfReqMeta for UploadFromUrl {
+    const METHOD: Method = Method::POST;
+    type Response = UploadFromUrlRes;
+}
+
+impl CfReqAuth for UploadFromUrl {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/copy")
+    }
+}
+
+/// [List Downloads](https://developers.cloudflare.com/api/operations/stream-m-p-4-downloads-list-downloads) API
+/// Useful for polling until the download created by [CreateDownloads] is ready
+#[derive(Serialize)]
+pub struct GetDownloads {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl GetDownloads {
+    /// Get the download links for the video
+    /// identifier is the video's uid
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [List Downloads](https://developers.cloudflare.com/api/operations/stream-m-p-4-downloads-list-downloads#Responses) API
+pub type GetDownloadsRes = CreateDownloadsRes;
+
+impl CfReqMeta for GetDownloads {
+    const METHOD: Method = Method::GET;
+    type Response = GetDownloadsRes;
+}
+
+impl CfReqAuth for GetDownloads {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/{}/downloads", self.identifier)
+    }
+}
+
+/// [Delete Downloads](https://developers.cloudflare.com/api/operations/stream-m-p-4-downloads-delete-downloads) API
+#[derive(Serialize)]
+pub struct DeleteDownloads {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl DeleteDownloads {
+    /// Delete the download links for the video
+    /// identifier is the video's uid
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Downloads](https://developers.cloudflare.com/api/operations/stream-m-p-4-downloads-delete-downloads#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteDownloadsRes;
+
+impl CfReqMeta for DeleteDownloads {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteDownloadsRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty string
+        Ok(DeleteDownloadsRes)
+    }
+}
+
+impl CfReqAuth for DeleteDownloads {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/{}/downloads", self.identifier)
+    }
+}
+
+/// [Clip videos given a start and end time](https://developers.cloudflare.com/api/operations/stream-video-clipping-clip-videos-given-a-start-and-end-time) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipVideo {
+    #[serde(rename = "clippedFromVideoUID")]
+    clipped_from_video_uid: String,
+    start_time_seconds: u64,
+    end_time_seconds: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    watermark: Option<Watermark>,
+    #[serde(rename = "requireSignedURLs", skip_serializing_if = "Option::is_none")]
+    require_signed_urls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_origins: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_duration_seconds: Option<u64>,
+}
+
+impl ClipVideo {
+    /// Clip the video between `start` and `end` (offsets from the beginning of the video)
+    /// identifier is the source video's uid
+    pub fn new(identifier: impl Into<String>, start: Duration, end: Duration) -> Result<Self> {
+        let (start, end) = (start.as_secs(), end.as_secs());
+        if end <= start {
+            return Err(Error::InvalidRequest(format!(
+                "clip end ({end}s) must be after its start ({start}s)"
+            )));
+        }
+        Ok(Self {
+            clipped_from_video_uid: identifier.into(),
+            start_time_seconds: start,
+            end_time_seconds: end,
+            creator: None,
+            watermark: None,
+            require_signed_urls: None,
+            allowed_origins: None,
+            max_duration_seconds: None,
+        })
+    }
+
+    /// Creator of the clip
+    pub fn creator(mut self, creator: impl Into<String>) -> Self {
+        self.creator = Some(creator.into());
+        self
+    }
+
+    /// Add a watermark by UID to the clip
+    /// see [Cloudflare docs](https://developers.cloudflare.com/stream/edit-videos/applying-watermarks/)
+    pub fn watermark(mut self, uid: impl Into<String>) -> Self {
+        self.watermark = Some(Watermark { uid: uid.into() });
+        self
+    }
+
+    /// Require signed URLs for playback
+    pub fn require_signed_urls(mut self, require_signed_urls: bool) -> Self {
+        self.require_signed_urls = Some(require_signed_urls);
+        self
+    }
+
+    /// Origins allowed to embed the clip, an empty list allows all origins
+    pub fn allowed_origins(mut self, allowed_origins: Vec<String>) -> Self {
+        self.allowed_origins = Some(allowed_origins);
+        self
+    }
+
+    /// Maximum duration of the clip
+    pub fn max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration_seconds = Some(max_duration.as_secs());
+        self
+    }
+}
+
+/// Success response from the [Clip videos given a start and end time](https://developers.cloudflare.com/api/operations/stream-video-clipping-clip-videos-given-a-start-and-end-time#Responses) API
+pub type ClipVideoRes = Video;
+
+impl CfReqMeta for ClipVideo {
+    const METHOD: Method = Method::POST;
+    type Response = ClipVideoRes;
+}
+
+impl CfReqAuth for ClipVideo {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/clip")
+    }
+}
+
+/// [Storage use](https://developers.cloudflare.com/api/operations/stream-videos-storage-usage) API
+#[derive(Serialize, Default)]
+pub struct StreamStorageUsage {
+    creator: Option<String>,
+}
+
+impl StreamStorageUsage {
+    /// Only count videos by the given creator
+    pub fn creator(mut self, creator: impl Into<String>) -> Self {
+        self.creator = Some(creator.into());
+        self
+    }
+}
+
+/// Success response from the [Storage use](https://developers.cloudflare.com/api/operations/stream-videos-storage-usage#Responses) API
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamStorageUsageRes {
+    /// Creator the usage was filtered by
+    pub creator: Option<String>,
+    /// Total minutes of video stored
+    pub total_storage_minutes: u64,
+    /// Storage quota in minutes
+    pub total_storage_minutes_limit: u64,
+    /// Number of videos stored
+    pub video_count: u64,
+}
+
+impl CfReqMeta for StreamStorageUsage {
+    const METHOD: Method = Method::GET;
+    type Response = StreamStorageUsageRes;
+}
+
+impl CfReqAuth for StreamStorageUsage {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/storage-usage")
+    }
+}
+
+/// [Basic video upload](https://developers.cloudflare.com/stream/uploading-videos/upload-video-file/#basic-uploads) API
+/// For files under 200MB, sent via [crate::CloudflareAuth::send_auth_multipart]
+pub struct UploadVideo {
+    file: Part,
+    creator: Option<String>,
+    meta: HashMap<String, String>,
+    require_signed_urls: Option<bool>,
+    thumbnail_timestamp_pct: Option<f64>,
+    scheduled_deletion: Option<String>,
+}
+
+impl UploadVideo {
+    fn with_part(file: Part) -> Self {
+        Self {
+            file,
+            creator: None,
+            meta: HashMap::new(),
+            require_signed_urls: None,
+            thumbnail_timestamp_pct: None,
+            scheduled_deletion: None,
+        }
+    }
+
+    /// Upload an in-memory video
+    /// the mime type is guessed from `file_name`
+    pub fn from_bytes(file: impl Into<Bytes>, file_name: impl Into<String>) -> Result<Self> {
+        Ok(Self::with_part(file_part(file.into(), file_name.into())?))
+    }
+
+    /// Upload a video file, streaming it from disk
+    /// the mime type is guessed from `file_name`
+    #[cfg(feature = "stream")]
+    pub async fn from_file(file: tokio::fs::File, file_name: impl Into<String>) -> Result<Self> {
+        let file_name = file_name.into();
+        let len = file.metadata().await?.len();
+        let mime = mime_guess::from_path(&file_name).first_or_octet_stream();
+        let part = Part::stream_with_length(file, len)
+            .file_name(file_name)
+            .mime_str(mime.as_ref())?;
+        Ok(Self::with_part(part))
+    }
+
+    /// Creator of the video
+    pub fn creator(mut self, creator: impl Into<String>) -> Self {
+        self.creator = Some(creator.into());
+        self
+    }
+
+    /// Add metadata to the video
+    pub fn add_meta(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.meta.insert(key.into(), value.into());
+        self
+    }
+
+    /// Require signed URLs for playback
+    pub fn require_signed_urls(mut self, require_signed_urls: bool) -> Self {
+        self.require_signed_urls = Some(require_signed_urls);
+        self
+    }
+
+    /// Position of the default thumbnail as a fraction (0.0 - 1.0) of the video's duration
+    pub fn thumbnail_timestamp_pct(mut self, pct: f64) -> Self {
+        self.thumbnail_timestamp_pct = Some(pct);
+        self
+    }
+
+    /// Schedule the video for deletion at the given time
+    pub fn scheduled_deletion(mut self, at: SystemTime) -> Self {
+        self.scheduled_deletion = Some(rfc3339(at));
+        self
+    }
+}
+
+/// Success response from the basic upload API
+pub type UploadVideoRes = Video;
+
+impl CfReqMeta for UploadVideo {
+    const METHOD: Method = Method::POST;
+    type Response = UploadVideoRes;
+}
+
+impl CfReqAuth for UploadVideo {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream")
+    }
+}
+
+impl TryFrom<UploadVideo> for Form {
+    type Error = Error;
+
+    fn try_from(value: UploadVideo) -> Result<Self> {
+        let mut form = Form::new().part("file", value.file);
+        if let Some(creator) = value.creator {
+            form = form.text("creator", creator);
+        }
+        if !value.meta.is_empty() {
+            form = form.text("meta", serde_json::to_string(&value.meta)?);
+        }
+        if let Some(require_signed_urls) = value.require_signed_urls {
+            form = form.text("requireSignedURLs", require_signed_urls.to_string());
+        }
+        if let Some(pct) = value.thumbnail_timestamp_pct {
+            form = form.text("thumbnailTimestampPct", pct.to_string());
+        }
+        if let Some(scheduled_deletion) = value.scheduled_deletion {
+            form = form.text("scheduledDeletion", scheduled_deletion);
+        }
+        Ok(form)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_ready_video() {
+        let body = br#"{
+            "result": {
+                "uid": "ea95132c15732412d22c1476fa83f27a",
+                "creator": null,
+                "thumbnail": "https://customer-f33zs165nr7gyfy4.cloudflarestream.com/ea95132c15732412d22c1476fa83f27a/thumbnails/thumbnail.jpg",
+                "thumbnailTimestampPct": 0,
+                "readyToStream": true,
+                "readyToStreamAt": "2024-05-02T13:42:16.513597Z",
+                "status": {
+                    "state": "ready",
+                    "pctComplete": "100.000000",
+                    "errorReasonCode": "",
+                    "errorReasonText": ""
+                },
+                "meta": {
+                    "name": "big_buck_bunny.mp4"
+                },
+                "created": "2024-05-02T13:42:03.503468Z",
+                "modified": "2024-05-02T13:42:16.513603Z",
+                "scheduledDeletion": null,
+                "size": 5510872,
+                "preview": "https://customer-f33zs165nr7gyfy4.cloudflarestream.com/ea95132c15732412d22c1476fa83f27a/watch",
+                "allowedOrigins": [],
+                "requireSignedURLs": false,
+                "uploaded": "2024-05-02T13:42:03.503466Z",
+                "uploadExpiry": null,
+                "maxSizeBytes": null,
+                "maxDurationSeconds": null,
+                "duration": 10.5,
+                "input": {
+                    "width": 1920,
+                    "height": 1080
+                },
+                "playback": {
+                    "hls": "https://customer-f33zs165nr7gyfy4.cloudflarestream.com/ea95132c15732412d22c1476fa83f27a/manifest/video.m3u8",
+                    "dash": "https://customer-f33zs165nr7gyfy4.cloudflarestream.com/ea95132c15732412d22c1476fa83f27a/manifest/video.mpd"
+                },
+                "watermark": null,
+                "liveInput": null,
+                "clippedFrom": null,
+                "publicDetails": null
+            },
+            "success": true,
+            "errors": [],
+            "messages": []
+        }"#;
+        let video = VideoDetails::deserialize_response(Bytes::from_static(body)).unwrap();
+        assert_eq!(video.uid, "ea95132c15732412d22c1476fa83f27a");
+        assert_eq!(video.status.state, VideoState::Ready);
+        assert_eq!(video.status.pct_complete.as_deref(), Some("100.000000"));
+        assert_eq!(video.ready_to_stream, Some(true));
+        assert_eq!(video.require_signed_urls, Some(false));
+        assert_eq!(video.duration, Some(10.5));
+        assert_eq!(video.size, Some(5510872));
+        assert_eq!(video.meta["name"], "big_buck_bunny.mp4");
+        let playback = video.playback.unwrap();
+        assert!(playback.hls.unwrap().ends_with("/manifest/video.m3u8"));
+        assert!(playback.dash.unwrap().ends_with("/manifest/video.mpd"));
+    }
+
+    #[test]
+    fn deserialize_pending_video() {
+        let body = br#"{
+            "result": {
+                "uid": "0f87ab6a8b9c4d5e6f708192a3b4c5d6",
+                "thumbnail": "https://customer-f33zs165nr7gyfy4.cloudflarestream.com/0f87ab6a8b9c4d5e6f708192a3b4c5d6/thumbnails/thumbnail.jpg",
+                "readyToStream": false,
+                "status": {
+                    "state": "pendingupload"
+                },
+                "meta": {},
+                "created": "2024-05-02T13:40:00.000000Z",
+                "modified": "2024-05-02T13:40:00.000000Z",
+                "requireSignedURLs": true,
+                "duration": -1,
+                "scheduledDeletion": null
+            },
+            "success": true,
+            "errors": [],
+            "messages": []
+        }"#;
+        let video = VideoDetails::deserialize_response(Bytes::from_static(body)).unwrap();
+        assert_eq!(video.status.state, VideoState::PendingUpload);
+        assert!(video.status.pct_complete.is_none());
+        assert_eq!(video.ready_to_stream, Some(false));
+        assert_eq!(video.duration, Some(-1.0));
+        assert!(video.size.is_none());
+        assert!(video.playback.is_none());
+        assert!(video.live_input.is_none());
+        assert!(video.meta.is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+//! Cloudflare Stream Watermark profile APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/stream/edit-videos/applying-watermarks/)
+use std::path::Path;
+
+use crate::{request::file_part, CfReqAuth, CfReqMeta, Error, Result};
+use bytes::Bytes;
+use reqwest::{multipart::Form, Method};
+use serde::{Deserialize, Serialize};
+
+/// Position of the watermark on the video
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WatermarkPosition {
+    /// Top right corner
+    UpperRight,
+    /// Top left corner
+    UpperLeft,
+    /// Bottom left corner
+    LowerLeft,
+    /// Bottom right corner
+    LowerRight,
+    /// Center of the video
+    Center,
+}
+
+impl WatermarkPosition {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::UpperRight => "upperRight",
+            Self::UpperLeft => "upperLeft",
+            Self::LowerLeft => "lowerLeft",
+            Self::LowerRight => "lowerRight",
+            Self::Center => "center",
+        }
+    }
+}
+
+/// A watermark profile
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Watermark {
+    /// Unique identifier for the watermark profile
+    pub uid: String,
+    /// Name of the watermark profile
+    pub name: Option<String>,
+    /// Size of the image in bytes
+    pub size: Option<u64>,
+    /// Height of the image in pixels
+    pub height: Option<u32>,
+    /// Width of the image in pixels
+    pub width: Option<u32>,
+    /// RFC3339 timestamp of when the profile was created
+    pub created: Option<String>,
+    /// URL the image was downloaded from, if any
+    pub downloaded_from: Option<String>,
+    /// Opacity of the watermark (0.0 - 1.0)
+    pub opacity: Option<f64>,
+    /// Padding between the watermark and the video's edges as a fraction of the video's size
+    pub padding: Option<f64>,
+    /// Size of the watermark as a fraction of the video's width
+    pub scale: Option<f64>,
+    /// Position of the watermark
+    pub position: Option<WatermarkPosition>,
+}
+
+/// [Create watermark profiles via basic upload](https://developers.cloudflare.com/api/operations/stream-watermark-profile-create-watermark-profiles-via-basic-upload) API
+/// Sent via [crate::CloudflareAuth::send_auth_multipart]
+pub struct CreateWatermark {
+    file: Bytes,
+    file_name: String,
+    name: Option<String>,
+    opacity: Option<f64>,
+    padding: Option<f64>,
+    scale: Option<f64>,
+    position: Option<WatermarkPosition>,
+}
+
+impl CreateWatermark {
+    /// Create a watermark profile from an in-memory image
+    /// the mime type is guessed from `file_name`
+    pub fn from_bytes(file: impl Into<Bytes>, file_name: impl Into<String>) -> Self {
+        Self {
+            file: file.into(),
+            file_name: file_name.into(),
+            name: None,
+            opacity: None,
+            padding: None,
+            scale: None,
+            position: None,
+        }
+    }
+
+    /// Create a watermark profile from an image file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::read(path)?;
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| Error::InvalidRequest(format!("invalid file name: {path:?}")))?;
+        Ok(Self::from_bytes(file, file_name))
+    }
+
+    /// Name of the watermark profile
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Opacity of the watermark (0.0 - 1.0)
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Padding between the watermark and the video's edges as a fraction of the video's size
+    pub fn padding(mut self, padding: f64) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Size of the watermark as a fraction of the video's width
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Position of the watermark
+    pub fn position(mut self, position: WatermarkPosition) -> Self {
+        self.position = Some(position);
+        self
+    }
+}
+
+/// Success response from the [Create watermark profiles via basic upload](https://developers.cloudflare.com/api/operations/stream-watermark-profile-create-watermark-profiles-via-basic-upload#Responses) API
+pub type CreateWatermarkRes = Watermark;
+
+impl CfReqMeta for CreateWatermark {
+    const METHOD: Method = Method::POST;
+    type Response = CreateWatermarkRes;
+}
+
+impl CfReqAuth for CreateWatermark {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/watermarks")
+    }
+}
+
+impl TryFrom<CreateWatermark> for Form {
+    type Error = Error;
+
+    fn try_from(value: CreateWatermark) -> Result<Self> {
+        let mut form = Form::new().part("file", file_part(value.file, value.file_name)?);
+        if let Some(name) = value.name {
+            form = form.text("name", name);
+        }
+        if let Some(opacity) = value.opacity {
+            form = form.text("opacity", opacity.to_string());
+        }
+        if let Some(padding) = value.padding {
+            form = form.text("padding", padding.to_string());
+        }
+        if let Some(scale) = value.scale {
+            form = form.text("scale", scale.to_string());
+        }
+        if let Some(position) = value.position {
+            form = form.text("position", position.as_str());
+        }
+        Ok(form)
+    }
+}
+
+/// [List watermark profiles](https://developers.cloudflare.com/api/operations/stream-watermark-profile-list-watermark-profiles) API
+#[derive(Serialize, Default)]
+pub struct ListWatermarks {}
+
+/// Success response from the [List watermark profiles](https://developers.cloudflare.com/api/operations/stream-watermark-profile-list-watermark-profiles#Responses) API
+pub type ListWatermarksRes = Vec<Watermark>;
+
+impl CfReqMeta for ListWatermarks {
+    const METHOD: Method = Method::GET;
+    type Response = ListWatermarksRes;
+}
+
+impl CfReqAuth for ListWatermarks {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/watermarks")
+    }
+}
+
+/// [Watermark profile details](https://developers.cloudflare.com/api/operations/stream-watermark-profile-watermark-profile-details) API
+#[derive(Serialize)]
+pub struct GetWatermark {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl GetWatermark {
+    /// Retrieve details of a watermark profile
+    /// identifier is the profile's uid
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Watermark profile details](https://developers.cloudflare.com/api/operations/stream-watermark-profile-watermark-profile-details#Responses) API
+pub type GetWatermarkRes = Watermark;
+
+impl CfReqMeta for GetWatermark {
+    const METHOD: Method = Method::GET;
+    type Response = GetWatermarkRes;
+}
+
+impl CfReqAuth for GetWatermark {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/watermarks/{}",
+            self.identifier
+        )
+    }
+}
+
+/// [Delete watermark profiles](https://developers.cloudflare.com/api/operations/stream-watermark-profile-delete-watermark-profiles) API
+#[derive(Serialize)]
+pub struct DeleteWatermark {
+    #[serde(skip)]
+    identifier: String,
+}
+
+impl DeleteWatermark {
+    /// Delete a watermark profile
+    /// identifier is the profile's uid
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete watermark profiles](https://developers.cloudflare.com/api/operations/stream-watermark-profile-delete-watermark-profiles#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteWatermarkRes;
+
+impl CfReqMeta for DeleteWatermark {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteWatermarkRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty string
+        Ok(DeleteWatermarkRes)
+    }
+}
+
+impl CfReqAuth for DeleteWatermark {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/stream/watermarks/{}",
+            self.identifier
+        )
+    }
+}
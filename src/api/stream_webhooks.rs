@@ -0,0 +1,231 @@
+//! Cloudflare Stream webhook APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/stream/manage-video-library/using-webhooks/)
+use crate::{CfReqAuth, CfReqMeta, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Webhook configuration of an account
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamWebhook {
+    /// URL notified when a video is ready to stream or fails to encode
+    pub notification_url: String,
+    /// RFC3339 timestamp of when the webhook was last modified
+    pub modified: Option<String>,
+    /// Secret used to sign webhook requests
+    #[cfg_attr(feature = "stream-webhooks", doc = "see [verify_webhook_signature]")]
+    pub secret: Option<String>,
+}
+
+/// [Create webhooks](https://developers.cloudflare.com/api/operations/stream-webhook-create-webhooks) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateStreamWebhook {
+    notification_url: String,
+}
+
+impl CreateStreamWebhook {
+    /// Set the URL notified by cloudflare, replacing any existing webhook
+    pub fn new(notification_url: impl Into<String>) -> Self {
+        Self {
+            notification_url: notification_url.into(),
+        }
+    }
+}
+
+/// Success response from the [Create webhooks](https://developers.cloudflare.com/api/operations/stream-webhook-create-webhooks#Responses) API
+pub type CreateStreamWebhookRes = StreamWebhook;
+
+impl CfReqMeta for CreateStreamWebhook {
+    const METHOD: Method = Method::PUT;
+    type Response = CreateStreamWebhookRes;
+}
+
+impl CfReqAuth for CreateStreamWebhook {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/webhook")
+    }
+}
+
+/// [View webhooks](https://developers.cloudflare.com/api/operations/stream-webhook-view-webhooks) API
+#[derive(Serialize, Default)]
+pub struct GetStreamWebhook {}
+
+/// Success response from the [View webhooks](https://developers.cloudflare.com/api/operations/stream-webhook-view-webhooks#Responses) API
+pub type GetStreamWebhookRes = StreamWebhook;
+
+impl CfReqMeta for GetStreamWebhook {
+    const METHOD: Method = Method::GET;
+    type Response = GetStreamWebhookRes;
+}
+
+impl CfReqAuth for GetStreamWebhook {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/webhook")
+    }
+}
+
+/// [Delete webhooks](https://developers.cloudflare.com/api/operations/stream-webhook-delete-webhooks) API
+#[derive(Serialize, Default)]
+pub struct DeleteStreamWebhook {}
+
+/// Success response from the [Delete webhooks](https://developers.cloudflare.com/api/operations/stream-webhook-delete-webhooks#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteStreamWebhookRes;
+
+impl CfReqMeta for DeleteStreamWebhook {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteStreamWebhookRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is an empty string
+        Ok(DeleteStreamWebhookRes)
+    }
+}
+
+impl CfReqAuth for DeleteStreamWebhook {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/stream/webhook")
+    }
+}
+
+/// Maximum age of a webhook request accepted by [verify_webhook_signature]
+#[cfg(feature = "stream-webhooks")]
+pub const WEBHOOK_TOLERANCE: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
+#[cfg(feature = "stream-webhooks")]
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Verify the `Webhook-Signature` header of a webhook request sent by cloudflare
+/// `secret` is the secret from [StreamWebhook], `body` is the raw request body.
+/// Requests older than [WEBHOOK_TOLERANCE] are rejected
+#[cfg(feature = "stream-webhooks")]
+pub fn verify_webhook_signature(secret: &str, body: &[u8], webhook_signature: &str) -> Result<()> {
+    use crate::Error;
+    use hmac::{Hmac, Mac};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let mut time = None;
+    let mut sig = None;
+    for part in webhook_signature.split(',') {
+        match part.trim().split_once('=') {
+            Some(("time", value)) => time = Some(value),
+            Some(("sig1", value)) => sig = Some(value),
+            _ => {}
+        }
+    }
+    let time = time.ok_or(Error::WebhookSignature("missing time"))?;
+    let sig = sig.ok_or(Error::WebhookSignature("missing sig1"))?;
+    let sig = decode_hex(sig).ok_or(Error::WebhookSignature("sig1 is not hex"))?;
+
+    let sent_at = time
+        .parse()
+        .ok()
+        .and_then(|secs| UNIX_EPOCH.checked_add(Duration::from_secs(secs)))
+        .ok_or(Error::WebhookSignature("time is not a unix timestamp"))?;
+    let age = SystemTime::now()
+        .duration_since(sent_at)
+        .unwrap_or_else(|e| e.duration());
+    if age > WEBHOOK_TOLERANCE {
+        return Err(Error::WebhookSignature("outside of tolerance window"));
+    }
+
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes())
+        .map_err(|_| Error::WebhookSignature("invalid secret"))?;
+    mac.update(time.as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac.verify_slice(&sig)
+        .map_err(|_| Error::WebhookSignature("signature mismatch"))
+}
+
+#[cfg(all(test, feature = "stream-webhooks"))]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use hmac::{Hmac, Mac};
+
+    use super::*;
+    use crate::Error;
+
+    const SECRET: &str = "85011ed3a913c6ad5f9cf6c5573cc0a7";
+    const BODY: &[u8] = br#"{"uid":"dd5d531a12de0c724bd1275a3b2bc9c6","readyToStream":true}"#;
+
+    fn header(time: u64, body: &[u8]) -> String {
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+        mac.update(format!("{time}.").as_bytes());
+        mac.update(body);
+        let sig: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        format!("time={time},sig1={sig}")
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    fn reason(res: Result<()>) -> &'static str {
+        match res {
+            Err(Error::WebhookSignature(reason)) => reason,
+            res => panic!("unexpected result {res:?}"),
+        }
+    }
+
+    #[test]
+    fn valid_signature() {
+        verify_webhook_signature(SECRET, BODY, &header(now(), BODY)).unwrap();
+    }
+
+    #[test]
+    fn signature_mismatch() {
+        let header = header(now(), b"{}");
+        assert_eq!(
+            reason(verify_webhook_signature(SECRET, BODY, &header)),
+            "signature mismatch"
+        );
+    }
+
+    #[test]
+    fn expired_timestamp() {
+        let sent_at = now() - WEBHOOK_TOLERANCE.as_secs() - 60;
+        assert_eq!(
+            reason(verify_webhook_signature(
+                SECRET,
+                BODY,
+                &header(sent_at, BODY)
+            )),
+            "outside of tolerance window"
+        );
+    }
+
+    #[test]
+    fn overflowing_timestamp() {
+        let header = format!("time={},sig1=00", u64::MAX);
+        assert_eq!(
+            reason(verify_webhook_signature(SECRET, BODY, &header)),
+            "time is not a unix timestamp"
+        );
+    }
+}
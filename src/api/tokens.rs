@@ -0,0 +1,540 @@
+//! Cloudflare API Tokens related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/fundamentals/api/get-started/create-token/)
+//! Tokens belong to the user, the client's account is not used
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    request::rfc3339, CfReqAuth, CfReqMeta, CfSuccessResWithInfo, CloudflareAuth, PageInfo, Result,
+    SecretString,
+};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Status of an API token
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenStatus {
+    /// Token can be used
+    Active,
+    /// Token was disabled
+    Disabled,
+    /// Token expired
+    Expired,
+    /// A status unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+impl TokenStatus {
+    /// Whether the token can be used
+    pub fn is_active(self) -> bool {
+        self == Self::Active
+    }
+}
+
+/// [Verify Token](https://developers.cloudflare.com/api/operations/user-api-tokens-verify-token) API
+/// Verifies the client's token
+#[derive(Serialize, Default)]
+pub struct VerifyToken {}
+
+/// Success response from the [Verify Token](https://developers.cloudflare.com/api/operations/user-api-tokens-verify-token#Responses) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VerifyTokenRes {
+    /// Token ID
+    pub id: String,
+    /// Status of the token
+    pub status: TokenStatus,
+    /// Time before which the token can't be used
+    pub not_before: Option<String>,
+    /// Expiry time of the token
+    pub expires_on: Option<String>,
+}
+
+impl CfReqMeta for VerifyToken {
+    const METHOD: Method = Method::GET;
+    type Response = VerifyTokenRes;
+}
+
+impl CfReqAuth for VerifyToken {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "user/tokens/verify".to_string()
+    }
+}
+
+impl CloudflareAuth {
+    /// Check the client's token with [VerifyToken]
+    /// Invalid or deleted tokens result in an error, check [TokenStatus::is_active] for the rest
+    pub async fn verify_credentials(&self) -> Result<TokenStatus> {
+        let res = self.send_auth(VerifyToken::default()).await?;
+        Ok(res.status)
+    }
+}
+
+/// A permission that can be granted by a [TokenPolicy]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PermissionGroup {
+    /// Permission group ID
+    pub id: String,
+    /// Name of the permission group (e.g `Workers Scripts Write`)
+    #[serde(default, skip_serializing)]
+    pub name: String,
+    /// Scopes the permission group applies to (e.g `com.cloudflare.api.account`)
+    #[serde(default, skip_serializing)]
+    pub scopes: Vec<String>,
+}
+
+/// Permission groups can be given wherever a permission group ID is expected
+impl AsRef<str> for PermissionGroup {
+    fn as_ref(&self) -> &str {
+        &self.id
+    }
+}
+
+/// [List Token Permission Groups](https://developers.cloudflare.com/api/operations/permission-groups-list-permission-groups) API
+#[derive(Serialize, Default)]
+pub struct ListPermissionGroups {}
+
+/// Success response from the [List Token Permission Groups](https://developers.cloudflare.com/api/operations/permission-groups-list-permission-groups#Responses) API
+pub type ListPermissionGroupsRes = Vec<PermissionGroup>;
+
+impl CfReqMeta for ListPermissionGroups {
+    const METHOD: Method = Method::GET;
+    type Response = ListPermissionGroupsRes;
+}
+
+impl CfReqAuth for ListPermissionGroups {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "user/tokens/permission_groups".to_string()
+    }
+}
+
+/// Effect of a [TokenPolicy]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PolicyEffect {
+    /// Grant the permissions
+    Allow,
+    /// Revoke the permissions
+    Deny,
+}
+
+/// Permissions of an API token on a set of resources
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TokenPolicy {
+    /// Policy ID, set by cloudflare
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Effect of the policy
+    pub effect: PolicyEffect,
+    /// Resources the policy applies to, by resource name
+    pub resources: HashMap<String, Value>,
+    /// Permissions granted or revoked
+    pub permission_groups: Vec<PermissionGroup>,
+}
+
+impl TokenPolicy {
+    fn new(effect: PolicyEffect) -> Self {
+        Self {
+            id: None,
+            effect,
+            resources: HashMap::new(),
+            permission_groups: vec![],
+        }
+    }
+
+    /// Policy granting permissions
+    pub fn allow() -> Self {
+        Self::new(PolicyEffect::Allow)
+    }
+
+    /// Policy revoking permissions
+    pub fn deny() -> Self {
+        Self::new(PolicyEffect::Deny)
+    }
+
+    /// Apply the policy to the account `account_id`
+    pub fn account(self, account_id: &str) -> Self {
+        self.resource(format!("com.cloudflare.api.account.{account_id}"), "*")
+    }
+
+    /// Apply the policy to every zone of the account `account_id`
+    pub fn account_zones(self, account_id: &str) -> Self {
+        self.resource(
+            format!("com.cloudflare.api.account.{account_id}"),
+            serde_json::json!({ "com.cloudflare.api.account.zone.*": "*" }),
+        )
+    }
+
+    /// Apply the policy to the zone `zone_id`
+    pub fn zone(self, zone_id: &str) -> Self {
+        self.resource(format!("com.cloudflare.api.account.zone.{zone_id}"), "*")
+    }
+
+    /// Apply the policy to the resource `name`
+    pub fn resource(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.resources.insert(name.into(), value.into());
+        self
+    }
+
+    /// Add a permission, see [ListPermissionGroups]
+    pub fn permission_group(mut self, permission_group: impl AsRef<str>) -> Self {
+        self.permission_groups.push(PermissionGroup {
+            id: permission_group.as_ref().to_string(),
+            name: String::new(),
+            scopes: vec![],
+        });
+        self
+    }
+}
+
+/// Client IP ranges allowed or denied for a token
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RequestIpCondition {
+    /// IP ranges allowed to use the token
+    #[serde(rename = "in", default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    /// IP ranges denied from using the token
+    #[serde(rename = "not_in", default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+}
+
+/// Conditions under which a token can be used
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct TokenCondition {
+    /// Client IP restrictions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_ip: Option<RequestIpCondition>,
+}
+
+/// An API token, without its value
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ApiToken {
+    /// Token ID
+    pub id: String,
+    /// Name of the token
+    pub name: String,
+    /// Status of the token
+    pub status: TokenStatus,
+    /// Permissions of the token
+    pub policies: Vec<TokenPolicy>,
+    /// Conditions under which the token can be used
+    #[serde(default)]
+    pub condition: TokenCondition,
+    /// Time before which the token can't be used
+    pub not_before: Option<String>,
+    /// Expiry time of the token
+    pub expires_on: Option<String>,
+    /// Creation time
+    pub issued_on: Option<String>,
+    /// Last update time
+    pub modified_on: Option<String>,
+    /// Last use time
+    pub last_used_on: Option<String>,
+}
+
+/// Settings of a token, used by [CreateApiToken] and [UpdateApiToken]
+#[derive(Serialize, Clone, Debug)]
+pub struct ApiTokenParams {
+    name: String,
+    policies: Vec<TokenPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    condition: Option<TokenCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    not_before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_on: Option<String>,
+}
+
+impl ApiTokenParams {
+    /// Token `name` without any permission
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            policies: vec![],
+            condition: None,
+            not_before: None,
+            expires_on: None,
+        }
+    }
+
+    /// Add a policy to the token
+    pub fn policy(mut self, policy: TokenPolicy) -> Self {
+        self.policies.push(policy);
+        self
+    }
+
+    fn request_ip(&mut self) -> &mut RequestIpCondition {
+        self.condition
+            .get_or_insert_with(Default::default)
+            .request_ip
+            .get_or_insert_with(Default::default)
+    }
+
+    /// Only allow clients in the IP range `cidr` to use the token
+    pub fn allow_ip(mut self, cidr: impl Into<String>) -> Self {
+        self.request_ip().allow.push(cidr.into());
+        self
+    }
+
+    /// Deny clients in the IP range `cidr` from using the token
+    pub fn deny_ip(mut self, cidr: impl Into<String>) -> Self {
+        self.request_ip().deny.push(cidr.into());
+        self
+    }
+
+    /// Time before which the token can't be used
+    pub fn not_before(mut self, at: SystemTime) -> Self {
+        self.not_before = Some(rfc3339(at));
+        self
+    }
+
+    /// Expiry time of the token
+    pub fn expires_at(mut self, at: SystemTime) -> Self {
+        self.expires_on = Some(rfc3339(at));
+        self
+    }
+
+    /// Expire the token `ttl` from now
+    pub fn ttl(self, ttl: Duration) -> Self {
+        self.expires_at(SystemTime::now() + ttl)
+    }
+}
+
+/// [Create Token](https://developers.cloudflare.com/api/operations/user-api-tokens-create-token) API
+#[derive(Serialize)]
+pub struct CreateApiToken {
+    #[serde(flatten)]
+    params: ApiTokenParams,
+}
+
+impl CreateApiToken {
+    /// Create a token
+    pub fn new(params: ApiTokenParams) -> Self {
+        Self { params }
+    }
+}
+
+/// Success response from the [Create Token](https://developers.cloudflare.com/api/operations/user-api-tokens-create-token#Responses) API
+#[derive(Deserialize, Clone, Debug)]
+pub struct CreateApiTokenRes {
+    /// The created token
+    #[serde(flatten)]
+    pub token: ApiToken,
+    /// Value of the token, only returned once
+    pub value: SecretString,
+}
+
+impl CfReqMeta for CreateApiToken {
+    const METHOD: Method = Method::POST;
+    type Response = CreateApiTokenRes;
+}
+
+impl CfReqAuth for CreateApiToken {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "user/tokens".to_string()
+    }
+}
+
+/// [List Tokens](https://developers.cloudflare.com/api/operations/user-api-tokens-list-tokens) API
+#[derive(Serialize, Default)]
+pub struct ListApiTokens {
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListApiTokens {
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of tokens per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List Tokens](https://developers.cloudflare.com/api/operations/user-api-tokens-list-tokens#Responses) API
+#[derive(Deserialize)]
+pub struct ListApiTokensRes {
+    /// Tokens in this page
+    pub tokens: Vec<ApiToken>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListApiTokens {
+    const METHOD: Method = Method::GET;
+    type Response = ListApiTokensRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (tokens, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListApiTokensRes { tokens, page_info })
+    }
+}
+
+impl CfReqAuth for ListApiTokens {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "user/tokens".to_string()
+    }
+}
+
+/// [Token Details](https://developers.cloudflare.com/api/operations/user-api-tokens-token-details) API
+#[derive(Serialize)]
+pub struct GetApiToken {
+    #[serde(skip)]
+    token_id: String,
+}
+
+impl GetApiToken {
+    /// Get the token `token_id`
+    pub fn new(token_id: impl Into<String>) -> Self {
+        Self {
+            token_id: token_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Token Details](https://developers.cloudflare.com/api/operations/user-api-tokens-token-details#Responses) API
+pub type GetApiTokenRes = ApiToken;
+
+impl CfReqMeta for GetApiToken {
+    const METHOD: Method = Method::GET;
+    type Response = GetApiTokenRes;
+}
+
+impl CfReqAuth for GetApiToken {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        format!("user/tokens/{}", self.token_id)
+    }
+}
+
+/// [Update Token](https://developers.cloudflare.com/api/operations/user-api-tokens-update-token) API
+/// All the settings of the token are replaced
+#[derive(Serialize)]
+pub struct UpdateApiToken {
+    #[serde(skip)]
+    token_id: String,
+    #[serde(flatten)]
+    params: ApiTokenParams,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<TokenStatus>,
+}
+
+impl UpdateApiToken {
+    /// Replace the settings of the token `token_id`
+    pub fn new(token_id: impl Into<String>, params: ApiTokenParams) -> Self {
+        Self {
+            token_id: token_id.into(),
+            params,
+            status: None,
+        }
+    }
+
+    /// Enable ([TokenStatus::Active]) or disable ([TokenStatus::Disabled]) the token
+    pub fn status(mut self, status: TokenStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// Success response from the [Update Token](https://developers.cloudflare.com/api/operations/user-api-tokens-update-token#Responses) API
+pub type UpdateApiTokenRes = ApiToken;
+
+impl CfReqMeta for UpdateApiToken {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateApiTokenRes;
+}
+
+impl CfReqAuth for UpdateApiToken {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        format!("user/tokens/{}", self.token_id)
+    }
+}
+
+/// [Delete Token](https://developers.cloudflare.com/api/operations/user-api-tokens-delete-token) API
+#[derive(Serialize)]
+pub struct DeleteApiToken {
+    #[serde(skip)]
+    token_id: String,
+}
+
+impl DeleteApiToken {
+    /// Delete the token `token_id`
+    pub fn new(token_id: impl Into<String>) -> Self {
+        Self {
+            token_id: token_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeleteApiToken {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqAuth for DeleteApiToken {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        format!("user/tokens/{}", self.token_id)
+    }
+}
+
+/// [Roll Token](https://developers.cloudflare.com/api/operations/user-api-tokens-roll-token) API
+/// The previous value stops working immediately
+#[derive(Serialize)]
+pub struct RollApiToken {
+    #[serde(skip)]
+    token_id: String,
+}
+
+impl RollApiToken {
+    /// Generate a new value for the token `token_id`
+    pub fn new(token_id: impl Into<String>) -> Self {
+        Self {
+            token_id: token_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Roll Token](https://developers.cloudflare.com/api/operations/user-api-tokens-roll-token#Responses) API
+/// The new value of the token, only returned once
+pub type RollApiTokenRes = SecretString;
+
+impl CfReqMeta for RollApiToken {
+    const METHOD: Method = Method::PUT;
+    type Response = RollApiTokenRes;
+}
+
+impl CfReqAuth for RollApiToken {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        format!("user/tokens/{}/value", self.token_id)
+    }
+}
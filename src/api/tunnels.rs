@@ -0,0 +1,757 @@
+//! Cloudflare Tunnel related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/cloudflare-one/connections/connect-networks/)
+use crate::{
+    request::encode_segment, CfReqAuth, CfReqMeta, CfSuccessResWithInfo, Error, PageInfo, Result,
+    SecretString,
+};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Where the configuration of a tunnel is managed
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigSource {
+    /// Configuration file of cloudflared
+    Local,
+    /// Cloudflare dashboard or API
+    Cloudflare,
+}
+
+/// Health of a tunnel
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TunnelStatus {
+    /// Tunnel was never connected
+    Inactive,
+    /// Some connections are unhealthy
+    Degraded,
+    /// All connections are healthy
+    Healthy,
+    /// Tunnel has no connection
+    Down,
+    /// A status unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// A connection of cloudflared to a cloudflare datacenter
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TunnelConnection {
+    /// Connection ID
+    pub id: String,
+    /// Datacenter of the connection (e.g `DFW`)
+    pub colo_name: String,
+    /// ID of the cloudflared instance
+    pub client_id: String,
+    /// Version of the cloudflared instance
+    pub client_version: String,
+    /// Whether cloudflared is reconnecting
+    #[serde(default)]
+    pub is_pending_reconnect: bool,
+    /// Time the connection was opened
+    pub opened_at: String,
+    /// Public IP of the cloudflared instance
+    pub origin_ip: String,
+}
+
+/// A Cloudflare Tunnel
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Tunnel {
+    /// Tunnel ID
+    pub id: String,
+    /// Name of the tunnel
+    pub name: String,
+    /// Health of the tunnel
+    pub status: TunnelStatus,
+    /// Whether the configuration is managed by cloudflare
+    #[serde(default)]
+    pub remote_config: bool,
+    /// Active connections
+    #[serde(default)]
+    pub connections: Vec<TunnelConnection>,
+    /// Creation time
+    pub created_at: String,
+    /// Deletion time, for deleted tunnels
+    pub deleted_at: Option<String>,
+    /// Time the tunnel was last connected
+    pub conns_active_at: Option<String>,
+    /// Time the tunnel was last disconnected
+    pub conns_inactive_at: Option<String>,
+}
+
+/// [Create a Cloudflare Tunnel](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-create-a-cloudflare-tunnel) API
+#[derive(Serialize, Debug)]
+pub struct CreateTunnel {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tunnel_secret: Option<SecretString>,
+    config_src: ConfigSource,
+}
+
+impl CreateTunnel {
+    /// Create the tunnel `name`, configured through cloudflare,
+    /// cloudflared is then run with the token from [GetTunnelToken]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tunnel_secret: None,
+            config_src: ConfigSource::Cloudflare,
+        }
+    }
+
+    /// Create the tunnel `name`, configured with a cloudflared configuration file,
+    /// `tunnel_secret` is the base64 encoding of at least 32 random bytes
+    pub fn local(name: impl Into<String>, tunnel_secret: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tunnel_secret: Some(SecretString::from(tunnel_secret.into())),
+            config_src: ConfigSource::Local,
+        }
+    }
+
+    /// Create the tunnel `name`, configured with a cloudflared configuration file,
+    /// using a randomly generated secret, see [Self::tunnel_secret]
+    #[cfg(feature = "tunnel-secret")]
+    pub fn local_generated(name: impl Into<String>) -> Result<Self> {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        let mut secret = [0u8; 32];
+        getrandom::getrandom(&mut secret)?;
+        Ok(Self::local(name, STANDARD.encode(secret)))
+    }
+
+    /// Secret of the tunnel, needed in the cloudflared credentials file
+    pub fn tunnel_secret(&self) -> Option<&SecretString> {
+        self.tunnel_secret.as_ref()
+    }
+}
+
+/// Success response from the [Create a Cloudflare Tunnel](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-create-a-cloudflare-tunnel#Responses) API
+pub type CreateTunnelRes = Tunnel;
+
+impl CfReqMeta for CreateTunnel {
+    const METHOD: Method = Method::POST;
+    type Response = CreateTunnelRes;
+}
+
+impl CfReqAuth for CreateTunnel {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/cfd_tunnel")
+    }
+}
+
+/// [List Cloudflare Tunnels](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-list-cloudflare-tunnels) API
+#[derive(Serialize, Default)]
+pub struct ListTunnels {
+    name: Option<String>,
+    is_deleted: Option<bool>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListTunnels {
+    /// Only list the tunnel named `name`
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Only list deleted (`true`) or existing (`false`) tunnels
+    pub fn is_deleted(mut self, is_deleted: bool) -> Self {
+        self.is_deleted = Some(is_deleted);
+        self
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of tunnels per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List Cloudflare Tunnels](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-list-cloudflare-tunnels#Responses) API
+#[derive(Deserialize)]
+pub struct ListTunnelsRes {
+    /// Tunnels in this page
+    pub tunnels: Vec<Tunnel>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListTunnels {
+    const METHOD: Method = Method::GET;
+    type Response = ListTunnelsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (tunnels, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListTunnelsRes { tunnels, page_info })
+    }
+}
+
+impl CfReqAuth for ListTunnels {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/cfd_tunnel")
+    }
+}
+
+/// [Get a Cloudflare Tunnel](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-get-a-cloudflare-tunnel) API
+#[derive(Serialize)]
+pub struct GetTunnel {
+    #[serde(skip)]
+    tunnel_id: String,
+}
+
+impl GetTunnel {
+    /// Get the tunnel `tunnel_id`
+    pub fn new(tunnel_id: impl Into<String>) -> Self {
+        Self {
+            tunnel_id: tunnel_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get a Cloudflare Tunnel](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-get-a-cloudflare-tunnel#Responses) API
+pub type GetTunnelRes = Tunnel;
+
+impl CfReqMeta for GetTunnel {
+    const METHOD: Method = Method::GET;
+    type Response = GetTunnelRes;
+}
+
+impl CfReqAuth for GetTunnel {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/cfd_tunnel/{}", self.tunnel_id)
+    }
+}
+
+/// [Delete a Cloudflare Tunnel](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-delete-a-cloudflare-tunnel) API
+/// Tunnels with active connections can't be deleted
+#[derive(Serialize)]
+pub struct DeleteTunnel {
+    #[serde(skip)]
+    tunnel_id: String,
+}
+
+impl DeleteTunnel {
+    /// Delete the tunnel `tunnel_id`
+    pub fn new(tunnel_id: impl Into<String>) -> Self {
+        Self {
+            tunnel_id: tunnel_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete a Cloudflare Tunnel](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-delete-a-cloudflare-tunnel#Responses) API
+pub type DeleteTunnelRes = Tunnel;
+
+impl CfReqMeta for DeleteTunnel {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteTunnelRes;
+}
+
+impl CfReqAuth for DeleteTunnel {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/cfd_tunnel/{}", self.tunnel_id)
+    }
+}
+
+/// [Get a Cloudflare Tunnel token](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-get-a-cloudflare-tunnel-token) API
+/// The token is used to run cloudflared (`cloudflared tunnel run --token <token>`)
+#[derive(Serialize)]
+pub struct GetTunnelToken {
+    #[serde(skip)]
+    tunnel_id: String,
+}
+
+impl GetTunnelToken {
+    /// Get the connector token of the tunnel `tunnel_id`
+    pub fn new(tunnel_id: impl Into<String>) -> Self {
+        Self {
+            tunnel_id: tunnel_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get a Cloudflare Tunnel token](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-get-a-cloudflare-tunnel-token#Responses) API
+pub type GetTunnelTokenRes = SecretString;
+
+impl CfReqMeta for GetTunnelToken {
+    const METHOD: Method = Method::GET;
+    type Response = GetTunnelTokenRes;
+}
+
+impl CfReqAuth for GetTunnelToken {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/cfd_tunnel/{}/token", self.tunnel_id)
+    }
+}
+
+/// A cloudflared instance connected to a tunnel
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TunnelClient {
+    /// ID of the cloudflared instance
+    pub id: String,
+    /// Architecture of the cloudflared instance (e.g `linux_amd64`)
+    pub arch: Option<String>,
+    /// Version of the cloudflared instance
+    pub version: Option<String>,
+    /// Start time of the cloudflared instance
+    pub run_at: Option<String>,
+    /// Connections of the cloudflared instance
+    #[serde(default)]
+    pub conns: Vec<TunnelConnection>,
+}
+
+/// [List Cloudflare Tunnel connections](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-list-cloudflare-tunnel-connections) API
+#[derive(Serialize)]
+pub struct ListTunnelConnections {
+    #[serde(skip)]
+    tunnel_id: String,
+}
+
+impl ListTunnelConnections {
+    /// List the cloudflared instances connected to the tunnel `tunnel_id`
+    pub fn new(tunnel_id: impl Into<String>) -> Self {
+        Self {
+            tunnel_id: tunnel_id.into(),
+        }
+    }
+}
+
+/// Success response from the [List Cloudflare Tunnel connections](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-list-cloudflare-tunnel-connections#Responses) API
+pub type ListTunnelConnectionsRes = Vec<TunnelClient>;
+
+impl CfReqMeta for ListTunnelConnections {
+    const METHOD: Method = Method::GET;
+    type Response = ListTunnelConnectionsRes;
+}
+
+impl CfReqAuth for ListTunnelConnections {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/cfd_tunnel/{}/connections",
+            self.tunnel_id
+        )
+    }
+}
+
+/// Settings of the requests cloudflared sends to an origin
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OriginRequest {
+    /// Timeout for connecting to the origin, in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_timeout: Option<u32>,
+    /// Timeout for the TLS handshake with the origin, in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_timeout: Option<u32>,
+    /// Idle timeout of connections to the origin, in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_alive_timeout: Option<u32>,
+    /// Disable TLS certificate verification of the origin
+    #[serde(rename = "noTLSVerify", skip_serializing_if = "Option::is_none")]
+    pub no_tls_verify: Option<bool>,
+    /// Hostname expected in the origin's certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin_server_name: Option<String>,
+    /// `Host` header sent to the origin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_host_header: Option<String>,
+    /// Connect to the origin over HTTP/2
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http2_origin: Option<bool>,
+    /// Disable chunked transfer encoding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_chunked_encoding: Option<bool>,
+}
+
+/// An ingress rule, mapping requests to a service
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IngressRule {
+    /// Hostname matched by the rule, `None` matches every hostname
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// Regex matched against the path, `None` matches every path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Service requests are sent to (e.g `http://localhost:8080` or `http_status:404`)
+    pub service: String,
+    /// Settings overriding [TunnelConfig::origin_request] for this rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin_request: Option<OriginRequest>,
+}
+
+impl IngressRule {
+    /// Send requests for `hostname` to `service`
+    pub fn new(hostname: impl Into<String>, service: impl Into<String>) -> Self {
+        Self {
+            hostname: Some(hostname.into()),
+            path: None,
+            service: service.into(),
+            origin_request: None,
+        }
+    }
+
+    /// Send all remaining requests to `service` (e.g `http_status:404`),
+    /// must be the last rule
+    pub fn catch_all(service: impl Into<String>) -> Self {
+        Self {
+            hostname: None,
+            path: None,
+            service: service.into(),
+            origin_request: None,
+        }
+    }
+
+    /// Only match paths matching the regex `path`
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Settings of the requests sent to the service
+    pub fn origin_request(mut self, origin_request: OriginRequest) -> Self {
+        self.origin_request = Some(origin_request);
+        self
+    }
+
+    /// Whether the rule matches every request
+    pub fn is_catch_all(&self) -> bool {
+        self.hostname.is_none() && self.path.is_none()
+    }
+}
+
+/// Private network routing through a tunnel
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct WarpRouting {
+    /// Whether private network routing is enabled
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Configuration of a tunnel managed by cloudflare
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct TunnelConfig {
+    /// Ingress rules, in order
+    #[serde(default)]
+    pub ingress: Vec<IngressRule>,
+    /// Settings of the requests sent to every service
+    #[serde(rename = "originRequest", skip_serializing_if = "Option::is_none")]
+    pub origin_request: Option<OriginRequest>,
+    /// Private network routing
+    #[serde(rename = "warp-routing", skip_serializing_if = "Option::is_none")]
+    pub warp_routing: Option<WarpRouting>,
+}
+
+/// [Get configuration](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-configuration-get-configuration) API
+#[derive(Serialize)]
+pub struct GetTunnelConfiguration {
+    #[serde(skip)]
+    tunnel_id: String,
+}
+
+impl GetTunnelConfiguration {
+    /// Get the configuration of the tunnel `tunnel_id`
+    pub fn new(tunnel_id: impl Into<String>) -> Self {
+        Self {
+            tunnel_id: tunnel_id.into(),
+        }
+    }
+}
+
+/// Configuration of a tunnel, along with its version
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TunnelConfiguration {
+    /// Tunnel ID
+    pub tunnel_id: String,
+    /// Version of the configuration, incremented on every update
+    pub version: u32,
+    /// The configuration, `None` if it was never set
+    pub config: Option<TunnelConfig>,
+    /// Where the configuration is managed
+    pub source: ConfigSource,
+    /// Time of the last update
+    pub created_at: String,
+}
+
+/// Success response from the [Get configuration](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-configuration-get-configuration#Responses) API
+pub type GetTunnelConfigurationRes = TunnelConfiguration;
+
+impl CfReqMeta for GetTunnelConfiguration {
+    const METHOD: Method = Method::GET;
+    type Response = GetTunnelConfigurationRes;
+}
+
+impl CfReqAuth for GetTunnelConfiguration {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/cfd_tunnel/{}/configurations",
+            self.tunnel_id
+        )
+    }
+}
+
+/// [Put configuration](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-configuration-put-configuration) API
+/// Only for tunnels created with [ConfigSource::Cloudflare]
+#[derive(Serialize)]
+pub struct PutTunnelConfiguration {
+    #[serde(skip)]
+    tunnel_id: String,
+    config: TunnelConfig,
+}
+
+impl PutTunnelConfiguration {
+    /// Replace the configuration of the tunnel `tunnel_id`
+    /// The last ingress rule must be the only [catch-all](IngressRule::catch_all) rule
+    pub fn new(tunnel_id: impl Into<String>, config: TunnelConfig) -> Result<Self> {
+        let Some(last) = config.ingress.last() else {
+            return Err(Error::InvalidRequest(
+                "tunnel ingress needs at least a catch-all rule".to_string(),
+            ));
+        };
+        if !last.is_catch_all() {
+            return Err(Error::InvalidRequest(
+                "the last tunnel ingress rule must be a catch-all rule (no hostname or path)"
+                    .to_string(),
+            ));
+        }
+        let rules = config.ingress.len() - 1;
+        if let Some(idx) = config.ingress[..rules]
+            .iter()
+            .position(IngressRule::is_catch_all)
+        {
+            return Err(Error::InvalidRequest(format!(
+                "only the last tunnel ingress rule can be a catch-all rule, rule {idx} is one"
+            )));
+        }
+        Ok(Self {
+            tunnel_id: tunnel_id.into(),
+            config,
+        })
+    }
+}
+
+/// Success response from the [Put configuration](https://developers.cloudflare.com/api/operations/cloudflare-tunnel-configuration-put-configuration#Responses) API
+pub type PutTunnelConfigurationRes = TunnelConfiguration;
+
+impl CfReqMeta for PutTunnelConfiguration {
+    const METHOD: Method = Method::PUT;
+    type Response = PutTunnelConfigurationRes;
+}
+
+impl CfReqAuth for PutTunnelConfiguration {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/cfd_tunnel/{}/configurations",
+            self.tunnel_id
+        )
+    }
+}
+
+/// A private network route through a tunnel
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TunnelRoute {
+    /// Route ID
+    pub id: String,
+    /// Routed network, in CIDR notation
+    pub network: String,
+    /// ID of the tunnel the network is routed through
+    pub tunnel_id: String,
+    /// Comment of the route
+    #[serde(default)]
+    pub comment: String,
+    /// ID of the virtual network of the route
+    pub virtual_network_id: Option<String>,
+    /// Creation time
+    pub created_at: String,
+    /// Deletion time, for deleted routes
+    pub deleted_at: Option<String>,
+}
+
+/// [Create a tunnel route](https://developers.cloudflare.com/api/operations/tunnel-route-create-a-tunnel-route) API
+#[derive(Serialize)]
+pub struct CreateTunnelRoute {
+    network: String,
+    tunnel_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    virtual_network_id: Option<String>,
+}
+
+impl CreateTunnelRoute {
+    /// Route the network `network` (e.g `10.0.0.0/16`) through the tunnel `tunnel_id`
+    pub fn new(network: impl Into<String>, tunnel_id: impl Into<String>) -> Self {
+        Self {
+            network: network.into(),
+            tunnel_id: tunnel_id.into(),
+            comment: None,
+            virtual_network_id: None,
+        }
+    }
+
+    /// Comment of the route
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Virtual network of the route, defaults to the account's default virtual network
+    pub fn virtual_network_id(mut self, virtual_network_id: impl Into<String>) -> Self {
+        self.virtual_network_id = Some(virtual_network_id.into());
+        self
+    }
+}
+
+/// Success response from the [Create a tunnel route](https://developers.cloudflare.com/api/operations/tunnel-route-create-a-tunnel-route#Responses) API
+pub type CreateTunnelRouteRes = TunnelRoute;
+
+impl CfReqMeta for CreateTunnelRoute {
+    const METHOD: Method = Method::POST;
+    type Response = CreateTunnelRouteRes;
+}
+
+impl CfReqAuth for CreateTunnelRoute {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/teamnet/routes")
+    }
+}
+
+/// [List tunnel routes](https://developers.cloudflare.com/api/operations/tunnel-route-list-tunnel-routes) API
+#[derive(Serialize, Default)]
+pub struct ListTunnelRoutes {
+    tunnel_id: Option<String>,
+    is_deleted: Option<bool>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListTunnelRoutes {
+    /// Only list routes through the tunnel `tunnel_id`
+    pub fn tunnel_id(mut self, tunnel_id: impl Into<String>) -> Self {
+        self.tunnel_id = Some(tunnel_id.into());
+        self
+    }
+
+    /// Only list deleted (`true`) or existing (`false`) routes
+    pub fn is_deleted(mut self, is_deleted: bool) -> Self {
+        self.is_deleted = Some(is_deleted);
+        self
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of routes per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List tunnel routes](https://developers.cloudflare.com/api/operations/tunnel-route-list-tunnel-routes#Responses) API
+#[derive(Deserialize)]
+pub struct ListTunnelRoutesRes {
+    /// Routes in this page
+    pub routes: Vec<TunnelRoute>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListTunnelRoutes {
+    const METHOD: Method = Method::GET;
+    type Response = ListTunnelRoutesRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (routes, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListTunnelRoutesRes { routes, page_info })
+    }
+}
+
+impl CfReqAuth for ListTunnelRoutes {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/teamnet/routes")
+    }
+}
+
+/// [Delete a tunnel route by CIDR](https://developers.cloudflare.com/api/operations/tunnel-route-delete-a-tunnel-route-with-cidr) API
+#[derive(Serialize)]
+pub struct DeleteTunnelRoute {
+    #[serde(skip)]
+    network: String,
+    #[serde(skip)]
+    virtual_network_id: Option<String>,
+}
+
+impl DeleteTunnelRoute {
+    /// Delete the route of the network `network` (e.g `10.0.0.0/16`)
+    pub fn new(network: impl Into<String>) -> Self {
+        Self {
+            network: network.into(),
+            virtual_network_id: None,
+        }
+    }
+
+    /// Virtual network of the route, defaults to the account's default virtual network
+    pub fn virtual_network_id(mut self, virtual_network_id: impl Into<String>) -> Self {
+        self.virtual_network_id = Some(virtual_network_id.into());
+        self
+    }
+}
+
+/// Success response from the [Delete a tunnel route by CIDR](https://developers.cloudflare.com/api/operations/tunnel-route-delete-a-tunnel-route-with-cidr#Responses) API
+pub type DeleteTunnelRouteRes = TunnelRoute;
+
+impl CfReqMeta for DeleteTunnelRoute {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteTunnelRouteRes;
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.virtual_network_id
+            .iter()
+            .map(|id| ("virtual_network_id", id.clone()))
+            .collect()
+    }
+}
+
+impl CfReqAuth for DeleteTunnelRoute {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/teamnet/routes/network/{}",
+            encode_segment(&self.network)
+        )
+    }
+}
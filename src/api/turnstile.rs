@@ -0,0 +1,442 @@
+//! Cloudflare Turnstile related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/turnstile/)
+use std::fmt;
+
+use crate::{
+    consts::TURNSTILE_SITEVERIFY_URL, CfReq, CfReqAuth, CfReqMeta, CfSuccessResWithInfo, PageInfo,
+    Result,
+};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// [Siteverify](https://developers.cloudflare.com/turnstile/get-started/server-side-validation/) API
+/// Send form encoded with [crate::Cloudflare::send_form], the request always goes to [TURNSTILE_SITEVERIFY_URL]
+#[derive(Serialize)]
+pub struct TurnstileSiteverify {
+    secret: String,
+    response: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remoteip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    idempotency_key: Option<String>,
+}
+
+impl fmt::Debug for TurnstileSiteverify {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TurnstileSiteverify")
+            .field("secret", &"<redacted>")
+            .field("response", &self.response)
+            .field("remoteip", &self.remoteip)
+            .field("idempotency_key", &self.idempotency_key)
+            .finish()
+    }
+}
+
+impl TurnstileSiteverify {
+    /// Validate the token `response` produced by the widget with the widget's `secret`
+    pub fn new(secret: impl Into<String>, response: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+            response: response.into(),
+            remoteip: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// IP address of the visitor
+    pub fn remote_ip(mut self, remote_ip: impl Into<String>) -> Self {
+        self.remoteip = Some(remote_ip.into());
+        self
+    }
+
+    /// UUID allowing the same token to be validated more than once (e.g on retries)
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+}
+
+/// Error code returned by [TurnstileSiteverify]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TurnstileError {
+    /// The secret was not passed
+    MissingInputSecret,
+    /// The secret is invalid
+    InvalidInputSecret,
+    /// The token was not passed
+    MissingInputResponse,
+    /// The token is invalid or expired
+    InvalidInputResponse,
+    /// The request was rejected because it was malformed
+    BadRequest,
+    /// The token was already validated, or is older than 5 minutes
+    TimeoutOrDuplicate,
+    /// Cloudflare failed to validate the token, the request can be retried
+    InternalError,
+    /// An error code unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Success response from the [Siteverify](https://developers.cloudflare.com/turnstile/get-started/server-side-validation/) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TurnstileSiteverifyRes {
+    /// Whether the token is valid
+    pub success: bool,
+    /// Reasons the token is invalid
+    #[serde(rename = "error-codes", default)]
+    pub error_codes: Vec<TurnstileError>,
+    /// RFC3339 timestamp of when the challenge was solved
+    pub challenge_ts: Option<String>,
+    /// Hostname of the site the challenge was solved on
+    pub hostname: Option<String>,
+    /// Action passed to the widget
+    pub action: Option<String>,
+    /// Customer data passed to the widget
+    pub cdata: Option<String>,
+}
+
+impl CfReqMeta for TurnstileSiteverify {
+    const METHOD: Method = Method::POST;
+    type Response = TurnstileSiteverifyRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        // the response is not wrapped in a CfSuccessRes
+        Ok(serde_json::from_slice(&body)?)
+    }
+}
+
+impl CfReq for TurnstileSiteverify {
+    // absolute, so joining it to the base URL replaces the base URL
+    const PATH: &'static str = TURNSTILE_SITEVERIFY_URL;
+}
+
+/// Mode of a Turnstile widget
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum WidgetMode {
+    /// Cloudflare decides whether to show a checkbox
+    Managed,
+    /// Never show a checkbox, the widget is visible
+    NonInteractive,
+    /// The widget is not visible
+    Invisible,
+}
+
+/// A Turnstile widget
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TurnstileWidget {
+    /// Sitekey of the widget, used by the client side widget
+    pub sitekey: String,
+    /// Secret of the widget, used by [TurnstileSiteverify]
+    /// Not returned when listing widgets
+    pub secret: Option<String>,
+    /// Name of the widget
+    pub name: String,
+    /// Domains the widget can be used on
+    pub domains: Vec<String>,
+    /// Mode of the widget
+    pub mode: WidgetMode,
+    /// Whether bots solving the challenge are blocked
+    #[serde(default)]
+    pub bot_fight_mode: bool,
+    /// RFC3339 timestamp of when the widget was created
+    pub created_on: Option<String>,
+    /// RFC3339 timestamp of when the widget was last modified
+    pub modified_on: Option<String>,
+}
+
+impl fmt::Debug for TurnstileWidget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TurnstileWidget")
+            .field("sitekey", &self.sitekey)
+            .field("secret", &self.secret.as_ref().map(|_| "<redacted>"))
+            .field("name", &self.name)
+            .field("domains", &self.domains)
+            .field("mode", &self.mode)
+            .field("bot_fight_mode", &self.bot_fight_mode)
+            .field("created_on", &self.created_on)
+            .field("modified_on", &self.modified_on)
+            .finish()
+    }
+}
+
+/// Settings of a Turnstile widget
+#[derive(Serialize, Clone, Debug)]
+pub struct WidgetSettings {
+    name: String,
+    domains: Vec<String>,
+    mode: WidgetMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bot_fight_mode: Option<bool>,
+}
+
+impl WidgetSettings {
+    /// A widget named `name` usable on `domains`
+    pub fn new<D: Into<String>>(
+        name: impl Into<String>,
+        domains: impl IntoIterator<Item = D>,
+        mode: WidgetMode,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            domains: domains.into_iter().map(Into::into).collect(),
+            mode,
+            bot_fight_mode: None,
+        }
+    }
+
+    /// Block bots solving the challenge
+    pub fn bot_fight_mode(mut self, enabled: bool) -> Self {
+        self.bot_fight_mode = Some(enabled);
+        self
+    }
+}
+
+/// [Create a Turnstile Widget](https://developers.cloudflare.com/api/operations/accounts-turnstile-widget-create) API
+#[derive(Serialize)]
+pub struct CreateTurnstileWidget {
+    #[serde(flatten)]
+    settings: WidgetSettings,
+}
+
+impl CreateTurnstileWidget {
+    /// Create a widget with the given settings
+    pub fn new(settings: WidgetSettings) -> Self {
+        Self { settings }
+    }
+}
+
+/// Success response from the [Create a Turnstile Widget](https://developers.cloudflare.com/api/operations/accounts-turnstile-widget-create#Responses) API
+pub type CreateTurnstileWidgetRes = TurnstileWidget;
+
+impl CfReqMeta for CreateTurnstileWidget {
+    const METHOD: Method = Method::POST;
+    type Response = CreateTurnstileWidgetRes;
+}
+
+impl CfReqAuth for CreateTurnstileWidget {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/challenges/widgets")
+    }
+}
+
+/// [List Turnstile Widgets](https://developers.cloudflare.com/api/operations/accounts-turnstile-widgets-list) API
+#[derive(Serialize, Default)]
+pub struct ListTurnstileWidgets {
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListTurnstileWidgets {
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of widgets per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List Turnstile Widgets](https://developers.cloudflare.com/api/operations/accounts-turnstile-widgets-list#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListTurnstileWidgetsRes {
+    /// Widgets in this page, without their secret
+    pub widgets: Vec<TurnstileWidget>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListTurnstileWidgets {
+    const METHOD: Method = Method::GET;
+    type Response = ListTurnstileWidgetsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (widgets, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListTurnstileWidgetsRes { widgets, page_info })
+    }
+}
+
+impl CfReqAuth for ListTurnstileWidgets {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/challenges/widgets")
+    }
+}
+
+/// [Turnstile Widget Details](https://developers.cloudflare.com/api/operations/accounts-turnstile-widget-get) API
+#[derive(Serialize)]
+pub struct GetTurnstileWidget {
+    #[serde(skip)]
+    sitekey: String,
+}
+
+impl GetTurnstileWidget {
+    /// Retrieve the widget with the given sitekey
+    pub fn new(sitekey: impl Into<String>) -> Self {
+        Self {
+            sitekey: sitekey.into(),
+        }
+    }
+}
+
+/// Success response from the [Turnstile Widget Details](https://developers.cloudflare.com/api/operations/accounts-turnstile-widget-get#Responses) API
+pub type GetTurnstileWidgetRes = TurnstileWidget;
+
+impl CfReqMeta for GetTurnstileWidget {
+    const METHOD: Method = Method::GET;
+    type Response = GetTurnstileWidgetRes;
+}
+
+impl CfReqAuth for GetTurnstileWidget {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/challenges/widgets/{}", self.sitekey)
+    }
+}
+
+/// [Update a Turnstile Widget](https://developers.cloudflare.com/api/operations/accounts-turnstile-widget-update) API
+#[derive(Serialize)]
+pub struct UpdateTurnstileWidget {
+    #[serde(skip)]
+    sitekey: String,
+    #[serde(flatten)]
+    settings: WidgetSettings,
+}
+
+impl UpdateTurnstileWidget {
+    /// Replace the settings of the widget with the given sitekey
+    pub fn new(sitekey: impl Into<String>, settings: WidgetSettings) -> Self {
+        Self {
+            sitekey: sitekey.into(),
+            settings,
+        }
+    }
+}
+
+/// Success response from the [Update a Turnstile Widget](https://developers.cloudflare.com/api/operations/accounts-turnstile-widget-update#Responses) API
+pub type UpdateTurnstileWidgetRes = TurnstileWidget;
+
+impl CfReqMeta for UpdateTurnstileWidget {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateTurnstileWidgetRes;
+}
+
+impl CfReqAuth for UpdateTurnstileWidget {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/challenges/widgets/{}", self.sitekey)
+    }
+}
+
+/// [Delete a Turnstile Widget](https://developers.cloudflare.com/api/operations/accounts-turnstile-widget-delete) API
+#[derive(Serialize)]
+pub struct DeleteTurnstileWidget {
+    #[serde(skip)]
+    sitekey: String,
+}
+
+impl DeleteTurnstileWidget {
+    /// Delete the widget with the given sitekey
+    pub fn new(sitekey: impl Into<String>) -> Self {
+        Self {
+            sitekey: sitekey.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete a Turnstile Widget](https://developers.cloudflare.com/api/operations/accounts-turnstile-widget-delete#Responses) API
+pub type DeleteTurnstileWidgetRes = TurnstileWidget;
+
+impl CfReqMeta for DeleteTurnstileWidget {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteTurnstileWidgetRes;
+}
+
+impl CfReqAuth for DeleteTurnstileWidget {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/challenges/widgets/{}", self.sitekey)
+    }
+}
+
+/// [Rotate Secret for a Turnstile Widget](https://developers.cloudflare.com/api/operations/accounts-turnstile-widget-rotate-secret) API
+#[derive(Serialize)]
+pub struct RotateTurnstileSecret {
+    #[serde(skip)]
+    sitekey: String,
+    invalidate_immediately: bool,
+}
+
+impl RotateTurnstileSecret {
+    /// Generate a new secret for the widget with the given sitekey,
+    /// the previous secret stays valid for 2 hours
+    pub fn new(sitekey: impl Into<String>) -> Self {
+        Self {
+            sitekey: sitekey.into(),
+            invalidate_immediately: false,
+        }
+    }
+
+    /// Invalidate the previous secret right away
+    pub fn invalidate_immediately(mut self) -> Self {
+        self.invalidate_immediately = true;
+        self
+    }
+}
+
+/// Success response from the [Rotate Secret for a Turnstile Widget](https://developers.cloudflare.com/api/operations/accounts-turnstile-widget-rotate-secret#Responses) API
+pub type RotateTurnstileSecretRes = TurnstileWidget;
+
+impl CfReqMeta for RotateTurnstileSecret {
+    const METHOD: Method = Method::POST;
+    type Response = RotateTurnstileSecretRes;
+}
+
+impl CfReqAuth for RotateTurnstileSecret {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/challenges/widgets/{}/rotate_secret",
+            self.sitekey
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn siteverify_form_body() {
+        let req = TurnstileSiteverify::new("0x4AAAAAAA", "token")
+            .remote_ip("203.0.113.7")
+            .idempotency_key("f5a3e1d2-7c4b-4e8a-9d6f-1b2c3d4e5f60");
+        let body = reqwest::Client::new()
+            .post(TURNSTILE_SITEVERIFY_URL)
+            .form(&req)
+            .build()
+            .unwrap();
+        assert_eq!(
+            body.headers()[reqwest::header::CONTENT_TYPE],
+            "application/x-www-form-urlencoded"
+        );
+        assert_eq!(
+            body.body().and_then(|body| body.as_bytes()).unwrap(),
+            b"secret=0x4AAAAAAA&response=token&remoteip=203.0.113.7&idempotency_key=f5a3e1d2-7c4b-4e8a-9d6f-1b2c3d4e5f60"
+        );
+    }
+}
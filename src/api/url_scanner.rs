@@ -0,0 +1,328 @@
+//! Cloudflare URL Scanner related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/radar/investigate/url-scanner/)
+use std::collections::HashMap;
+
+use crate::{CfApiErr, CfReqAuth, CfReqMeta, Error, Result};
+use bytes::Bytes;
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+
+/// Visibility of a scan
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanVisibility {
+    /// Listed in the public scan history
+    Public,
+    /// Only accessible to the account
+    Unlisted,
+}
+
+/// [Create URL Scan](https://developers.cloudflare.com/api/operations/urlscanner-create-scan-v2) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitUrlScan {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<ScanVisibility>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    custom_headers: HashMap<String, String>,
+}
+
+impl SubmitUrlScan {
+    /// Scan the page at `url`
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            visibility: None,
+            custom_headers: HashMap::new(),
+        }
+    }
+
+    /// Visibility of the scan, defaults to [ScanVisibility::Public]
+    pub fn visibility(mut self, visibility: ScanVisibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Add a header to the requests made by the scanner
+    pub fn add_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.custom_headers.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// Success response from the [Create URL Scan](https://developers.cloudflare.com/api/operations/urlscanner-create-scan-v2#Responses) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SubmitUrlScanRes {
+    /// Identifier of the scan
+    pub uuid: String,
+    /// URL of the scan result in the API
+    pub api: String,
+    /// Visibility of the scan
+    pub visibility: ScanVisibility,
+    /// Scanned URL
+    pub url: String,
+    /// Submission message
+    #[serde(default)]
+    pub message: String,
+}
+
+impl CfReqMeta for SubmitUrlScan {
+    const METHOD: Method = Method::POST;
+    type Response = SubmitUrlScanRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        // v2 responses are not wrapped in a CfSuccessRes
+        Ok(serde_json::from_slice(&body)?)
+    }
+}
+
+impl CfReqAuth for SubmitUrlScan {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/urlscanner/v2/scan")
+    }
+}
+
+/// Outcome of retrieving the result of a scan
+/// Cloudflare responds with a 404 until the scan is finished,
+/// so unknown scans are also reported as [ScanOutcome::ScanPending]
+#[derive(Deserialize, Clone, Debug)]
+#[serde(from = "Option<T>")]
+pub enum ScanOutcome<T> {
+    /// The scan is still running
+    ScanPending,
+    /// The scan is finished
+    Done(T),
+}
+
+impl<T> From<Option<T>> for ScanOutcome<T> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::ScanPending, Self::Done)
+    }
+}
+
+impl<T> ScanOutcome<T> {
+    /// The result, `None` if the scan is still running
+    pub fn done(self) -> Option<T> {
+        match self {
+            Self::ScanPending => None,
+            Self::Done(res) => Some(res),
+        }
+    }
+}
+
+fn pending_on_not_found<T>(status: StatusCode, errors: Vec<CfApiErr>) -> Result<ScanOutcome<T>> {
+    if status == StatusCode::NOT_FOUND {
+        return Ok(ScanOutcome::ScanPending);
+    }
+    Err(Error::Cloudflare(errors))
+}
+
+/// Task of a scan
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScanTask {
+    /// Identifier of the scan
+    pub uuid: String,
+    /// Scanned URL
+    pub url: String,
+    /// Whether the page was scanned successfully
+    #[serde(default)]
+    pub success: bool,
+    /// RFC3339 timestamp of the scan
+    pub time: Option<String>,
+}
+
+/// Scanned page
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScanPage {
+    /// Final URL of the page, after redirects
+    pub url: String,
+    /// Domain of the page
+    pub domain: Option<String>,
+    /// IP address serving the page
+    pub ip: Option<String>,
+    /// Country of the IP address
+    pub country: Option<String>,
+    /// HTTP status of the page
+    pub status: Option<u16>,
+}
+
+/// Overall verdict of a scan
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScanVerdict {
+    /// Whether the page is considered malicious
+    pub malicious: bool,
+    /// Categories of the threats found
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+/// Verdicts of a scan
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScanVerdicts {
+    /// Overall verdict
+    pub overall: ScanVerdict,
+}
+
+/// Result of a scan
+/// Note: This response is not complete
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UrlScanResult {
+    /// Task of the scan
+    pub task: ScanTask,
+    /// Scanned page
+    pub page: Option<ScanPage>,
+    /// Verdicts of the scan
+    pub verdicts: Option<ScanVerdicts>,
+}
+
+/// [Get URL scan](https://developers.cloudflare.com/api/operations/urlscanner-get-scan-v2) API
+#[derive(Serialize)]
+pub struct GetUrlScanResult {
+    #[serde(skip)]
+    scan_id: String,
+}
+
+impl GetUrlScanResult {
+    /// Retrieve the result of the scan with the given id
+    pub fn new(scan_id: impl Into<String>) -> Self {
+        Self {
+            scan_id: scan_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get URL scan](https://developers.cloudflare.com/api/operations/urlscanner-get-scan-v2#Responses) API
+pub type GetUrlScanResultRes = ScanOutcome<UrlScanResult>;
+
+impl CfReqMeta for GetUrlScanResult {
+    const METHOD: Method = Method::GET;
+    type Response = GetUrlScanResultRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        // v2 responses are not wrapped in a CfSuccessRes
+        Ok(ScanOutcome::Done(serde_json::from_slice(&body)?))
+    }
+
+    fn handle_error(status: StatusCode, errors: Vec<CfApiErr>) -> Result<Self::Response> {
+        pending_on_not_found(status, errors)
+    }
+}
+
+impl CfReqAuth for GetUrlScanResult {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/urlscanner/v2/result/{}",
+            self.scan_id
+        )
+    }
+}
+
+/// [Get URL scan's HAR](https://developers.cloudflare.com/api/operations/urlscanner-get-scan-har-v2) API
+#[derive(Serialize)]
+pub struct GetUrlScanHar {
+    #[serde(skip)]
+    scan_id: String,
+}
+
+impl GetUrlScanHar {
+    /// Retrieve the HTTP archive of the scan with the given id
+    pub fn new(scan_id: impl Into<String>) -> Self {
+        Self {
+            scan_id: scan_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get URL scan's HAR](https://developers.cloudflare.com/api/operations/urlscanner-get-scan-har-v2#Responses) API
+pub type GetUrlScanHarRes = ScanOutcome<serde_json::Value>;
+
+impl CfReqMeta for GetUrlScanHar {
+    const METHOD: Method = Method::GET;
+    type Response = GetUrlScanHarRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        // v2 responses are not wrapped in a CfSuccessRes
+        Ok(ScanOutcome::Done(serde_json::from_slice(&body)?))
+    }
+
+    fn handle_error(status: StatusCode, errors: Vec<CfApiErr>) -> Result<Self::Response> {
+        pending_on_not_found(status, errors)
+    }
+}
+
+impl CfReqAuth for GetUrlScanHar {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/urlscanner/v2/har/{}", self.scan_id)
+    }
+}
+
+/// Device a scan screenshot was taken with
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotResolution {
+    /// Desktop browser
+    Desktop,
+    /// Mobile browser
+    Mobile,
+    /// Tablet browser
+    Tablet,
+}
+
+/// [Get screenshot](https://developers.cloudflare.com/api/operations/urlscanner-get-scan-screenshot-v2) API
+#[derive(Serialize)]
+pub struct GetUrlScanScreenshot {
+    #[serde(skip)]
+    scan_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolution: Option<ScreenshotResolution>,
+}
+
+impl GetUrlScanScreenshot {
+    /// Retrieve the screenshot of the scan with the given id
+    pub fn new(scan_id: impl Into<String>) -> Self {
+        Self {
+            scan_id: scan_id.into(),
+            resolution: None,
+        }
+    }
+
+    /// Device of the screenshot, defaults to [ScreenshotResolution::Desktop]
+    pub fn resolution(mut self, resolution: ScreenshotResolution) -> Self {
+        self.resolution = Some(resolution);
+        self
+    }
+}
+
+/// Success response from the [Get screenshot](https://developers.cloudflare.com/api/operations/urlscanner-get-scan-screenshot-v2#Responses) API
+/// PNG image
+pub type GetUrlScanScreenshotRes = ScanOutcome<Bytes>;
+
+impl CfReqMeta for GetUrlScanScreenshot {
+    const METHOD: Method = Method::GET;
+    type Response = GetUrlScanScreenshotRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        Ok(ScanOutcome::Done(body))
+    }
+
+    fn handle_error(status: StatusCode, errors: Vec<CfApiErr>) -> Result<Self::Response> {
+        pending_on_not_found(status, errors)
+    }
+}
+
+impl CfReqAuth for GetUrlScanScreenshot {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/urlscanner/v2/screenshots/{}.png",
+            self.scan_id
+        )
+    }
+}
@@ -0,0 +1,673 @@
+//! Cloudflare Vectorize related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/vectorize/)
+use crate::{request::RawBody, CfReqAuth, CfReqMeta, CfSuccessRes, Error, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of dimensions of a vector index
+pub const VECTORIZE_MAX_DIMENSIONS: u32 = 1536;
+
+/// Distance metric of a vector index
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DistanceMetric {
+    /// Cosine similarity
+    Cosine,
+    /// Euclidean distance
+    Euclidean,
+    /// Dot product
+    DotProduct,
+}
+
+/// Configuration of a vector index
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct VectorizeIndexConfig {
+    /// Number of dimensions of the vectors
+    pub dimensions: u32,
+    /// Distance metric used to query the index
+    pub metric: DistanceMetric,
+}
+
+/// A vector index
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VectorizeIndex {
+    /// Name of the index
+    pub name: String,
+    /// Description of the index
+    pub description: Option<String>,
+    /// Configuration of the index
+    pub config: VectorizeIndexConfig,
+    /// RFC3339 timestamp of when the index was created
+    pub created_on: Option<String>,
+    /// RFC3339 timestamp of when the index was last modified
+    pub modified_on: Option<String>,
+}
+
+/// [Create Vectorize Index](https://developers.cloudflare.com/api/operations/vectorize-create-vectorize-index) API
+#[derive(Serialize)]
+pub struct CreateVectorizeIndex {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    config: VectorizeIndexConfig,
+}
+
+impl CreateVectorizeIndex {
+    /// Create an index named `name` for vectors of `dimensions` dimensions
+    /// Fails if `dimensions` is not between 1 and [VECTORIZE_MAX_DIMENSIONS]
+    pub fn new(name: impl Into<String>, dimensions: u32, metric: DistanceMetric) -> Result<Self> {
+        if !(1..=VECTORIZE_MAX_DIMENSIONS).contains(&dimensions) {
+            return Err(Error::InvalidRequest(format!(
+                "vector dimensions must be between 1 and {VECTORIZE_MAX_DIMENSIONS}"
+            )));
+        }
+        Ok(Self {
+            name: name.into(),
+            description: None,
+            config: VectorizeIndexConfig { dimensions, metric },
+        })
+    }
+
+    /// Description of the index
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Success response from the [Create Vectorize Index](https://developers.cloudflare.com/api/operations/vectorize-create-vectorize-index#Responses) API
+pub type CreateVectorizeIndexRes = VectorizeIndex;
+
+impl CfReqMeta for CreateVectorizeIndex {
+    const METHOD: Method = Method::POST;
+    type Response = CreateVectorizeIndexRes;
+}
+
+impl CfReqAuth for CreateVectorizeIndex {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/vectorize/v2/indexes")
+    }
+}
+
+/// [List Vectorize Indexes](https://developers.cloudflare.com/api/operations/vectorize-list-vectorize-indexes) API
+#[derive(Serialize, Default)]
+pub struct ListVectorizeIndexes {}
+
+/// Success response from the [List Vectorize Indexes](https://developers.cloudflare.com/api/operations/vectorize-list-vectorize-indexes#Responses) API
+pub type ListVectorizeIndexesRes = Vec<VectorizeIndex>;
+
+impl CfReqMeta for ListVectorizeIndexes {
+    const METHOD: Method = Method::GET;
+    type Response = ListVectorizeIndexesRes;
+}
+
+impl CfReqAuth for ListVectorizeIndexes {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/vectorize/v2/indexes")
+    }
+}
+
+/// [Get Vectorize Index](https://developers.cloudflare.com/api/operations/vectorize-get-vectorize-index) API
+#[derive(Serialize)]
+pub struct GetVectorizeIndex {
+    #[serde(skip)]
+    index_name: String,
+}
+
+impl GetVectorizeIndex {
+    /// Retrieve the index with the given name
+    pub fn new(index_name: impl Into<String>) -> Self {
+        Self {
+            index_name: index_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Vectorize Index](https://developers.cloudflare.com/api/operations/vectorize-get-vectorize-index#Responses) API
+pub type GetVectorizeIndexRes = VectorizeIndex;
+
+impl CfReqMeta for GetVectorizeIndex {
+    const METHOD: Method = Method::GET;
+    type Response = GetVectorizeIndexRes;
+}
+
+impl CfReqAuth for GetVectorizeIndex {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/vectorize/v2/indexes/{}",
+            self.index_name
+        )
+    }
+}
+
+/// [Delete Vectorize Index](https://developers.cloudflare.com/api/operations/vectorize-delete-vectorize-index) API
+#[derive(Serialize)]
+pub struct DeleteVectorizeIndex {
+    #[serde(skip)]
+    index_name: String,
+}
+
+impl DeleteVectorizeIndex {
+    /// Delete the index with the given name
+    pub fn new(index_name: impl Into<String>) -> Self {
+        Self {
+            index_name: index_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Vectorize Index](https://developers.cloudflare.com/api/operations/vectorize-delete-vectorize-index#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteVectorizeIndexRes;
+
+impl CfReqMeta for DeleteVectorizeIndex {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteVectorizeIndexRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(DeleteVectorizeIndexRes)
+    }
+}
+
+impl CfReqAuth for DeleteVectorizeIndex {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/vectorize/v2/indexes/{}",
+            self.index_name
+        )
+    }
+}
+
+/// Type of a metadata property indexed for filtering
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MetadataIndexType {
+    /// String values
+    String,
+    /// Number values
+    Number,
+    /// Boolean values
+    Boolean,
+}
+
+/// A metadata property indexed for filtering
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataIndex {
+    /// Name of the metadata property
+    pub property_name: String,
+    /// Type of the metadata property
+    pub index_type: MetadataIndexType,
+}
+
+/// Identifier of an asynchronous change to an index
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorizeMutation {
+    /// Identifier of the change
+    pub mutation_id: String,
+}
+
+/// [Create Metadata Index](https://developers.cloudflare.com/api/operations/vectorize-create-metadata-index) API
+#[derive(Serialize)]
+pub struct CreateMetadataIndex {
+    #[serde(skip)]
+    index_name: String,
+    #[serde(flatten)]
+    index: MetadataIndex,
+}
+
+impl CreateMetadataIndex {
+    /// Index the metadata property `property_name` of the index `index_name`
+    pub fn new(
+        index_name: impl Into<String>,
+        property_name: impl Into<String>,
+        index_type: MetadataIndexType,
+    ) -> Self {
+        Self {
+            index_name: index_name.into(),
+            index: MetadataIndex {
+                property_name: property_name.into(),
+                index_type,
+            },
+        }
+    }
+}
+
+/// Success response from the [Create Metadata Index](https://developers.cloudflare.com/api/operations/vectorize-create-metadata-index#Responses) API
+pub type CreateMetadataIndexRes = VectorizeMutation;
+
+impl CfReqMeta for CreateMetadataIndex {
+    const METHOD: Method = Method::POST;
+    type Response = CreateMetadataIndexRes;
+}
+
+impl CfReqAuth for CreateMetadataIndex {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/vectorize/v2/indexes/{}/metadata_index/create",
+            self.index_name
+        )
+    }
+}
+
+/// [List Metadata Indexes](https://developers.cloudflare.com/api/operations/vectorize-list-metadata-indexes) API
+#[derive(Serialize)]
+pub struct ListMetadataIndexes {
+    #[serde(skip)]
+    index_name: String,
+}
+
+impl ListMetadataIndexes {
+    /// List the metadata properties indexed by the index `index_name`
+    pub fn new(index_name: impl Into<String>) -> Self {
+        Self {
+            index_name: index_name.into(),
+        }
+    }
+}
+
+/// Success response from the [List Metadata Indexes](https://developers.cloudflare.com/api/operations/vectorize-list-metadata-indexes#Responses) API
+pub type ListMetadataIndexesRes = Vec<MetadataIndex>;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MetadataIndexes {
+    metadata_indexes: Vec<MetadataIndex>,
+}
+
+impl CfReqMeta for ListMetadataIndexes {
+    const METHOD: Method = Method::GET;
+    type Response = ListMetadataIndexesRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let res: CfSuccessRes<MetadataIndexes> = serde_json::from_slice(&body)?;
+        Ok(res.result.metadata_indexes)
+    }
+}
+
+impl CfReqAuth for ListMetadataIndexes {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/vectorize/v2/indexes/{}/metadata_index/list",
+            self.index_name
+        )
+    }
+}
+
+/// [Delete Metadata Index](https://developers.cloudflare.com/api/operations/vectorize-delete-metadata-index) API
+#[derive(Serialize)]
+pub struct DeleteMetadataIndex {
+    #[serde(skip)]
+    index_name: String,
+    #[serde(rename = "propertyName")]
+    property_name: String,
+}
+
+impl DeleteMetadataIndex {
+    /// Stop indexing the metadata property `property_name` of the index `index_name`
+    pub fn new(index_name: impl Into<String>, property_name: impl Into<String>) -> Self {
+        Self {
+            index_name: index_name.into(),
+            property_name: property_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Metadata Index](https://developers.cloudflare.com/api/operations/vectorize-delete-metadata-index#Responses) API
+pub type DeleteMetadataIndexRes = VectorizeMutation;
+
+impl CfReqMeta for DeleteMetadataIndex {
+    const METHOD: Method = Method::POST;
+    type Response = DeleteMetadataIndexRes;
+}
+
+impl CfReqAuth for DeleteMetadataIndex {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/vectorize/v2/indexes/{}/metadata_index/delete",
+            self.index_name
+        )
+    }
+}
+
+/// A vector stored in an index
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Vector {
+    /// Identifier of the vector
+    pub id: String,
+    /// Values of the vector, one per dimension of the index
+    #[serde(default)]
+    pub values: Vec<f32>,
+    /// Metadata of the vector
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Namespace of the vector
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+impl Vector {
+    /// A vector without metadata
+    pub fn new(id: impl Into<String>, values: Vec<f32>) -> Self {
+        Self {
+            id: id.into(),
+            values,
+            metadata: None,
+            namespace: None,
+        }
+    }
+
+    /// Metadata of the vector
+    pub fn metadata(mut self, metadata: serde_json::Map<String, serde_json::Value>) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Namespace of the vector
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+}
+
+/// Encode vectors as NDJSON, one vector per line
+fn ndjson(vectors: &[Vector]) -> Result<RawBody> {
+    let mut body = Vec::new();
+    for vector in vectors {
+        serde_json::to_writer(&mut body, vector)?;
+        body.push(b'\n');
+    }
+    Ok(RawBody::new("application/x-ndjson", body))
+}
+
+/// [Upsert Vectors](https://developers.cloudflare.com/api/operations/vectorize-upsert-vector) API
+/// Send with [crate::CloudflareAuth::send_auth_raw]
+pub struct VectorizeUpsert {
+    index_name: String,
+    vectors: Vec<Vector>,
+}
+
+impl VectorizeUpsert {
+    /// Insert `vectors` into the index `index_name`, replacing vectors with the same id
+    pub fn new(index_name: impl Into<String>, vectors: impl IntoIterator<Item = Vector>) -> Self {
+        Self {
+            index_name: index_name.into(),
+            vectors: vectors.into_iter().collect(),
+        }
+    }
+}
+
+impl TryFrom<VectorizeUpsert> for RawBody {
+    type Error = Error;
+
+    fn try_from(value: VectorizeUpsert) -> Result<Self> {
+        ndjson(&value.vectors)
+    }
+}
+
+/// Success response from the [Upsert Vectors](https://developers.cloudflare.com/api/operations/vectorize-upsert-vector#Responses) API
+pub type VectorizeUpsertRes = VectorizeMutation;
+
+impl CfReqMeta for VectorizeUpsert {
+    const METHOD: Method = Method::POST;
+    type Response = VectorizeUpsertRes;
+}
+
+impl CfReqAuth for VectorizeUpsert {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/vectorize/v2/indexes/{}/upsert",
+            self.index_name
+        )
+    }
+}
+
+/// [Insert Vectors](https://developers.cloudflare.com/api/operations/vectorize-insert-vector) API
+/// Send with [crate::CloudflareAuth::send_auth_raw]
+pub struct VectorizeInsert {
+    index_name: String,
+    vectors: Vec<Vector>,
+}
+
+impl VectorizeInsert {
+    /// Insert `vectors` into the index `index_name`, vectors with an existing id are ignored
+    pub fn new(index_name: impl Into<String>, vectors: impl IntoIterator<Item = Vector>) -> Self {
+        Self {
+            index_name: index_name.into(),
+            vectors: vectors.into_iter().collect(),
+        }
+    }
+}
+
+impl TryFrom<VectorizeInsert> for RawBody {
+    type Error = Error;
+
+    fn try_from(value: VectorizeInsert) -> Result<Self> {
+        ndjson(&value.vectors)
+    }
+}
+
+/// Success response from the [Insert Vectors](https://developers.cloudflare.com/api/operations/vectorize-insert-vector#Responses) API
+pub type VectorizeInsertRes = VectorizeMutation;
+
+impl CfReqMeta for VectorizeInsert {
+    const METHOD: Method = Method::POST;
+    type Response = VectorizeInsertRes;
+}
+
+impl CfReqAuth for VectorizeInsert {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/vectorize/v2/indexes/{}/insert",
+            self.index_name
+        )
+    }
+}
+
+/// Metadata returned with query matches
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReturnMetadata {
+    /// No metadata
+    None,
+    /// Only indexed metadata properties
+    Indexed,
+    /// All metadata
+    All,
+}
+
+/// [Query Vectors](https://developers.cloudflare.com/api/operations/vectorize-query-vector) API
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorizeQuery {
+    #[serde(skip)]
+    index_name: String,
+    vector: Vec<f32>,
+    top_k: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<serde_json::Value>,
+    return_values: bool,
+    return_metadata: ReturnMetadata,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    namespace: Option<String>,
+}
+
+impl VectorizeQuery {
+    /// Find the `top_k` vectors of the index `index_name` closest to `vector`
+    pub fn new(index_name: impl Into<String>, vector: Vec<f32>, top_k: u32) -> Self {
+        Self {
+            index_name: index_name.into(),
+            vector,
+            top_k,
+            filter: None,
+            return_values: false,
+            return_metadata: ReturnMetadata::None,
+            namespace: None,
+        }
+    }
+
+    /// Only match vectors whose metadata matches the filter (e.g `{"genre": {"$eq": "drama"}}`)
+    pub fn filter(mut self, filter: serde_json::Value) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Return the values of matched vectors
+    pub fn return_values(mut self) -> Self {
+        self.return_values = true;
+        self
+    }
+
+    /// Metadata to return with matched vectors
+    pub fn return_metadata(mut self, metadata: ReturnMetadata) -> Self {
+        self.return_metadata = metadata;
+        self
+    }
+
+    /// Only match vectors of the given namespace
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+}
+
+/// A vector matching a [VectorizeQuery]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VectorMatch {
+    /// Identifier of the vector
+    pub id: String,
+    /// Similarity score, its meaning depends on the [DistanceMetric] of the index
+    pub score: f32,
+    /// Values of the vector, if requested
+    pub values: Option<Vec<f32>>,
+    /// Metadata of the vector, if requested
+    pub metadata: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Namespace of the vector
+    pub namespace: Option<String>,
+}
+
+/// Success response from the [Query Vectors](https://developers.cloudflare.com/api/operations/vectorize-query-vector#Responses) API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VectorizeQueryRes {
+    /// Number of matches
+    pub count: u32,
+    /// Matches, closest first
+    pub matches: Vec<VectorMatch>,
+}
+
+impl CfReqMeta for VectorizeQuery {
+    const METHOD: Method = Method::POST;
+    type Response = VectorizeQueryRes;
+}
+
+impl CfReqAuth for VectorizeQuery {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/vectorize/v2/indexes/{}/query",
+            self.index_name
+        )
+    }
+}
+
+/// [Get Vectors By Identifier](https://developers.cloudflare.com/api/operations/vectorize-get-vectors-by-id) API
+#[derive(Serialize)]
+pub struct VectorizeGetByIds {
+    #[serde(skip)]
+    index_name: String,
+    ids: Vec<String>,
+}
+
+impl VectorizeGetByIds {
+    /// Retrieve the vectors of the index `index_name` with the given ids
+    pub fn new<I: Into<String>>(
+        index_name: impl Into<String>,
+        ids: impl IntoIterator<Item = I>,
+    ) -> Self {
+        Self {
+            index_name: index_name.into(),
+            ids: ids.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Success response from the [Get Vectors By Identifier](https://developers.cloudflare.com/api/operations/vectorize-get-vectors-by-id#Responses) API
+pub type VectorizeGetByIdsRes = Vec<Vector>;
+
+impl CfReqMeta for VectorizeGetByIds {
+    const METHOD: Method = Method::POST;
+    type Response = VectorizeGetByIdsRes;
+}
+
+impl CfReqAuth for VectorizeGetByIds {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/vectorize/v2/indexes/{}/get_by_ids",
+            self.index_name
+        )
+    }
+}
+
+/// [Delete Vectors By Identifier](https://developers.cloudflare.com/api/operations/vectorize-delete-vectors-by-id) API
+#[derive(Serialize)]
+pub struct VectorizeDeleteByIds {
+    #[serde(skip)]
+    index_name: String,
+    ids: Vec<String>,
+}
+
+impl VectorizeDeleteByIds {
+    /// Delete the vectors of the index `index_name` with the given ids
+    pub fn new<I: Into<String>>(
+        index_name: impl Into<String>,
+        ids: impl IntoIterator<Item = I>,
+    ) -> Self {
+        Self {
+            index_name: index_name.into(),
+            ids: ids.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Success response from the [Delete Vectors By Identifier](https://developers.cloudflare.com/api/operations/vectorize-delete-vectors-by-id#Responses) API
+pub type VectorizeDeleteByIdsRes = VectorizeMutation;
+
+impl CfReqMeta for VectorizeDeleteByIds {
+    const METHOD: Method = Method::POST;
+    type Response = VectorizeDeleteByIdsRes;
+}
+
+impl CfReqAuth for VectorizeDeleteByIds {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/vectorize/v2/indexes/{}/delete_by_ids",
+            self.index_name
+        )
+    }
+}
@@ -0,0 +1,698 @@
+//! Cloudflare Waiting Room related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/waiting-room/)
+//! All requests are zone scoped, send them with [crate::CloudflareZone::send_zone]
+use std::time::SystemTime;
+
+use crate::{request::rfc3339, CfReqMeta, CfReqZone, Error, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Minimum value of `new_users_per_minute` and `total_active_users`
+pub const WAITING_ROOM_MIN_USERS: u32 = 200;
+
+fn check_users(field: &str, users: u32) -> Result<u32> {
+    if users < WAITING_ROOM_MIN_USERS {
+        return Err(Error::InvalidRequest(format!(
+            "waiting room {field} must be at least {WAITING_ROOM_MIN_USERS}, got {users}"
+        )));
+    }
+    Ok(users)
+}
+
+/// Order in which queued users are let in
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum QueueingMethod {
+    /// First in, first out
+    Fifo,
+    /// Random order
+    Random,
+    /// Let every user in, the waiting room is disabled
+    Passthrough,
+    /// Reject every user with a static page
+    Reject,
+    /// A method unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// A waiting room
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WaitingRoom {
+    /// Waiting room ID
+    pub id: String,
+    /// Name of the waiting room
+    pub name: String,
+    /// Description of the waiting room
+    #[serde(default)]
+    pub description: String,
+    /// Host protected by the waiting room
+    pub host: String,
+    /// Path protected by the waiting room
+    pub path: String,
+    /// Users allowed on the site at once
+    pub total_active_users: u32,
+    /// Users let in per minute
+    pub new_users_per_minute: u32,
+    /// Order in which queued users are let in
+    pub queueing_method: QueueingMethod,
+    /// Minutes a user can leave the site before having to queue again
+    pub session_duration: u32,
+    /// Whether JSON requests get a JSON response while queued
+    #[serde(default)]
+    pub json_response_enabled: bool,
+    /// Custom HTML of the waiting room page
+    pub custom_page_html: Option<String>,
+    /// Whether the waiting room is suspended
+    #[serde(default)]
+    pub suspended: bool,
+    /// Whether every user is queued
+    #[serde(default)]
+    pub queue_all: bool,
+    /// Creation time
+    pub created_on: String,
+    /// Last update time
+    pub modified_on: String,
+}
+
+/// Settings of a waiting room, used by [CreateWaitingRoom] and [PatchWaitingRoom]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct WaitingRoomParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    host: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_active_users: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_users_per_minute: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    queueing_method: Option<QueueingMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_duration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    json_response_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_page_html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suspended: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    queue_all: Option<bool>,
+}
+
+impl WaitingRoomParams {
+    /// Name of the waiting room
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Host protected by the waiting room
+    pub fn host(mut self, host: impl Into<String>) -> Self {
+        self.host = Some(host.into());
+        self
+    }
+
+    /// Users allowed on the site at once, at least [WAITING_ROOM_MIN_USERS]
+    pub fn total_active_users(mut self, users: u32) -> Result<Self> {
+        self.total_active_users = Some(check_users("total_active_users", users)?);
+        Ok(self)
+    }
+
+    /// Users let in per minute, at least [WAITING_ROOM_MIN_USERS]
+    pub fn new_users_per_minute(mut self, users: u32) -> Result<Self> {
+        self.new_users_per_minute = Some(check_users("new_users_per_minute", users)?);
+        Ok(self)
+    }
+
+    /// Description of the waiting room
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Path protected by the waiting room, defaults to `/`
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Order in which queued users are let in
+    pub fn queueing_method(mut self, queueing_method: QueueingMethod) -> Self {
+        self.queueing_method = Some(queueing_method);
+        self
+    }
+
+    /// Minutes a user can leave the site before having to queue again, defaults to 5
+    pub fn session_duration(mut self, minutes: u32) -> Self {
+        self.session_duration = Some(minutes);
+        self
+    }
+
+    /// Whether JSON requests (`Accept: application/json`) get a JSON response while queued
+    pub fn json_response_enabled(mut self, enabled: bool) -> Self {
+        self.json_response_enabled = Some(enabled);
+        self
+    }
+
+    /// Custom HTML of the waiting room page
+    pub fn custom_page_html(mut self, html: impl Into<String>) -> Self {
+        self.custom_page_html = Some(html.into());
+        self
+    }
+
+    /// Whether the waiting room is suspended
+    pub fn suspended(mut self, suspended: bool) -> Self {
+        self.suspended = Some(suspended);
+        self
+    }
+
+    /// Whether every user is queued, regardless of the traffic
+    pub fn queue_all(mut self, queue_all: bool) -> Self {
+        self.queue_all = Some(queue_all);
+        self
+    }
+}
+
+/// [Create waiting room](https://developers.cloudflare.com/api/operations/waiting-room-create-waiting-room) API
+#[derive(Serialize)]
+pub struct CreateWaitingRoom {
+    #[serde(flatten)]
+    params: WaitingRoomParams,
+}
+
+impl CreateWaitingRoom {
+    /// Create the waiting room `name` protecting `host`,
+    /// letting in `new_users_per_minute` users up to `total_active_users`
+    pub fn new(
+        name: impl Into<String>,
+        host: impl Into<String>,
+        total_active_users: u32,
+        new_users_per_minute: u32,
+    ) -> Result<Self> {
+        let params = WaitingRoomParams::default()
+            .name(name)
+            .host(host)
+            .total_active_users(total_active_users)?
+            .new_users_per_minute(new_users_per_minute)?;
+        Ok(Self { params })
+    }
+
+    /// Create a waiting room with the given settings,
+    /// which must include a name, host, total active users and new users per minute
+    pub fn with_params(params: WaitingRoomParams) -> Self {
+        Self { params }
+    }
+}
+
+/// Success response from the [Create waiting room](https://developers.cloudflare.com/api/operations/waiting-room-create-waiting-room#Responses) API
+pub type CreateWaitingRoomRes = WaitingRoom;
+
+impl CfReqMeta for CreateWaitingRoom {
+    const METHOD: Method = Method::POST;
+    type Response = CreateWaitingRoomRes;
+}
+
+impl CfReqZone for CreateWaitingRoom {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/waiting_rooms")
+    }
+}
+
+/// [List waiting rooms](https://developers.cloudflare.com/api/operations/waiting-room-list-waiting-rooms) API
+#[derive(Serialize, Default)]
+pub struct ListWaitingRooms {}
+
+/// Success response from the [List waiting rooms](https://developers.cloudflare.com/api/operations/waiting-room-list-waiting-rooms#Responses) API
+pub type ListWaitingRoomsRes = Vec<WaitingRoom>;
+
+impl CfReqMeta for ListWaitingRooms {
+    const METHOD: Method = Method::GET;
+    type Response = ListWaitingRoomsRes;
+}
+
+impl CfReqZone for ListWaitingRooms {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/waiting_rooms")
+    }
+}
+
+/// [Waiting room details](https://developers.cloudflare.com/api/operations/waiting-room-waiting-room-details) API
+#[derive(Serialize)]
+pub struct GetWaitingRoom {
+    #[serde(skip)]
+    waiting_room_id: String,
+}
+
+impl GetWaitingRoom {
+    /// Get the waiting room `waiting_room_id`
+    pub fn new(waiting_room_id: impl Into<String>) -> Self {
+        Self {
+            waiting_room_id: waiting_room_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Waiting room details](https://developers.cloudflare.com/api/operations/waiting-room-waiting-room-details#Responses) API
+pub type GetWaitingRoomRes = WaitingRoom;
+
+impl CfReqMeta for GetWaitingRoom {
+    const METHOD: Method = Method::GET;
+    type Response = GetWaitingRoomRes;
+}
+
+impl CfReqZone for GetWaitingRoom {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/waiting_rooms/{}", self.waiting_room_id)
+    }
+}
+
+/// [Patch waiting room](https://developers.cloudflare.com/api/operations/waiting-room-patch-waiting-room) API
+/// Only the given settings are changed
+#[derive(Serialize)]
+pub struct PatchWaitingRoom {
+    #[serde(skip)]
+    waiting_room_id: String,
+    #[serde(flatten)]
+    params: WaitingRoomParams,
+}
+
+impl PatchWaitingRoom {
+    /// Change the settings of the waiting room `waiting_room_id`
+    pub fn new(waiting_room_id: impl Into<String>, params: WaitingRoomParams) -> Self {
+        Self {
+            waiting_room_id: waiting_room_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Patch waiting room](https://developers.cloudflare.com/api/operations/waiting-room-patch-waiting-room#Responses) API
+pub type PatchWaitingRoomRes = WaitingRoom;
+
+impl CfReqMeta for PatchWaitingRoom {
+    const METHOD: Method = Method::PATCH;
+    type Response = PatchWaitingRoomRes;
+}
+
+impl CfReqZone for PatchWaitingRoom {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/waiting_rooms/{}", self.waiting_room_id)
+    }
+}
+
+/// [Delete waiting room](https://developers.cloudflare.com/api/operations/waiting-room-delete-waiting-room) API
+#[derive(Serialize)]
+pub struct DeleteWaitingRoom {
+    #[serde(skip)]
+    waiting_room_id: String,
+}
+
+impl DeleteWaitingRoom {
+    /// Delete the waiting room `waiting_room_id` and its events
+    pub fn new(waiting_room_id: impl Into<String>) -> Self {
+        Self {
+            waiting_room_id: waiting_room_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeleteWaitingRoom {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqZone for DeleteWaitingRoom {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/waiting_rooms/{}", self.waiting_room_id)
+    }
+}
+
+/// A scheduled event of a waiting room, overriding its settings for a period of time
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WaitingRoomEvent {
+    /// Event ID
+    pub id: String,
+    /// Name of the event
+    pub name: String,
+    /// Description of the event
+    #[serde(default)]
+    pub description: String,
+    /// Start time of the event
+    pub event_start_time: String,
+    /// End time of the event
+    pub event_end_time: String,
+    /// Time users start being queued before the event
+    pub prequeue_start_time: Option<String>,
+    /// Whether users queued before the event are shuffled when it starts
+    #[serde(default)]
+    pub shuffle_at_event_start: bool,
+    /// Whether the event is suspended
+    #[serde(default)]
+    pub suspended: bool,
+    /// Override of [WaitingRoom::total_active_users]
+    pub total_active_users: Option<u32>,
+    /// Override of [WaitingRoom::new_users_per_minute]
+    pub new_users_per_minute: Option<u32>,
+    /// Override of [WaitingRoom::queueing_method]
+    pub queueing_method: Option<QueueingMethod>,
+    /// Override of [WaitingRoom::session_duration]
+    pub session_duration: Option<u32>,
+    /// Override of [WaitingRoom::custom_page_html]
+    pub custom_page_html: Option<String>,
+    /// Creation time
+    pub created_on: String,
+    /// Last update time
+    pub modified_on: String,
+}
+
+/// Settings of an event, used by [CreateWaitingRoomEvent] and [PatchWaitingRoomEvent]
+/// Settings that are not given are inherited from the waiting room
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct WaitingRoomEventParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_start_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prequeue_start_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shuffle_at_event_start: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suspended: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_active_users: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_users_per_minute: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    queueing_method: Option<QueueingMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_duration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_page_html: Option<String>,
+}
+
+impl WaitingRoomEventParams {
+    /// Name of the event
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Period of the event
+    pub fn period(mut self, start: SystemTime, end: SystemTime) -> Self {
+        self.event_start_time = Some(rfc3339(start));
+        self.event_end_time = Some(rfc3339(end));
+        self
+    }
+
+    /// Start queueing users at `at`, before the event starts
+    pub fn prequeue_start(mut self, at: SystemTime) -> Self {
+        self.prequeue_start_time = Some(rfc3339(at));
+        self
+    }
+
+    /// Whether users queued before the event are shuffled when it starts
+    pub fn shuffle_at_event_start(mut self, shuffle: bool) -> Self {
+        self.shuffle_at_event_start = Some(shuffle);
+        self
+    }
+
+    /// Description of the event
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the event is suspended
+    pub fn suspended(mut self, suspended: bool) -> Self {
+        self.suspended = Some(suspended);
+        self
+    }
+
+    /// Users allowed on the site at once during the event, at least [WAITING_ROOM_MIN_USERS]
+    pub fn total_active_users(mut self, users: u32) -> Result<Self> {
+        self.total_active_users = Some(check_users("total_active_users", users)?);
+        Ok(self)
+    }
+
+    /// Users let in per minute during the event, at least [WAITING_ROOM_MIN_USERS]
+    pub fn new_users_per_minute(mut self, users: u32) -> Result<Self> {
+        self.new_users_per_minute = Some(check_users("new_users_per_minute", users)?);
+        Ok(self)
+    }
+
+    /// Order in which queued users are let in during the event
+    pub fn queueing_method(mut self, queueing_method: QueueingMethod) -> Self {
+        self.queueing_method = Some(queueing_method);
+        self
+    }
+
+    /// Session duration in minutes during the event
+    pub fn session_duration(mut self, minutes: u32) -> Self {
+        self.session_duration = Some(minutes);
+        self
+    }
+
+    /// Custom HTML of the waiting room page during the event
+    pub fn custom_page_html(mut self, html: impl Into<String>) -> Self {
+        self.custom_page_html = Some(html.into());
+        self
+    }
+}
+
+/// [Create event](https://developers.cloudflare.com/api/operations/waiting-room-create-event) API
+#[derive(Serialize)]
+pub struct CreateWaitingRoomEvent {
+    #[serde(skip)]
+    waiting_room_id: String,
+    #[serde(flatten)]
+    params: WaitingRoomEventParams,
+}
+
+impl CreateWaitingRoomEvent {
+    /// Schedule the event `name` from `start` to `end` in the waiting room `waiting_room_id`
+    pub fn new(
+        waiting_room_id: impl Into<String>,
+        name: impl Into<String>,
+        start: SystemTime,
+        end: SystemTime,
+    ) -> Self {
+        Self::with_params(
+            waiting_room_id,
+            WaitingRoomEventParams::default()
+                .name(name)
+                .period(start, end),
+        )
+    }
+
+    /// Schedule an event with the given settings, which must include a name and period
+    pub fn with_params(waiting_room_id: impl Into<String>, params: WaitingRoomEventParams) -> Self {
+        Self {
+            waiting_room_id: waiting_room_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Create event](https://developers.cloudflare.com/api/operations/waiting-room-create-event#Responses) API
+pub type CreateWaitingRoomEventRes = WaitingRoomEvent;
+
+impl CfReqMeta for CreateWaitingRoomEvent {
+    const METHOD: Method = Method::POST;
+    type Response = CreateWaitingRoomEventRes;
+}
+
+impl CfReqZone for CreateWaitingRoomEvent {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!(
+            "zones/{zone_id}/waiting_rooms/{}/events",
+            self.waiting_room_id
+        )
+    }
+}
+
+/// [List events](https://developers.cloudflare.com/api/operations/waiting-room-list-events) API
+#[derive(Serialize)]
+pub struct ListWaitingRoomEvents {
+    #[serde(skip)]
+    waiting_room_id: String,
+}
+
+impl ListWaitingRoomEvents {
+    /// List the events of the waiting room `waiting_room_id`
+    pub fn new(waiting_room_id: impl Into<String>) -> Self {
+        Self {
+            waiting_room_id: waiting_room_id.into(),
+        }
+    }
+}
+
+/// Success response from the [List events](https://developers.cloudflare.com/api/operations/waiting-room-list-events#Responses) API
+pub type ListWaitingRoomEventsRes = Vec<WaitingRoomEvent>;
+
+impl CfReqMeta for ListWaitingRoomEvents {
+    const METHOD: Method = Method::GET;
+    type Response = ListWaitingRoomEventsRes;
+}
+
+impl CfReqZone for ListWaitingRoomEvents {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!(
+            "zones/{zone_id}/waiting_rooms/{}/events",
+            self.waiting_room_id
+        )
+    }
+}
+
+/// [Event details](https://developers.cloudflare.com/api/operations/waiting-room-event-details) API
+#[derive(Serialize)]
+pub struct GetWaitingRoomEvent {
+    #[serde(skip)]
+    waiting_room_id: String,
+    #[serde(skip)]
+    event_id: String,
+}
+
+impl GetWaitingRoomEvent {
+    /// Get the event `event_id` of the waiting room `waiting_room_id`
+    pub fn new(waiting_room_id: impl Into<String>, event_id: impl Into<String>) -> Self {
+        Self {
+            waiting_room_id: waiting_room_id.into(),
+            event_id: event_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Event details](https://developers.cloudflare.com/api/operations/waiting-room-event-details#Responses) API
+pub type GetWaitingRoomEventRes = WaitingRoomEvent;
+
+impl CfReqMeta for GetWaitingRoomEvent {
+    const METHOD: Method = Method::GET;
+    type Response = GetWaitingRoomEventRes;
+}
+
+impl CfReqZone for GetWaitingRoomEvent {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!(
+            "zones/{zone_id}/waiting_rooms/{}/events/{}",
+            self.waiting_room_id, self.event_id
+        )
+    }
+}
+
+/// [Patch event](https://developers.cloudflare.com/api/operations/waiting-room-patch-event) API
+/// Only the given settings are changed
+#[derive(Serialize)]
+pub struct PatchWaitingRoomEvent {
+    #[serde(skip)]
+    waiting_room_id: String,
+    #[serde(skip)]
+    event_id: String,
+    #[serde(flatten)]
+    params: WaitingRoomEventParams,
+}
+
+impl PatchWaitingRoomEvent {
+    /// Change the settings of the event `event_id` of the waiting room `waiting_room_id`
+    pub fn new(
+        waiting_room_id: impl Into<String>,
+        event_id: impl Into<String>,
+        params: WaitingRoomEventParams,
+    ) -> Self {
+        Self {
+            waiting_room_id: waiting_room_id.into(),
+            event_id: event_id.into(),
+            params,
+        }
+    }
+}
+
+/// Success response from the [Patch event](https://developers.cloudflare.com/api/operations/waiting-room-patch-event#Responses) API
+pub type PatchWaitingRoomEventRes = WaitingRoomEvent;
+
+impl CfReqMeta for PatchWaitingRoomEvent {
+    const METHOD: Method = Method::PATCH;
+    type Response = PatchWaitingRoomEventRes;
+}
+
+impl CfReqZone for PatchWaitingRoomEvent {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!(
+            "zones/{zone_id}/waiting_rooms/{}/events/{}",
+            self.waiting_room_id, self.event_id
+        )
+    }
+}
+
+/// [Delete event](https://developers.cloudflare.com/api/operations/waiting-room-delete-event) API
+#[derive(Serialize)]
+pub struct DeleteWaitingRoomEvent {
+    #[serde(skip)]
+    waiting_room_id: String,
+    #[serde(skip)]
+    event_id: String,
+}
+
+impl DeleteWaitingRoomEvent {
+    /// Delete the event `event_id` of the waiting room `waiting_room_id`
+    pub fn new(waiting_room_id: impl Into<String>, event_id: impl Into<String>) -> Self {
+        Self {
+            waiting_room_id: waiting_room_id.into(),
+            event_id: event_id.into(),
+        }
+    }
+}
+
+impl CfReqMeta for DeleteWaitingRoomEvent {
+    const METHOD: Method = Method::DELETE;
+    type Response = ();
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result only contains the id
+        Ok(())
+    }
+}
+
+impl CfReqZone for DeleteWaitingRoomEvent {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!(
+            "zones/{zone_id}/waiting_rooms/{}/events/{}",
+            self.waiting_room_id, self.event_id
+        )
+    }
+}
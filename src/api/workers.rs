@@ -0,0 +1,2067 @@
+//! Cloudflare Workers related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/workers/)
+use std::fmt;
+
+use crate::{
+    request::encode_segment, CfReqAuth, CfReqMeta, CfReqZone, CfSuccessResWithInfo, CursorInfo,
+    Error, Result,
+};
+use bytes::Bytes;
+use reqwest::{
+    multipart::{Form, Part},
+    Method,
+};
+use serde::{Deserialize, Serialize};
+
+/// Type of a module uploaded with [UploadScript]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModuleType {
+    /// ES module
+    EsModule,
+    /// CommonJS module
+    CommonJs,
+    /// WebAssembly module
+    Wasm,
+    /// Text module, imported as a string
+    Text,
+    /// Data module, imported as an ArrayBuffer
+    Data,
+    /// Python module
+    Python,
+}
+
+impl ModuleType {
+    /// Content type of the module's part
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::EsModule => "application/javascript+module",
+            Self::CommonJs => "application/javascript",
+            Self::Wasm => "application/wasm",
+            Self::Text => "text/plain",
+            Self::Data => "application/octet-stream",
+            Self::Python => "text/x-python",
+        }
+    }
+}
+
+/// A binding exposed to a worker
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Binding {
+    /// A KV namespace
+    KvNamespace {
+        /// Name of the binding
+        name: String,
+        /// Identifier of the namespace
+        namespace_id: String,
+    },
+    /// An R2 bucket
+    R2Bucket {
+        /// Name of the binding
+        name: String,
+        /// Name of the bucket
+        bucket_name: String,
+    },
+    /// A D1 database
+    D1 {
+        /// Name of the binding
+        name: String,
+        /// Identifier of the database
+        id: String,
+    },
+    /// A plain text environment variable
+    PlainText {
+        /// Name of the binding
+        name: String,
+        /// Value of the variable
+        text: String,
+    },
+    /// A secret environment variable
+    SecretText {
+        /// Name of the binding
+        name: String,
+        /// Value of the secret, not returned by cloudflare
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        text: String,
+    },
+    /// A queue producer
+    Queue {
+        /// Name of the binding
+        name: String,
+        /// Name of the queue
+        queue_name: String,
+    },
+    /// Another worker
+    Service {
+        /// Name of the binding
+        name: String,
+        /// Name of the worker
+        service: String,
+        /// Environment of the worker
+        #[serde(skip_serializing_if = "Option::is_none")]
+        environment: Option<String>,
+    },
+}
+
+impl fmt::Debug for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::KvNamespace { name, namespace_id } => f
+                .debug_struct("KvNamespace")
+                .field("name", name)
+                .field("namespace_id", namespace_id)
+                .finish(),
+            Self::R2Bucket { name, bucket_name } => f
+                .debug_struct("R2Bucket")
+                .field("name", name)
+                .field("bucket_name", bucket_name)
+                .finish(),
+            Self::D1 { name, id } => f
+                .debug_struct("D1")
+                .field("name", name)
+                .field("id", id)
+                .finish(),
+            Self::PlainText { name, text } => f
+                .debug_struct("PlainText")
+                .field("name", name)
+                .field("text", text)
+                .finish(),
+            Self::SecretText { name, .. } => f
+                .debug_struct("SecretText")
+                .field("name", name)
+                .field("text", &"<redacted>")
+                .finish(),
+            Self::Queue { name, queue_name } => f
+                .debug_struct("Queue")
+                .field("name", name)
+                .field("queue_name", queue_name)
+                .finish(),
+            Self::Service {
+                name,
+                service,
+                environment,
+            } => f
+                .debug_struct("Service")
+                .field("name", name)
+                .field("service", service)
+                .field("environment", environment)
+                .finish(),
+        }
+    }
+}
+
+/// A worker script
+#[derive(Serialize, Deserialize)]
+pub struct Script {
+    /// Name of the script
+    pub id: String,
+    /// Hash of the script's content
+    pub etag: Option<String>,
+    /// RFC3339 timestamp of when the script was created
+    pub created_on: Option<String>,
+    /// RFC3339 timestamp of when the script was last modified
+    pub modified_on: Option<String>,
+    /// Usage model of the script (e.g `standard`)
+    pub usage_model: Option<String>,
+    /// Compatibility date of the script
+    pub compatibility_date: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ScriptMetadata {
+    main_module: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    bindings: Vec<Binding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compatibility_date: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    compatibility_flags: Vec<String>,
+}
+
+/// [Upload Worker Module](https://developers.cloudflare.com/api/operations/worker-script-upload-worker-module) API
+/// Sent via [crate::CloudflareAuth::send_auth_multipart]
+pub struct UploadScript {
+    script_name: String,
+    metadata: ScriptMetadata,
+    modules: Vec<(String, Bytes, ModuleType)>,
+}
+
+impl UploadScript {
+    /// Create or replace the script `script_name`
+    /// `main_module` is the name of the module containing the entrypoint
+    pub fn new(script_name: impl Into<String>, main_module: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+            metadata: ScriptMetadata {
+                main_module: main_module.into(),
+                bindings: Vec::new(),
+                compatibility_date: None,
+                compatibility_flags: Vec::new(),
+            },
+            modules: Vec::new(),
+        }
+    }
+
+    /// Add a module to the script
+    pub fn add_module(
+        mut self,
+        name: impl Into<String>,
+        contents: impl Into<Bytes>,
+        module_type: ModuleType,
+    ) -> Self {
+        self.modules
+            .push((name.into(), contents.into(), module_type));
+        self
+    }
+
+    /// Add modules given as `(name, contents, type)` to the script
+    pub fn modules(
+        mut self,
+        modules: impl IntoIterator<Item = (String, Bytes, ModuleType)>,
+    ) -> Self {
+        self.modules.extend(modules);
+        self
+    }
+
+    /// Add a binding to the script
+    pub fn add_binding(mut self, binding: Binding) -> Self {
+        self.metadata.bindings.push(binding);
+        self
+    }
+
+    /// Compatibility date of the script (e.g `2024-09-23`)
+    pub fn compatibility_date(mut self, compatibility_date: impl Into<String>) -> Self {
+        self.metadata.compatibility_date = Some(compatibility_date.into());
+        self
+    }
+
+    /// Add a compatibility flag to the script (e.g `nodejs_compat`)
+    pub fn add_compatibility_flag(mut self, flag: impl Into<String>) -> Self {
+        self.metadata.compatibility_flags.push(flag.into());
+        self
+    }
+}
+
+impl TryFrom<UploadScript> for Form {
+    type Error = Error;
+
+    fn try_from(value: UploadScript) -> Result<Self> {
+        let metadata =
+            Part::text(serde_json::to_string(&value.metadata)?).mime_str("application/json")?;
+        let mut form = Form::new().part("metadata", metadata);
+        for (name, contents, module_type) in value.modules {
+            let len = contents.len() as u64;
+            let part = Part::stream_with_length(contents, len)
+                .file_name(name.clone())
+                .mime_str(module_type.content_type())?;
+            form = form.part(name, part);
+        }
+        Ok(form)
+    }
+}
+
+/// Success response from the [Upload Worker Module](https://developers.cloudflare.com/api/operations/worker-script-upload-worker-module#Responses) API
+pub type UploadScriptRes = Script;
+
+impl CfReqMeta for UploadScript {
+    const METHOD: Method = Method::PUT;
+    type Response = UploadScriptRes;
+}
+
+impl CfReqAuth for UploadScript {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/workers/scripts/{}", self.script_name)
+    }
+}
+
+/// [List Workers](https://developers.cloudflare.com/api/operations/worker-script-list-workers) API
+#[derive(Serialize, Default)]
+pub struct ListScripts {}
+
+/// Success response from the [List Workers](https://developers.cloudflare.com/api/operations/worker-script-list-workers#Responses) API
+pub type ListScriptsRes = Vec<Script>;
+
+impl CfReqMeta for ListScripts {
+    const METHOD: Method = Method::GET;
+    type Response = ListScriptsRes;
+}
+
+impl CfReqAuth for ListScripts {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/workers/scripts")
+    }
+}
+
+/// [Get script content](https://developers.cloudflare.com/api/operations/worker-script-get-content) API
+/// returning the script as is, a multipart body if the script has multiple modules
+#[derive(Serialize)]
+pub struct GetScriptContent {
+    #[serde(skip)]
+    script_name: String,
+}
+
+impl GetScriptContent {
+    /// Download the content of a script
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+        }
+    }
+}
+
+/// Raw content of the script
+pub type GetScriptContentRes = Bytes;
+
+impl CfReqMeta for GetScriptContent {
+    const METHOD: Method = Method::GET;
+    type Response = GetScriptContentRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        Ok(body)
+    }
+}
+
+impl CfReqAuth for GetScriptContent {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/content/v2",
+            self.script_name
+        )
+    }
+}
+
+/// [Delete Worker](https://developers.cloudflare.com/api/operations/worker-script-delete-worker) API
+#[derive(Serialize)]
+pub struct DeleteScript {
+    #[serde(skip)]
+    script_name: String,
+    #[serde(skip)]
+    force: bool,
+}
+
+impl DeleteScript {
+    /// Delete a script
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+            force: false,
+        }
+    }
+
+    /// Delete the script even if it is bound to Durable Objects
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+}
+
+/// Success response from the [Delete Worker](https://developers.cloudflare.com/api/operations/worker-script-delete-worker#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteScriptRes;
+
+impl CfReqMeta for DeleteScript {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteScriptRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(DeleteScriptRes)
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        if self.force {
+            vec![("force", "true".into())]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl CfReqAuth for DeleteScript {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/workers/scripts/{}", self.script_name)
+    }
+}
+
+const CRON_FIELD_RANGES: [(&str, u32, u32); 5] = [
+    ("minute", 0, 59),
+    ("hour", 0, 23),
+    ("day of month", 1, 31),
+    ("month", 1, 12),
+    ("day of week", 0, 7),
+];
+
+// catches obviously broken expressions, cloudflare does the full validation
+fn check_cron(cron: &str) -> Result<()> {
+    let invalid =
+        |reason: String| Error::InvalidRequest(format!("invalid cron {cron:?}: {reason}"));
+    let fields: Vec<_> = cron.split_whitespace().collect();
+    if fields.len() != CRON_FIELD_RANGES.len() {
+        return Err(invalid(format!("expected 5 fields, got {}", fields.len())));
+    }
+    for (field, (field_name, min, max)) in fields.into_iter().zip(CRON_FIELD_RANGES) {
+        for item in field.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, Some(step)),
+                None => (item, None),
+            };
+            if let Some(step) = step {
+                if !step.parse::<u32>().is_ok_and(|step| step > 0) {
+                    return Err(invalid(format!("invalid step {step:?} in {field_name}")));
+                }
+            }
+            if range == "*" {
+                continue;
+            }
+            for value in range.split('-') {
+                if value.is_empty() {
+                    return Err(invalid(format!("empty value in {field_name}")));
+                }
+                // names (MON, JAN) and L/W/# modifiers are left to cloudflare
+                if let Ok(value) = value.parse::<u32>() {
+                    if !(min..=max).contains(&value) {
+                        return Err(invalid(format!(
+                            "{field_name} must be between {min} and {max}, got {value}"
+                        )));
+                    }
+                } else if !value
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '#' || c == '?')
+                {
+                    return Err(invalid(format!("invalid value {value:?} in {field_name}")));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A cron trigger of a worker
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CronTrigger {
+    /// Cron expression (e.g `*/5 * * * *`)
+    pub cron: String,
+    /// RFC3339 timestamp of when the trigger was created
+    #[serde(skip_serializing)]
+    pub created_on: Option<String>,
+    /// RFC3339 timestamp of when the trigger was last modified
+    #[serde(skip_serializing)]
+    pub modified_on: Option<String>,
+}
+
+/// Cron triggers of a worker
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CronTriggers {
+    /// Cron triggers
+    pub schedules: Vec<CronTrigger>,
+}
+
+/// [Get Cron Triggers](https://developers.cloudflare.com/api/operations/worker-cron-trigger-get-cron-triggers) API
+#[derive(Serialize)]
+pub struct GetCronTriggers {
+    #[serde(skip)]
+    script_name: String,
+}
+
+impl GetCronTriggers {
+    /// Retrieve the cron triggers of a script
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Cron Triggers](https://developers.cloudflare.com/api/operations/worker-cron-trigger-get-cron-triggers#Responses) API
+pub type GetCronTriggersRes = CronTriggers;
+
+impl CfReqMeta for GetCronTriggers {
+    const METHOD: Method = Method::GET;
+    type Response = GetCronTriggersRes;
+}
+
+impl CfReqAuth for GetCronTriggers {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/schedules",
+            self.script_name
+        )
+    }
+}
+
+/// [Update Cron Triggers](https://developers.cloudflare.com/api/operations/worker-cron-trigger-update-cron-triggers) API
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct PutCronTriggers {
+    #[serde(skip)]
+    script_name: String,
+    schedules: Vec<CronTrigger>,
+}
+
+impl PutCronTriggers {
+    /// Replace the cron triggers of a script, add triggers with [Self::add_cron]
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+            schedules: Vec::new(),
+        }
+    }
+
+    /// Add a cron trigger
+    /// Fails if the expression is obviously invalid
+    pub fn add_cron(mut self, cron: impl Into<String>) -> Result<Self> {
+        let cron = cron.into();
+        check_cron(&cron)?;
+        self.schedules.push(CronTrigger {
+            cron,
+            created_on: None,
+            modified_on: None,
+        });
+        Ok(self)
+    }
+}
+
+/// Success response from the [Update Cron Triggers](https://developers.cloudflare.com/api/operations/worker-cron-trigger-update-cron-triggers#Responses) API
+pub type PutCronTriggersRes = CronTriggers;
+
+impl CfReqMeta for PutCronTriggers {
+    const METHOD: Method = Method::PUT;
+    type Response = PutCronTriggersRes;
+}
+
+impl CfReqAuth for PutCronTriggers {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/schedules",
+            self.script_name
+        )
+    }
+}
+
+/// A secret of a worker, without its value
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Secret {
+    /// Name of the secret
+    pub name: String,
+    /// Type of the secret, `secret_text`
+    #[serde(rename = "type")]
+    pub secret_type: String,
+}
+
+/// [Add script secret](https://developers.cloudflare.com/api/operations/worker-script-put-secret) API
+#[derive(Serialize)]
+pub struct PutSecret {
+    #[serde(skip)]
+    script_name: String,
+    name: String,
+    text: String,
+    #[serde(rename = "type")]
+    secret_type: &'static str,
+}
+
+impl PutSecret {
+    /// Create or replace the secret `name` of a script
+    pub fn new(
+        script_name: impl Into<String>,
+        name: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        Self {
+            script_name: script_name.into(),
+            name: name.into(),
+            text: text.into(),
+            secret_type: "secret_text",
+        }
+    }
+}
+
+impl fmt::Debug for PutSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PutSecret")
+            .field("script_name", &self.script_name)
+            .field("name", &self.name)
+            .field("text", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Success response from the [Add script secret](https://developers.cloudflare.com/api/operations/worker-script-put-secret#Responses) API
+pub type PutSecretRes = Secret;
+
+impl CfReqMeta for PutSecret {
+    const METHOD: Method = Method::PUT;
+    type Response = PutSecretRes;
+}
+
+impl CfReqAuth for PutSecret {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/secrets",
+            self.script_name
+        )
+    }
+}
+
+/// [List script secrets](https://developers.cloudflare.com/api/operations/worker-script-list-secrets) API
+#[derive(Serialize)]
+pub struct ListSecrets {
+    #[serde(skip)]
+    script_name: String,
+}
+
+impl ListSecrets {
+    /// List the secrets of a script
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+        }
+    }
+}
+
+/// Success response from the [List script secrets](https://developers.cloudflare.com/api/operations/worker-script-list-secrets#Responses) API
+pub type ListSecretsRes = Vec<Secret>;
+
+impl CfReqMeta for ListSecrets {
+    const METHOD: Method = Method::GET;
+    type Response = ListSecretsRes;
+}
+
+impl CfReqAuth for ListSecrets {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/secrets",
+            self.script_name
+        )
+    }
+}
+
+/// [Delete script secret](https://developers.cloudflare.com/api/operations/worker-script-delete-secret) API
+#[derive(Serialize)]
+pub struct DeleteSecret {
+    #[serde(skip)]
+    script_name: String,
+    #[serde(skip)]
+    name: String,
+}
+
+impl DeleteSecret {
+    /// Delete the secret `name` of a script
+    pub fn new(script_name: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+            name: name.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete script secret](https://developers.cloudflare.com/api/operations/worker-script-delete-secret#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteSecretRes;
+
+impl CfReqMeta for DeleteSecret {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteSecretRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(DeleteSecretRes)
+    }
+}
+
+impl CfReqAuth for DeleteSecret {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/secrets/{}",
+            self.script_name,
+            encode_segment(&self.name)
+        )
+    }
+}
+
+/// The workers.dev subdomain of an account
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WorkersSubdomain {
+    /// Subdomain, scripts are served at `<script>.<subdomain>.workers.dev`
+    pub subdomain: String,
+}
+
+/// [Get Subdomain](https://developers.cloudflare.com/api/operations/worker-subdomain-get-subdomain) API
+#[derive(Serialize, Default)]
+pub struct GetWorkersSubdomain {}
+
+/// Success response from the [Get Subdomain](https://developers.cloudflare.com/api/operations/worker-subdomain-get-subdomain#Responses) API
+pub type GetWorkersSubdomainRes = WorkersSubdomain;
+
+impl CfReqMeta for GetWorkersSubdomain {
+    const METHOD: Method = Method::GET;
+    type Response = GetWorkersSubdomainRes;
+}
+
+impl CfReqAuth for GetWorkersSubdomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/workers/subdomain")
+    }
+}
+
+/// [Create Subdomain](https://developers.cloudflare.com/api/operations/worker-subdomain-create-subdomain) API
+#[derive(Serialize)]
+pub struct CreateWorkersSubdomain {
+    subdomain: String,
+}
+
+impl CreateWorkersSubdomain {
+    /// Set the workers.dev subdomain of the account
+    pub fn new(subdomain: impl Into<String>) -> Self {
+        Self {
+            subdomain: subdomain.into(),
+        }
+    }
+}
+
+/// Success response from the [Create Subdomain](https://developers.cloudflare.com/api/operations/worker-subdomain-create-subdomain#Responses) API
+pub type CreateWorkersSubdomainRes = WorkersSubdomain;
+
+impl CfReqMeta for CreateWorkersSubdomain {
+    const METHOD: Method = Method::PUT;
+    type Response = CreateWorkersSubdomainRes;
+}
+
+impl CfReqAuth for CreateWorkersSubdomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/workers/subdomain")
+    }
+}
+
+/// Whether a script is served on the workers.dev subdomain
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ScriptSubdomain {
+    /// Whether the script is served at `<script>.<subdomain>.workers.dev`
+    pub enabled: bool,
+    /// Whether preview URLs of the script's versions are served
+    #[serde(default)]
+    pub previews_enabled: bool,
+}
+
+/// [Get Worker subdomain](https://developers.cloudflare.com/api/operations/worker-script-get-subdomain) API
+#[derive(Serialize)]
+pub struct GetScriptSubdomain {
+    #[serde(skip)]
+    script_name: String,
+}
+
+impl GetScriptSubdomain {
+    /// Retrieve whether a script is served on the workers.dev subdomain
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Worker subdomain](https://developers.cloudflare.com/api/operations/worker-script-get-subdomain#Responses) API
+pub type GetScriptSubdomainRes = ScriptSubdomain;
+
+impl CfReqMeta for GetScriptSubdomain {
+    const METHOD: Method = Method::GET;
+    type Response = GetScriptSubdomainRes;
+}
+
+impl CfReqAuth for GetScriptSubdomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/subdomain",
+            self.script_name
+        )
+    }
+}
+
+/// [Post Worker subdomain](https://developers.cloudflare.com/api/operations/worker-script-post-subdomain) API
+#[derive(Serialize)]
+pub struct SetScriptSubdomain {
+    #[serde(skip)]
+    script_name: String,
+    #[serde(flatten)]
+    subdomain: ScriptSubdomain,
+}
+
+impl SetScriptSubdomain {
+    /// Enable or disable serving a script on the workers.dev subdomain
+    pub fn new(script_name: impl Into<String>, enabled: bool, previews_enabled: bool) -> Self {
+        Self {
+            script_name: script_name.into(),
+            subdomain: ScriptSubdomain {
+                enabled,
+                previews_enabled,
+            },
+        }
+    }
+}
+
+/// Success response from the [Post Worker subdomain](https://developers.cloudflare.com/api/operations/worker-script-post-subdomain#Responses) API
+pub type SetScriptSubdomainRes = ScriptSubdomain;
+
+impl CfReqMeta for SetScriptSubdomain {
+    const METHOD: Method = Method::POST;
+    type Response = SetScriptSubdomainRes;
+}
+
+impl CfReqAuth for SetScriptSubdomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/subdomain",
+            self.script_name
+        )
+    }
+}
+
+/// A custom domain serving a worker
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WorkerDomain {
+    /// Identifier of the domain
+    pub id: String,
+    /// Hostname serving the worker
+    pub hostname: String,
+    /// Name of the worker
+    pub service: String,
+    /// Environment of the worker
+    pub environment: Option<String>,
+    /// Zone the hostname belongs to
+    pub zone_id: String,
+    /// Name of the zone the hostname belongs to
+    pub zone_name: Option<String>,
+}
+
+/// [Attach to Domain](https://developers.cloudflare.com/api/operations/worker-domain-attach-to-domain) API
+#[derive(Serialize)]
+pub struct AttachWorkerDomain {
+    hostname: String,
+    zone_id: String,
+    service: String,
+    environment: String,
+}
+
+impl AttachWorkerDomain {
+    /// Serve the worker `service` on `hostname`, part of the zone `zone_id`
+    pub fn new(
+        hostname: impl Into<String>,
+        zone_id: impl Into<String>,
+        service: impl Into<String>,
+    ) -> Self {
+        Self {
+            hostname: hostname.into(),
+            zone_id: zone_id.into(),
+            service: service.into(),
+            environment: "production".into(),
+        }
+    }
+
+    /// Environment of the worker (defaults to `production`)
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = environment.into();
+        self
+    }
+}
+
+/// Success response from the [Attach to Domain](https://developers.cloudflare.com/api/operations/worker-domain-attach-to-domain#Responses) API
+pub type AttachWorkerDomainRes = WorkerDomain;
+
+impl CfReqMeta for AttachWorkerDomain {
+    const METHOD: Method = Method::PUT;
+    type Response = AttachWorkerDomainRes;
+}
+
+impl CfReqAuth for AttachWorkerDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/workers/domains")
+    }
+}
+
+/// [List Domains](https://developers.cloudflare.com/api/operations/worker-domain-list-domains) API
+#[derive(Serialize, Default)]
+pub struct ListWorkerDomains {
+    hostname: Option<String>,
+    service: Option<String>,
+    zone_id: Option<String>,
+    environment: Option<String>,
+}
+
+impl ListWorkerDomains {
+    /// Only return the given hostname
+    pub fn hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostname = Some(hostname.into());
+        self
+    }
+
+    /// Only return domains serving the given worker
+    pub fn service(mut self, service: impl Into<String>) -> Self {
+        self.service = Some(service.into());
+        self
+    }
+
+    /// Only return domains in the given zone
+    pub fn zone_id(mut self, zone_id: impl Into<String>) -> Self {
+        self.zone_id = Some(zone_id.into());
+        self
+    }
+
+    /// Only return domains serving the given environment
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+}
+
+/// Success response from the [List Domains](https://developers.cloudflare.com/api/operations/worker-domain-list-domains#Responses) API
+pub type ListWorkerDomainsRes = Vec<WorkerDomain>;
+
+impl CfReqMeta for ListWorkerDomains {
+    const METHOD: Method = Method::GET;
+    type Response = ListWorkerDomainsRes;
+}
+
+impl CfReqAuth for ListWorkerDomains {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/workers/domains")
+    }
+}
+
+/// [Detach from Domain](https://developers.cloudflare.com/api/operations/worker-domain-detach-from-domain) API
+#[derive(Serialize)]
+pub struct DetachWorkerDomain {
+    #[serde(skip)]
+    domain_id: String,
+}
+
+impl DetachWorkerDomain {
+    /// Stop serving a worker on a custom domain
+    /// domain_id is the [WorkerDomain]'s id
+    pub fn new(domain_id: impl Into<String>) -> Self {
+        Self {
+            domain_id: domain_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Detach from Domain](https://developers.cloudflare.com/api/operations/worker-domain-detach-from-domain#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DetachWorkerDomainRes;
+
+impl CfReqMeta for DetachWorkerDomain {
+    const METHOD: Method = Method::DELETE;
+    type Response = DetachWorkerDomainRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // cloudflare responds with an empty body
+        Ok(DetachWorkerDomainRes)
+    }
+}
+
+impl CfReqAuth for DetachWorkerDomain {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/workers/domains/{}", self.domain_id)
+    }
+}
+
+fn check_route_pattern(pattern: &str) -> Result<()> {
+    if pattern.trim().is_empty() {
+        return Err(Error::InvalidRequest(
+            "worker route pattern must not be empty".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// A route sending matching requests of a zone to a worker
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Route {
+    /// Identifier of the route
+    pub id: String,
+    /// URL pattern matched by the route (e.g `example.com/api/*`)
+    pub pattern: String,
+    /// Name of the worker, `None` to disable workers on matching requests
+    pub script: Option<String>,
+}
+
+/// [Create Route](https://developers.cloudflare.com/api/operations/worker-routes-create-route) API
+/// Send with [crate::CloudflareZone::send_zone]
+#[derive(Serialize)]
+pub struct CreateRoute {
+    pattern: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script: Option<String>,
+}
+
+impl CreateRoute {
+    /// Route requests matching `pattern` to the worker `script`
+    /// Fails if the pattern is empty
+    pub fn new(pattern: impl Into<String>, script: impl Into<String>) -> Result<Self> {
+        let pattern = pattern.into();
+        check_route_pattern(&pattern)?;
+        Ok(Self {
+            pattern,
+            script: Some(script.into()),
+        })
+    }
+
+    /// Disable workers on requests matching `pattern`
+    /// Fails if the pattern is empty
+    pub fn disabled(pattern: impl Into<String>) -> Result<Self> {
+        let pattern = pattern.into();
+        check_route_pattern(&pattern)?;
+        Ok(Self {
+            pattern,
+            script: None,
+        })
+    }
+}
+
+/// Success response from the [Create Route](https://developers.cloudflare.com/api/operations/worker-routes-create-route#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct CreateRouteRes {
+    /// Identifier of the route
+    pub id: String,
+}
+
+impl CfReqMeta for CreateRoute {
+    const METHOD: Method = Method::POST;
+    type Response = CreateRouteRes;
+}
+
+impl CfReqZone for CreateRoute {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/workers/routes")
+    }
+}
+
+/// [List Routes](https://developers.cloudflare.com/api/operations/worker-routes-list-routes) API
+/// Send with [crate::CloudflareZone::send_zone]
+#[derive(Serialize, Default)]
+pub struct ListRoutes {}
+
+/// Success response from the [List Routes](https://developers.cloudflare.com/api/operations/worker-routes-list-routes#Responses) API
+pub type ListRoutesRes = Vec<Route>;
+
+impl CfReqMeta for ListRoutes {
+    const METHOD: Method = Method::GET;
+    type Response = ListRoutesRes;
+}
+
+impl CfReqZone for ListRoutes {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/workers/routes")
+    }
+}
+
+/// [Update Route](https://developers.cloudflare.com/api/operations/worker-routes-update-route) API
+/// Send with [crate::CloudflareZone::send_zone]
+#[derive(Serialize)]
+pub struct UpdateRoute {
+    #[serde(skip)]
+    route_id: String,
+    pattern: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script: Option<String>,
+}
+
+impl UpdateRoute {
+    /// Replace the pattern and worker of a route
+    /// Fails if the pattern is empty
+    pub fn new(
+        route_id: impl Into<String>,
+        pattern: impl Into<String>,
+        script: Option<String>,
+    ) -> Result<Self> {
+        let pattern = pattern.into();
+        check_route_pattern(&pattern)?;
+        Ok(Self {
+            route_id: route_id.into(),
+            pattern,
+            script,
+        })
+    }
+}
+
+/// Success response from the [Update Route](https://developers.cloudflare.com/api/operations/worker-routes-update-route#Responses) API
+pub type UpdateRouteRes = Route;
+
+impl CfReqMeta for UpdateRoute {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateRouteRes;
+}
+
+impl CfReqZone for UpdateRoute {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/workers/routes/{}", self.route_id)
+    }
+}
+
+/// [Delete Route](https://developers.cloudflare.com/api/operations/worker-routes-delete-route) API
+/// Send with [crate::CloudflareZone::send_zone]
+#[derive(Serialize)]
+pub struct DeleteRoute {
+    #[serde(skip)]
+    route_id: String,
+}
+
+impl DeleteRoute {
+    /// Delete a route
+    pub fn new(route_id: impl Into<String>) -> Self {
+        Self {
+            route_id: route_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Route](https://developers.cloudflare.com/api/operations/worker-routes-delete-route#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteRouteRes {
+    /// Identifier of the deleted route
+    pub id: String,
+}
+
+impl CfReqMeta for DeleteRoute {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteRouteRes;
+}
+
+impl CfReqZone for DeleteRoute {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/workers/routes/{}", self.route_id)
+    }
+}
+
+/// A worker receiving the logs of another worker
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TailConsumer {
+    /// Name of the consuming worker
+    pub service: String,
+    /// Environment of the consuming worker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    /// Dispatch namespace of the consuming worker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+/// Settings of a worker script
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ScriptSettings {
+    /// Bindings exposed to the script
+    #[serde(default)]
+    pub bindings: Vec<Binding>,
+    /// Compatibility date of the script
+    pub compatibility_date: Option<String>,
+    /// Compatibility flags of the script
+    #[serde(default)]
+    pub compatibility_flags: Vec<String>,
+    /// Whether Logpush is enabled for the script
+    #[serde(default)]
+    pub logpush: bool,
+    /// Workers receiving the logs of the script
+    #[serde(default)]
+    pub tail_consumers: Vec<TailConsumer>,
+    /// Usage model of the script
+    pub usage_model: Option<String>,
+}
+
+/// [Get Script Settings](https://developers.cloudflare.com/api/operations/worker-script-get-settings) API
+#[derive(Serialize)]
+pub struct GetScriptSettings {
+    #[serde(skip)]
+    script_name: String,
+}
+
+impl GetScriptSettings {
+    /// Retrieve the settings of a script
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Script Settings](https://developers.cloudflare.com/api/operations/worker-script-get-settings#Responses) API
+pub type GetScriptSettingsRes = ScriptSettings;
+
+impl CfReqMeta for GetScriptSettings {
+    const METHOD: Method = Method::GET;
+    type Response = GetScriptSettingsRes;
+}
+
+impl CfReqAuth for GetScriptSettings {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/settings",
+            self.script_name
+        )
+    }
+}
+
+// only the fields that were set are sent, as missing fields are left untouched
+// while e.g an empty list of bindings removes all bindings
+#[derive(Serialize, Default)]
+struct ScriptSettingsPatch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bindings: Option<Vec<Binding>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compatibility_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compatibility_flags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    logpush: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tail_consumers: Option<Vec<TailConsumer>>,
+}
+
+/// [Patch Script Settings](https://developers.cloudflare.com/api/operations/worker-script-patch-settings) API
+/// Sent via [crate::CloudflareAuth::send_auth_multipart]
+pub struct PatchScriptSettings {
+    script_name: String,
+    settings: ScriptSettingsPatch,
+}
+
+impl PatchScriptSettings {
+    /// Update the settings of a script, only the fields set are changed
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+            settings: Default::default(),
+        }
+    }
+
+    /// Replace all bindings of the script, an empty list removes all bindings
+    pub fn bindings(mut self, bindings: Vec<Binding>) -> Self {
+        self.settings.bindings = Some(bindings);
+        self
+    }
+
+    /// Compatibility date of the script
+    pub fn compatibility_date(mut self, compatibility_date: impl Into<String>) -> Self {
+        self.settings.compatibility_date = Some(compatibility_date.into());
+        self
+    }
+
+    /// Replace the compatibility flags of the script
+    pub fn compatibility_flags(mut self, compatibility_flags: Vec<String>) -> Self {
+        self.settings.compatibility_flags = Some(compatibility_flags);
+        self
+    }
+
+    /// Enable or disable Logpush for the script
+    pub fn logpush(mut self, logpush: bool) -> Self {
+        self.settings.logpush = Some(logpush);
+        self
+    }
+
+    /// Replace the workers receiving the logs of the script
+    pub fn tail_consumers(mut self, tail_consumers: Vec<TailConsumer>) -> Self {
+        self.settings.tail_consumers = Some(tail_consumers);
+        self
+    }
+}
+
+impl TryFrom<PatchScriptSettings> for Form {
+    type Error = Error;
+
+    fn try_from(value: PatchScriptSettings) -> Result<Self> {
+        let settings =
+            Part::text(serde_json::to_string(&value.settings)?).mime_str("application/json")?;
+        Ok(Form::new().part("settings", settings))
+    }
+}
+
+/// Success response from the [Patch Script Settings](https://developers.cloudflare.com/api/operations/worker-script-patch-settings#Responses) API
+pub type PatchScriptSettingsRes = ScriptSettings;
+
+impl CfReqMeta for PatchScriptSettings {
+    const METHOD: Method = Method::PATCH;
+    type Response = PatchScriptSettingsRes;
+}
+
+impl CfReqAuth for PatchScriptSettings {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/settings",
+            self.script_name
+        )
+    }
+}
+
+/// Usage model of a worker script
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageModel {
+    /// Standard pricing
+    Standard,
+    /// Legacy request based pricing
+    Bundled,
+    /// Legacy duration based pricing
+    Unbound,
+}
+
+/// Usage model setting of a worker script
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ScriptUsageModel {
+    /// Usage model of the script
+    pub usage_model: UsageModel,
+}
+
+/// [Fetch Usage Model](https://developers.cloudflare.com/api/operations/worker-script-fetch-usage-model) API
+#[derive(Serialize)]
+pub struct GetUsageModel {
+    #[serde(skip)]
+    script_name: String,
+}
+
+impl GetUsageModel {
+    /// Retrieve the usage model of a script
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Fetch Usage Model](https://developers.cloudflare.com/api/operations/worker-script-fetch-usage-model#Responses) API
+pub type GetUsageModelRes = ScriptUsageModel;
+
+impl CfReqMeta for GetUsageModel {
+    const METHOD: Method = Method::GET;
+    type Response = GetUsageModelRes;
+}
+
+impl CfReqAuth for GetUsageModel {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/usage-model",
+            self.script_name
+        )
+    }
+}
+
+/// [Update Usage Model](https://developers.cloudflare.com/api/operations/worker-script-update-usage-model) API
+#[derive(Serialize)]
+pub struct UpdateUsageModel {
+    #[serde(skip)]
+    script_name: String,
+    usage_model: UsageModel,
+}
+
+impl UpdateUsageModel {
+    /// Change the usage model of a script
+    pub fn new(script_name: impl Into<String>, usage_model: UsageModel) -> Self {
+        Self {
+            script_name: script_name.into(),
+            usage_model,
+        }
+    }
+}
+
+/// Success response from the [Update Usage Model](https://developers.cloudflare.com/api/operations/worker-script-update-usage-model#Responses) API
+pub type UpdateUsageModelRes = ScriptUsageModel;
+
+impl CfReqMeta for UpdateUsageModel {
+    const METHOD: Method = Method::PUT;
+    type Response = UpdateUsageModelRes;
+}
+
+impl CfReqAuth for UpdateUsageModel {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/usage-model",
+            self.script_name
+        )
+    }
+}
+
+/// Share of traffic sent to a version in a [Deployment]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeploymentVersion {
+    /// Identifier of the version
+    pub version_id: String,
+    /// Percentage of requests served by the version
+    pub percentage: f64,
+}
+
+/// Annotations of a [Deployment]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct DeploymentAnnotations {
+    /// Human readable message describing the deployment
+    #[serde(rename = "workers/message", skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// What triggered the deployment, set by cloudflare
+    #[serde(rename = "workers/triggered_by", skip_serializing)]
+    pub triggered_by: Option<String>,
+}
+
+/// A deployment of a worker, splitting traffic between versions
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Deployment {
+    /// Identifier of the deployment
+    pub id: String,
+    /// Source of the deployment (e.g `api`, `wrangler`)
+    pub source: Option<String>,
+    /// Deployment strategy, `percentage`
+    pub strategy: String,
+    /// Email of the deployment's author
+    pub author_email: Option<String>,
+    /// RFC3339 timestamp of when the deployment was created
+    pub created_on: Option<String>,
+    /// Versions receiving traffic
+    pub versions: Vec<DeploymentVersion>,
+    /// Annotations of the deployment
+    pub annotations: Option<DeploymentAnnotations>,
+}
+
+/// [List Deployments](https://developers.cloudflare.com/api/operations/worker-deployments-list-deployments) API
+#[derive(Serialize)]
+pub struct ListDeployments {
+    #[serde(skip)]
+    script_name: String,
+}
+
+impl ListDeployments {
+    /// List the deployments of a script, the latest one being active
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+        }
+    }
+}
+
+/// Success response from the [List Deployments](https://developers.cloudflare.com/api/operations/worker-deployments-list-deployments#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListDeploymentsRes {
+    /// Deployments of the script
+    pub deployments: Vec<Deployment>,
+}
+
+impl CfReqMeta for ListDeployments {
+    const METHOD: Method = Method::GET;
+    type Response = ListDeploymentsRes;
+}
+
+impl CfReqAuth for ListDeployments {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/deployments",
+            self.script_name
+        )
+    }
+}
+
+/// [Create Deployment](https://developers.cloudflare.com/api/operations/worker-deployments-create-deployment) API
+#[derive(Serialize)]
+pub struct CreateDeployment {
+    #[serde(skip)]
+    script_name: String,
+    strategy: &'static str,
+    versions: Vec<DeploymentVersion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    annotations: Option<DeploymentAnnotations>,
+}
+
+impl CreateDeployment {
+    /// Deploy a script, splitting traffic between the given `(version_id, percentage)` pairs
+    /// Fails if a percentage is not within 0 to 100 or if they do not add up to 100
+    pub fn new(
+        script_name: impl Into<String>,
+        versions: impl IntoIterator<Item = (String, f64)>,
+    ) -> Result<Self> {
+        let versions: Vec<_> = versions
+            .into_iter()
+            .map(|(version_id, percentage)| DeploymentVersion {
+                version_id,
+                percentage,
+            })
+            .collect();
+        if let Some(version) = versions
+            .iter()
+            .find(|version| !(0.0..=100.0).contains(&version.percentage))
+        {
+            return Err(Error::InvalidRequest(format!(
+                "deployment percentage of {} must be within 0 and 100, got {}",
+                version.version_id, version.percentage
+            )));
+        }
+        let total: f64 = versions.iter().map(|version| version.percentage).sum();
+        if (total - 100.0).abs() > 1e-6 {
+            return Err(Error::InvalidRequest(format!(
+                "deployment percentages must add up to 100, got {total}"
+            )));
+        }
+        Ok(Self {
+            script_name: script_name.into(),
+            strategy: "percentage",
+            versions,
+            annotations: None,
+        })
+    }
+
+    /// Deploy a single version to all traffic
+    pub fn single(script_name: impl Into<String>, version_id: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+            strategy: "percentage",
+            versions: vec![DeploymentVersion {
+                version_id: version_id.into(),
+                percentage: 100.0,
+            }],
+            annotations: None,
+        }
+    }
+
+    /// Describe the deployment
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.annotations = Some(DeploymentAnnotations {
+            message: Some(message.into()),
+            triggered_by: None,
+        });
+        self
+    }
+}
+
+/// Success response from the [Create Deployment](https://developers.cloudflare.com/api/operations/worker-deployments-create-deployment#Responses) API
+pub type CreateDeploymentRes = Deployment;
+
+impl CfReqMeta for CreateDeployment {
+    const METHOD: Method = Method::POST;
+    type Response = CreateDeploymentRes;
+}
+
+impl CfReqAuth for CreateDeployment {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/deployments",
+            self.script_name
+        )
+    }
+}
+
+/// Annotations of a [Version]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct VersionAnnotations {
+    /// Human readable message describing the version
+    #[serde(rename = "workers/message")]
+    pub message: Option<String>,
+    /// User provided tag of the version
+    #[serde(rename = "workers/tag")]
+    pub tag: Option<String>,
+    /// What triggered the creation of the version
+    #[serde(rename = "workers/triggered_by")]
+    pub triggered_by: Option<String>,
+}
+
+/// Metadata of a [Version]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct VersionMetadata {
+    /// Email of the version's author
+    pub author_email: Option<String>,
+    /// Identifier of the version's author
+    pub author_id: Option<String>,
+    /// RFC3339 timestamp of when the version was created
+    pub created_on: Option<String>,
+    /// RFC3339 timestamp of when the version was last modified
+    pub modified_on: Option<String>,
+    /// Source of the version (e.g `api`, `wrangler`)
+    pub source: Option<String>,
+}
+
+/// A version of a worker script
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Version {
+    /// Identifier of the version
+    pub id: String,
+    /// Sequential number of the version
+    pub number: Option<u64>,
+    /// Metadata of the version
+    #[serde(default)]
+    pub metadata: VersionMetadata,
+    /// Annotations of the version
+    #[serde(default)]
+    pub annotations: VersionAnnotations,
+}
+
+/// [List Versions](https://developers.cloudflare.com/api/operations/worker-versions-list-versions) API
+#[derive(Serialize)]
+pub struct ListVersions {
+    #[serde(skip)]
+    script_name: String,
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl ListVersions {
+    /// List the versions of a script, newest first
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+            page: None,
+            per_page: None,
+        }
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of versions per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List Versions](https://developers.cloudflare.com/api/operations/worker-versions-list-versions#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListVersionsRes {
+    /// Versions in this page
+    pub items: Vec<Version>,
+}
+
+impl CfReqMeta for ListVersions {
+    const METHOD: Method = Method::GET;
+    type Response = ListVersionsRes;
+}
+
+impl CfReqAuth for ListVersions {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/versions",
+            self.script_name
+        )
+    }
+}
+
+/// [Get Version Detail](https://developers.cloudflare.com/api/operations/worker-versions-get-version-detail) API
+#[derive(Serialize)]
+pub struct GetVersion {
+    #[serde(skip)]
+    script_name: String,
+    #[serde(skip)]
+    version_id: String,
+}
+
+impl GetVersion {
+    /// Retrieve a version of a script
+    pub fn new(script_name: impl Into<String>, version_id: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+            version_id: version_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Get Version Detail](https://developers.cloudflare.com/api/operations/worker-versions-get-version-detail#Responses) API
+pub type GetVersionRes = Version;
+
+impl CfReqMeta for GetVersion {
+    const METHOD: Method = Method::GET;
+    type Response = GetVersionRes;
+}
+
+impl CfReqAuth for GetVersion {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/versions/{}",
+            self.script_name, self.version_id
+        )
+    }
+}
+
+/// A Durable Object namespace
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DurableObjectNamespace {
+    /// Identifier of the namespace
+    pub id: String,
+    /// Name of the namespace
+    pub name: String,
+    /// Name of the script defining the class
+    pub script: Option<String>,
+    /// Name of the Durable Object class
+    pub class: Option<String>,
+    /// Whether the objects use SQLite storage
+    #[serde(default)]
+    pub use_sqlite: bool,
+}
+
+/// [List Namespaces](https://developers.cloudflare.com/api/operations/durable-objects-namespace-list-namespaces) API
+#[derive(Serialize, Default)]
+pub struct ListDurableObjectNamespaces {}
+
+/// Success response from the [List Namespaces](https://developers.cloudflare.com/api/operations/durable-objects-namespace-list-namespaces#Responses) API
+pub type ListDurableObjectNamespacesRes = Vec<DurableObjectNamespace>;
+
+impl CfReqMeta for ListDurableObjectNamespaces {
+    const METHOD: Method = Method::GET;
+    type Response = ListDurableObjectNamespacesRes;
+}
+
+impl CfReqAuth for ListDurableObjectNamespaces {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/workers/durable_objects/namespaces")
+    }
+}
+
+/// A Durable Object
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DurableObject {
+    /// Identifier of the object
+    pub id: String,
+    /// Whether the object has stored data
+    #[serde(default)]
+    pub has_stored_data: bool,
+}
+
+/// [List Objects](https://developers.cloudflare.com/api/operations/durable-objects-namespace-list-objects) API
+/// Only objects with stored data are listed
+#[derive(Serialize)]
+pub struct ListDurableObjects {
+    #[serde(skip)]
+    namespace_id: String,
+    limit: Option<u32>,
+    cursor: Option<String>,
+}
+
+impl ListDurableObjects {
+    /// List the objects of a namespace
+    pub fn new(namespace_id: impl Into<String>) -> Self {
+        Self {
+            namespace_id: namespace_id.into(),
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    /// Maximum number of objects to return (10 - 10000, Cloudflare defaults to 1000)
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Continue listing from the cursor returned by a previous [ListDurableObjectsRes]
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+/// Success response from the [List Objects](https://developers.cloudflare.com/api/operations/durable-objects-namespace-list-objects#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListDurableObjectsRes {
+    /// Objects in this page
+    pub objects: Vec<DurableObject>,
+    /// Cursor for fetching the next page, `None` if this is the last page
+    pub cursor: Option<String>,
+}
+
+impl CfReqMeta for ListDurableObjects {
+    const METHOD: Method = Method::GET;
+    type Response = ListDurableObjectsRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (objects, info): (_, CursorInfo) = CfSuccessResWithInfo::from_body(&body)?;
+        let cursor = info.cursor.filter(|cursor| !cursor.is_empty());
+        Ok(ListDurableObjectsRes { objects, cursor })
+    }
+}
+
+impl CfReqAuth for ListDurableObjects {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/durable_objects/namespaces/{}/objects",
+            self.namespace_id
+        )
+    }
+}
+
+/// A live log session of a worker
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Tail {
+    /// Identifier of the tail
+    pub id: String,
+    /// Websocket URL streaming the logs
+    pub url: String,
+    /// RFC3339 timestamp of when the tail expires
+    pub expires_at: String,
+}
+
+/// [Start Tail](https://developers.cloudflare.com/api/operations/worker-tail-logs-start-tail) API
+#[derive(Serialize)]
+pub struct CreateTail {
+    #[serde(skip)]
+    script_name: String,
+}
+
+impl CreateTail {
+    /// Start a live log session for a script
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+        }
+    }
+}
+
+/// Success response from the [Start Tail](https://developers.cloudflare.com/api/operations/worker-tail-logs-start-tail#Responses) API
+pub type CreateTailRes = Tail;
+
+impl CfReqMeta for CreateTail {
+    const METHOD: Method = Method::POST;
+    type Response = CreateTailRes;
+}
+
+impl CfReqAuth for CreateTail {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/tails",
+            self.script_name
+        )
+    }
+}
+
+/// [List Tails](https://developers.cloudflare.com/api/operations/worker-tail-logs-list-tails) API
+#[derive(Serialize)]
+pub struct ListTails {
+    #[serde(skip)]
+    script_name: String,
+}
+
+impl ListTails {
+    /// List the live log sessions of a script
+    pub fn new(script_name: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+        }
+    }
+}
+
+/// Success response from the [List Tails](https://developers.cloudflare.com/api/operations/worker-tail-logs-list-tails#Responses) API
+pub type ListTailsRes = Vec<Tail>;
+
+impl CfReqMeta for ListTails {
+    const METHOD: Method = Method::GET;
+    type Response = ListTailsRes;
+}
+
+impl CfReqAuth for ListTails {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/tails",
+            self.script_name
+        )
+    }
+}
+
+/// [Delete Tail](https://developers.cloudflare.com/api/operations/worker-tail-logs-delete-tail) API
+#[derive(Serialize)]
+pub struct DeleteTail {
+    #[serde(skip)]
+    script_name: String,
+    #[serde(skip)]
+    tail_id: String,
+}
+
+impl DeleteTail {
+    /// End a live log session of a script
+    pub fn new(script_name: impl Into<String>, tail_id: impl Into<String>) -> Self {
+        Self {
+            script_name: script_name.into(),
+            tail_id: tail_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Tail](https://developers.cloudflare.com/api/operations/worker-tail-logs-delete-tail#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteTailRes;
+
+impl CfReqMeta for DeleteTail {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteTailRes;
+
+    fn deserialize_response(_body: Bytes) -> Result<Self::Response> {
+        // the result is null
+        Ok(DeleteTailRes)
+    }
+}
+
+impl CfReqAuth for DeleteTail {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!(
+            "accounts/{account_id}/workers/scripts/{}/tails/{}",
+            self.script_name, self.tail_id
+        )
+    }
+}
+
+/// A `console` call made by a worker
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TailLog {
+    /// Arguments of the call
+    pub message: Vec<serde_json::Value>,
+    /// Log level (e.g `log`, `warn`, `error`)
+    pub level: String,
+    /// Unix timestamp in milliseconds
+    pub timestamp: u64,
+}
+
+/// An uncaught exception thrown by a worker
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TailException {
+    /// Name of the exception (e.g `Error`)
+    pub name: String,
+    /// Message of the exception
+    pub message: String,
+    /// Unix timestamp in milliseconds
+    pub timestamp: u64,
+}
+
+/// An invocation of a worker streamed by a [Tail]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TailEvent {
+    /// Outcome of the invocation (e.g `ok`, `exception`, `exceededCpu`)
+    pub outcome: String,
+    /// Name of the invoked script
+    pub script_name: Option<String>,
+    /// Uncaught exceptions
+    #[serde(default)]
+    pub exceptions: Vec<TailException>,
+    /// `console` calls
+    #[serde(default)]
+    pub logs: Vec<TailLog>,
+    /// Unix timestamp in milliseconds of when the invocation started
+    pub event_timestamp: Option<u64>,
+    /// What triggered the invocation (e.g the request or scheduled event)
+    pub event: Option<serde_json::Value>,
+}
+
+/// Connect to the websocket `url` of a [Tail] and stream its events
+/// The session ends when the returned stream is dropped
+#[cfg(feature = "workers-tail")]
+pub async fn connect_tail(
+    url: &str,
+) -> Result<impl futures_util::Stream<Item = Result<TailEvent>>> {
+    use futures_util::StreamExt;
+    use tokio_tungstenite::tungstenite::{client::IntoClientRequest, http::HeaderValue, Message};
+
+    let mut request = url.into_client_request()?;
+    request.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        HeaderValue::from_static("trace-v1"),
+    );
+    let (socket, _) = tokio_tungstenite::connect_async(request).await?;
+    Ok(socket.filter_map(|message| async move {
+        match message {
+            Ok(Message::Text(text)) => Some(serde_json::from_str(&text).map_err(Error::from)),
+            Ok(Message::Binary(data)) => Some(serde_json::from_slice(&data).map_err(Error::from)),
+            Ok(_) => None,
+            Err(err) => Some(Err(err.into())),
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patch_settings_only_sends_set_fields() -> Result<()> {
+        let req = PatchScriptSettings::new("script").logpush(true);
+        assert_eq!(serde_json::to_string(&req.settings)?, r#"{"logpush":true}"#);
+
+        let req = PatchScriptSettings::new("script").bindings(Vec::new());
+        assert_eq!(serde_json::to_string(&req.settings)?, r#"{"bindings":[]}"#);
+        Ok(())
+    }
+
+    #[test]
+    fn deployment_percentages_are_validated() {
+        let split = |percentages: &[f64]| {
+            CreateDeployment::new(
+                "script",
+                percentages
+                    .iter()
+                    .enumerate()
+                    .map(|(i, percentage)| (format!("v{i}"), *percentage)),
+            )
+        };
+        assert!(split(&[100.0]).is_ok());
+        assert!(split(&[90.0, 10.0]).is_ok());
+        assert!(split(&[0.0, 100.0]).is_ok());
+        assert!(split(&[90.0, 5.0]).is_err());
+        assert!(split(&[150.0, -50.0]).is_err());
+        assert!(split(&[f64::NAN]).is_err());
+        assert!(split(&[f64::NAN, 100.0]).is_err());
+        assert!(split(&[f64::INFINITY, f64::NEG_INFINITY]).is_err());
+    }
+}
@@ -0,0 +1,295 @@
+//! Cloudflare Workers AI related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/workers-ai/)
+use std::marker::PhantomData;
+
+use crate::{CfReqAuth, CfReqMeta, Error, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Streaming responses are not supported yet
+fn check_not_streaming(input: &impl Serialize) -> Result<()> {
+    let input = serde_json::to_value(input)?;
+    if input.get("stream") == Some(&serde_json::Value::Bool(true)) {
+        return Err(Error::InvalidRequest(
+            "streaming model responses are not supported".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Author of a [ChatMessage]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatRole {
+    /// Instructions for the model
+    System,
+    /// Message of the user
+    User,
+    /// Previous response of the model
+    Assistant,
+}
+
+/// A message of a text generation conversation
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ChatMessage {
+    /// Author of the message
+    pub role: ChatRole,
+    /// Content of the message
+    pub content: String,
+}
+
+impl ChatMessage {
+    /// A system message
+    pub fn system(content: impl Into<String>) -> Self {
+        Self {
+            role: ChatRole::System,
+            content: content.into(),
+        }
+    }
+
+    /// A user message
+    pub fn user(content: impl Into<String>) -> Self {
+        Self {
+            role: ChatRole::User,
+            content: content.into(),
+        }
+    }
+
+    /// An assistant message
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self {
+            role: ChatRole::Assistant,
+            content: content.into(),
+        }
+    }
+}
+
+/// Input of text generation models
+#[derive(Serialize, Clone, Debug)]
+pub struct TextGenerationInput {
+    messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+}
+
+impl TextGenerationInput {
+    /// Generate the next message of the conversation
+    pub fn new(messages: Vec<ChatMessage>) -> Self {
+        Self {
+            messages,
+            max_tokens: None,
+            temperature: None,
+        }
+    }
+
+    /// Maximum number of tokens to generate
+    pub fn max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Sampling temperature, higher is more random
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+}
+
+/// Output of text generation models
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TextGenerationOutput {
+    /// Generated text
+    #[serde(default)]
+    pub response: String,
+}
+
+/// Input of text embedding models
+#[derive(Serialize, Clone, Debug)]
+pub struct EmbeddingsInput {
+    text: Vec<String>,
+}
+
+/// Output of text embedding models
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EmbeddingsOutput {
+    /// Shape of [Self::data]
+    #[serde(default)]
+    pub shape: Vec<usize>,
+    /// One embedding per input text
+    pub data: Vec<Vec<f32>>,
+}
+
+/// Input of text to image models
+#[derive(Serialize, Clone, Debug)]
+pub struct ImageGenerationInput {
+    prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    negative_prompt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_steps: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+}
+
+impl ImageGenerationInput {
+    /// Generate an image described by `prompt`
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            negative_prompt: None,
+            num_steps: None,
+            width: None,
+            height: None,
+            seed: None,
+        }
+    }
+
+    /// Elements to avoid in the image
+    pub fn negative_prompt(mut self, negative_prompt: impl Into<String>) -> Self {
+        self.negative_prompt = Some(negative_prompt.into());
+        self
+    }
+
+    /// Number of diffusion steps
+    pub fn num_steps(mut self, num_steps: u32) -> Self {
+        self.num_steps = Some(num_steps);
+        self
+    }
+
+    /// Size of the image in pixels
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    /// Seed for reproducible results
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+/// [Execute AI model](https://developers.cloudflare.com/api/operations/workers-ai-post-run-model) API
+/// The input `I` and output `O` are specific to the model
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct RunModel<I, O = serde_json::Value> {
+    #[serde(skip)]
+    model: String,
+    input: I,
+    #[serde(skip)]
+    _output: PhantomData<fn() -> O>,
+}
+
+impl<I: Serialize, O> RunModel<I, O> {
+    /// Run the model `model` (e.g `@cf/meta/llama-3.1-8b-instruct`) on `input`
+    /// Fails if the input requests a streaming response
+    pub fn new(model: impl Into<String>, input: I) -> Result<Self> {
+        check_not_streaming(&input)?;
+        Ok(Self {
+            model: model.into(),
+            input,
+            _output: PhantomData,
+        })
+    }
+}
+
+impl RunModel<TextGenerationInput, TextGenerationOutput> {
+    /// Generate the next message of a conversation with a text generation model
+    pub fn text_generation(model: impl Into<String>, input: TextGenerationInput) -> Self {
+        Self {
+            model: model.into(),
+            input,
+            _output: PhantomData,
+        }
+    }
+}
+
+impl RunModel<EmbeddingsInput, EmbeddingsOutput> {
+    /// Compute the embeddings of `text` with a text embedding model
+    pub fn embeddings<T: Into<String>>(
+        model: impl Into<String>,
+        text: impl IntoIterator<Item = T>,
+    ) -> Self {
+        Self {
+            model: model.into(),
+            input: EmbeddingsInput {
+                text: text.into_iter().map(Into::into).collect(),
+            },
+            _output: PhantomData,
+        }
+    }
+}
+
+impl<I: Serialize + Send, O: DeserializeOwned> CfReqMeta for RunModel<I, O> {
+    const METHOD: Method = Method::POST;
+    type Response = O;
+}
+
+impl<I: Serialize + Send, O: DeserializeOwned> CfReqAuth for RunModel<I, O> {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/ai/run/{}", self.model)
+    }
+}
+
+/// [Execute AI model](https://developers.cloudflare.com/api/operations/workers-ai-post-run-model) API
+/// for models responding with raw bytes (e.g text to image models)
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct RunModelRaw<I> {
+    #[serde(skip)]
+    model: String,
+    input: I,
+}
+
+impl<I: Serialize> RunModelRaw<I> {
+    /// Run the model `model` on `input`
+    /// Fails if the input requests a streaming response
+    pub fn new(model: impl Into<String>, input: I) -> Result<Self> {
+        check_not_streaming(&input)?;
+        Ok(Self {
+            model: model.into(),
+            input,
+        })
+    }
+}
+
+impl RunModelRaw<ImageGenerationInput> {
+    /// Generate an image with a text to image model (e.g `@cf/lykon/dreamshaper-8-lcm`)
+    pub fn image_generation(model: impl Into<String>, input: ImageGenerationInput) -> Self {
+        Self {
+            model: model.into(),
+            input,
+        }
+    }
+}
+
+/// Raw output of the model (e.g a PNG image)
+pub type RunModelRawRes = Bytes;
+
+impl<I: Serialize + Send> CfReqMeta for RunModelRaw<I> {
+    const METHOD: Method = Method::POST;
+    type Response = RunModelRawRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        Ok(body)
+    }
+}
+
+impl<I: Serialize + Send> CfReqAuth for RunModelRaw<I> {
+    type Url = String;
+
+    fn path(&self, account_id: &str) -> String {
+        format!("accounts/{account_id}/ai/run/{}", self.model)
+    }
+}
@@ -0,0 +1,577 @@
+//! Cloudflare Zones related APIs
+//! See [Cloudflare Docs](https://developers.cloudflare.com/fundamentals/setup/accounts-and-zones/)
+use crate::{
+    api::r2::MinTls, CfReqAuth, CfReqMeta, CfReqZone, CfSuccessResWithInfo, CloudflareAuth,
+    PageInfo, Result,
+};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Find the zone named `name` (e.g `example.com`) among the zones accessible to the client
+pub async fn find_zone_by_name(client: &CloudflareAuth, name: &str) -> Result<Option<Zone>> {
+    let res = client.send_auth(ListZones::default().name(name)).await?;
+    Ok(res.zones.into_iter().find(|zone| zone.name == name))
+}
+
+/// Status of a zone
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ZoneStatus {
+    /// Zone is being set up
+    Initializing,
+    /// Waiting for the nameservers to be updated
+    Pending,
+    /// Zone is served by cloudflare
+    Active,
+    /// Nameservers were moved away from cloudflare
+    Moved,
+    /// A status unknown to this library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Type of a zone
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ZoneType {
+    /// DNS is hosted by cloudflare
+    Full,
+    /// CNAME setup, DNS is hosted elsewhere
+    Partial,
+    /// DNS is transferred from a primary DNS provider
+    Secondary,
+}
+
+/// Plan of a zone
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ZonePlan {
+    /// Plan identifier
+    pub id: String,
+    /// Name of the plan (e.g `Free Website`)
+    pub name: String,
+}
+
+/// Account owning a zone
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ZoneAccount {
+    /// Account ID
+    pub id: String,
+    /// Name of the account
+    pub name: Option<String>,
+}
+
+/// A zone
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Zone {
+    /// Zone ID
+    pub id: String,
+    /// Domain name of the zone
+    pub name: String,
+    /// Status of the zone
+    pub status: ZoneStatus,
+    /// Type of the zone
+    #[serde(rename = "type")]
+    pub zone_type: Option<ZoneType>,
+    /// Whether cloudflare's proxy is paused for the zone
+    #[serde(default)]
+    pub paused: bool,
+    /// Cloudflare nameservers assigned to the zone
+    #[serde(default)]
+    pub name_servers: Vec<String>,
+    /// Nameservers of the zone before it was moved to cloudflare
+    pub original_name_servers: Option<Vec<String>>,
+    /// Registrar of the domain before it was moved to cloudflare
+    pub original_registrar: Option<String>,
+    /// Plan of the zone
+    pub plan: Option<ZonePlan>,
+    /// Account owning the zone
+    pub account: Option<ZoneAccount>,
+    /// RFC3339 timestamp of when the zone was created
+    pub created_on: Option<String>,
+    /// RFC3339 timestamp of when the zone was last modified
+    pub modified_on: Option<String>,
+    /// RFC3339 timestamp of when the zone was activated
+    pub activated_on: Option<String>,
+}
+
+/// [List Zones](https://developers.cloudflare.com/api/operations/zones-get) API
+/// The client's account is not used, see [Self::account] to filter by account
+#[derive(Serialize, Default)]
+pub struct ListZones {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<ZoneStatus>,
+    #[serde(rename = "account.id", skip_serializing_if = "Option::is_none")]
+    account_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u32>,
+}
+
+impl ListZones {
+    /// Only list the zone with the given domain name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Only list zones with the given status
+    pub fn status(mut self, status: ZoneStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Only list zones of the given account
+    pub fn account(mut self, account_id: impl Into<String>) -> Self {
+        self.account_id = Some(account_id.into());
+        self
+    }
+
+    /// Page number, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of zones per page
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// Success response from the [List Zones](https://developers.cloudflare.com/api/operations/zones-get#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ListZonesRes {
+    /// Zones in this page
+    pub zones: Vec<Zone>,
+    /// Pagination info
+    pub page_info: PageInfo,
+}
+
+impl CfReqMeta for ListZones {
+    const METHOD: Method = Method::GET;
+    type Response = ListZonesRes;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        let (zones, page_info) = CfSuccessResWithInfo::from_body(&body)?;
+        Ok(ListZonesRes { zones, page_info })
+    }
+}
+
+impl CfReqAuth for ListZones {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "zones".to_string()
+    }
+}
+
+/// [Zone Details](https://developers.cloudflare.com/api/operations/zones-0-get) API
+/// The client's account is not used
+#[derive(Serialize)]
+pub struct GetZone {
+    #[serde(skip)]
+    zone_id: String,
+}
+
+impl GetZone {
+    /// Retrieve the zone with the given id
+    pub fn new(zone_id: impl Into<String>) -> Self {
+        Self {
+            zone_id: zone_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Zone Details](https://developers.cloudflare.com/api/operations/zones-0-get#Responses) API
+pub type GetZoneRes = Zone;
+
+impl CfReqMeta for GetZone {
+    const METHOD: Method = Method::GET;
+    type Response = GetZoneRes;
+}
+
+impl CfReqAuth for GetZone {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        format!("zones/{}", self.zone_id)
+    }
+}
+
+#[derive(Serialize)]
+struct AccountRef {
+    id: String,
+}
+
+/// [Create Zone](https://developers.cloudflare.com/api/operations/zones-post) API
+#[derive(Serialize)]
+pub struct CreateZone {
+    name: String,
+    account: AccountRef,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    zone_type: Option<ZoneType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jump_start: Option<bool>,
+}
+
+impl CreateZone {
+    /// Add the domain `name` as a zone of the account `account_id`
+    pub fn new(name: impl Into<String>, account_id: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            account: AccountRef {
+                id: account_id.into(),
+            },
+            zone_type: None,
+            jump_start: None,
+        }
+    }
+
+    /// Type of the zone, defaults to [ZoneType::Full]
+    pub fn zone_type(mut self, zone_type: ZoneType) -> Self {
+        self.zone_type = Some(zone_type);
+        self
+    }
+
+    /// Scan for existing DNS records of the domain and import them
+    pub fn jump_start(mut self, jump_start: bool) -> Self {
+        self.jump_start = Some(jump_start);
+        self
+    }
+}
+
+/// Success response from the [Create Zone](https://developers.cloudflare.com/api/operations/zones-post#Responses) API
+/// [Zone::name_servers] must be set at the registrar to activate the zone
+pub type CreateZoneRes = Zone;
+
+impl CfReqMeta for CreateZone {
+    const METHOD: Method = Method::POST;
+    type Response = CreateZoneRes;
+}
+
+impl CfReqAuth for CreateZone {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        "zones".to_string()
+    }
+}
+
+/// [Delete Zone](https://developers.cloudflare.com/api/operations/zones-0-delete) API
+/// The client's account is not used
+#[derive(Serialize)]
+pub struct DeleteZone {
+    #[serde(skip)]
+    zone_id: String,
+}
+
+impl DeleteZone {
+    /// Delete the zone with the given id
+    pub fn new(zone_id: impl Into<String>) -> Self {
+        Self {
+            zone_id: zone_id.into(),
+        }
+    }
+}
+
+/// Success response from the [Delete Zone](https://developers.cloudflare.com/api/operations/zones-0-delete#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct DeleteZoneRes {
+    /// Identifier of the deleted zone
+    pub id: String,
+}
+
+impl CfReqMeta for DeleteZone {
+    const METHOD: Method = Method::DELETE;
+    type Response = DeleteZoneRes;
+}
+
+impl CfReqAuth for DeleteZone {
+    type Url = String;
+
+    fn path(&self, _account_id: &str) -> String {
+        format!("zones/{}", self.zone_id)
+    }
+}
+
+/// [Rerun the Activation Check](https://developers.cloudflare.com/api/operations/put-zones-zone_id-activation_check) API
+/// Send with [crate::CloudflareZone::send_zone]
+#[derive(Serialize, Default)]
+pub struct ActivationCheck {}
+
+/// Success response from the [Rerun the Activation Check](https://developers.cloudflare.com/api/operations/put-zones-zone_id-activation_check#Responses) API
+#[derive(Serialize, Deserialize)]
+pub struct ActivationCheckRes {
+    /// Identifier of the zone
+    pub id: String,
+}
+
+impl CfReqMeta for ActivationCheck {
+    const METHOD: Method = Method::PUT;
+    type Response = ActivationCheckRes;
+}
+
+impl CfReqZone for ActivationCheck {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/activation_check")
+    }
+}
+
+/// A zone setting
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum ZoneSetting {
+    /// SSL mode, see [SslMode]
+    Ssl,
+    /// Redirect HTTP requests to HTTPS, `on`/`off`
+    AlwaysUseHttps,
+    /// Minimum TLS version, see [MinTls]
+    MinTlsVersion,
+    /// Security level, see [SecurityLevel]
+    SecurityLevel,
+    /// Cache level, see [CacheLevel]
+    CacheLevel,
+    /// Browser cache TTL in seconds, 0 to respect the origin headers
+    BrowserCacheTtl,
+    /// Rewrite HTTP links to HTTPS, `on`/`off`
+    AutomaticHttpsRewrites,
+    /// Bypass the cache temporarily, `on`/`off`
+    DevelopmentMode,
+    /// A setting unknown to this library
+    Other(String),
+}
+
+impl ZoneSetting {
+    /// The setting id as used by cloudflare
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Ssl => "ssl",
+            Self::AlwaysUseHttps => "always_use_https",
+            Self::MinTlsVersion => "min_tls_version",
+            Self::SecurityLevel => "security_level",
+            Self::CacheLevel => "cache_level",
+            Self::BrowserCacheTtl => "browser_cache_ttl",
+            Self::AutomaticHttpsRewrites => "automatic_https_rewrites",
+            Self::DevelopmentMode => "development_mode",
+            Self::Other(setting) => setting,
+        }
+    }
+}
+
+impl From<String> for ZoneSetting {
+    fn from(setting: String) -> Self {
+        match setting.as_str() {
+            "ssl" => Self::Ssl,
+            "always_use_https" => Self::AlwaysUseHttps,
+            "min_tls_version" => Self::MinTlsVersion,
+            "security_level" => Self::SecurityLevel,
+            "cache_level" => Self::CacheLevel,
+            "browser_cache_ttl" => Self::BrowserCacheTtl,
+            "automatic_https_rewrites" => Self::AutomaticHttpsRewrites,
+            "development_mode" => Self::DevelopmentMode,
+            _ => Self::Other(setting),
+        }
+    }
+}
+
+impl From<ZoneSetting> for String {
+    fn from(setting: ZoneSetting) -> Self {
+        match setting {
+            ZoneSetting::Other(setting) => setting,
+            setting => setting.as_str().to_string(),
+        }
+    }
+}
+
+/// Value of the [ZoneSetting::Ssl] setting
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SslMode {
+    /// No encryption
+    Off,
+    /// Encrypt traffic between visitors and cloudflare only
+    Flexible,
+    /// Encrypt traffic to the origin without validating its certificate
+    Full,
+    /// Encrypt traffic to the origin and validate its certificate
+    Strict,
+}
+
+/// Value of the [ZoneSetting::SecurityLevel] setting
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityLevel {
+    /// Challenge no visitors
+    Off,
+    /// Challenge only the most threatening visitors
+    EssentiallyOff,
+    /// Challenge only threatening visitors
+    Low,
+    /// Challenge moderately threatening visitors
+    Medium,
+    /// Challenge all visitors that exhibited threatening behavior
+    High,
+    /// Challenge all visitors
+    UnderAttack,
+}
+
+/// Value of the [ZoneSetting::CacheLevel] setting
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheLevel {
+    /// Cache every version of a resource regardless of the query string
+    Aggressive,
+    /// Cache only resources without a query string
+    Basic,
+    /// Cache the same resource regardless of the query string
+    Simplified,
+}
+
+/// A zone setting and its value
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ZoneSettingEntry {
+    /// The setting
+    pub id: ZoneSetting,
+    /// Value of the setting, its type depends on the setting
+    pub value: serde_json::Value,
+    /// Whether the setting can be changed
+    #[serde(default)]
+    pub editable: bool,
+    /// RFC3339 timestamp of when the setting was last changed
+    pub modified_on: Option<String>,
+}
+
+impl ZoneSettingEntry {
+    /// Deserialize the value (e.g as [SslMode] for [ZoneSetting::Ssl])
+    pub fn value_as<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(T::deserialize(&self.value)?)
+    }
+
+    /// Whether an `on`/`off` setting is on, `None` if the value is not `on`/`off`
+    pub fn is_on(&self) -> Option<bool> {
+        match self.value.as_str()? {
+            "on" => Some(true),
+            "off" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// [Get Zone Setting](https://developers.cloudflare.com/api/operations/zone-settings-get-single-setting) API
+#[derive(Serialize)]
+pub struct GetZoneSetting {
+    #[serde(skip)]
+    setting: ZoneSetting,
+}
+
+impl GetZoneSetting {
+    /// Retrieve the given setting
+    pub fn new(setting: ZoneSetting) -> Self {
+        Self { setting }
+    }
+}
+
+/// Success response from the [Get Zone Setting](https://developers.cloudflare.com/api/operations/zone-settings-get-single-setting#Responses) API
+pub type GetZoneSettingRes = ZoneSettingEntry;
+
+impl CfReqMeta for GetZoneSetting {
+    const METHOD: Method = Method::GET;
+    type Response = GetZoneSettingRes;
+}
+
+impl CfReqZone for GetZoneSetting {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/settings/{}", self.setting.as_str())
+    }
+}
+
+/// [Edit Zone Setting](https://developers.cloudflare.com/api/operations/zone-settings-edit-single-setting) API
+#[derive(Serialize)]
+pub struct PatchZoneSetting {
+    #[serde(skip)]
+    setting: ZoneSetting,
+    value: serde_json::Value,
+}
+
+impl PatchZoneSetting {
+    /// Set the given setting to `value`
+    pub fn new(setting: ZoneSetting, value: serde_json::Value) -> Self {
+        Self { setting, value }
+    }
+
+    /// Turn an `on`/`off` setting on or off
+    pub fn on_off(setting: ZoneSetting, on: bool) -> Self {
+        Self::new(setting, if on { "on" } else { "off" }.into())
+    }
+
+    /// Set the SSL mode
+    pub fn ssl(mode: SslMode) -> Self {
+        Self::new(ZoneSetting::Ssl, serde_json::json!(mode))
+    }
+
+    /// Set the minimum TLS version
+    pub fn min_tls_version(version: MinTls) -> Self {
+        Self::new(ZoneSetting::MinTlsVersion, serde_json::json!(version))
+    }
+
+    /// Set the security level
+    pub fn security_level(level: SecurityLevel) -> Self {
+        Self::new(ZoneSetting::SecurityLevel, serde_json::json!(level))
+    }
+
+    /// Set the cache level
+    pub fn cache_level(level: CacheLevel) -> Self {
+        Self::new(ZoneSetting::CacheLevel, serde_json::json!(level))
+    }
+
+    /// Set the browser cache TTL in seconds, 0 to respect the origin headers
+    pub fn browser_cache_ttl(ttl: u32) -> Self {
+        Self::new(ZoneSetting::BrowserCacheTtl, ttl.into())
+    }
+}
+
+/// Success response from the [Edit Zone Setting](https://developers.cloudflare.com/api/operations/zone-settings-edit-single-setting#Responses) API
+pub type PatchZoneSettingRes = ZoneSettingEntry;
+
+impl CfReqMeta for PatchZoneSetting {
+    const METHOD: Method = Method::PATCH;
+    type Response = PatchZoneSettingRes;
+}
+
+impl CfReqZone for PatchZoneSetting {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/settings/{}", self.setting.as_str())
+    }
+}
+
+/// [Get All Zone Settings](https://developers.cloudflare.com/api/operations/zone-settings-get-all-zone-settings) API
+#[derive(Serialize, Default)]
+pub struct ListZoneSettings {}
+
+/// Success response from the [Get All Zone Settings](https://developers.cloudflare.com/api/operations/zone-settings-get-all-zone-settings#Responses) API
+pub type ListZoneSettingsRes = Vec<ZoneSettingEntry>;
+
+impl CfReqMeta for ListZoneSettings {
+    const METHOD: Method = Method::GET;
+    type Response = ListZoneSettingsRes;
+}
+
+impl CfReqZone for ListZoneSettings {
+    type Url = String;
+
+    fn path(&self, zone_id: &str) -> String {
+        format!("zones/{zone_id}/settings")
+    }
+}
@@ -2,3 +2,7 @@
 
 /// Cloudflare API base URL
 pub const CF_BASE_URL: &str = "https://api.cloudflare.com/client/v4/";
+
+/// Turnstile siteverify URL, requests to it do not go through [CF_BASE_URL]
+pub const TURNSTILE_SITEVERIFY_URL: &str =
+    "https://challenges.cloudflare.com/turnstile/v0/siteverify";
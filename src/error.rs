@@ -1,6 +1,6 @@
-use std::str::Utf8Error;
+use std::{convert::Infallible, str::Utf8Error};
 
-use super::CfApiErr;
+use super::{CfApiErr, GraphqlError};
 use thiserror::Error;
 
 /// Error type for the Cloudflare API client
@@ -15,9 +15,93 @@ pub enum Error {
     Json(#[from] serde_json::Error),
     #[error("error(s) from cloudflare: {0:?}")]
     Cloudflare(Vec<CfApiErr>),
+    #[error("not found: {0:?}")]
+    NotFound(Vec<CfApiErr>),
+    #[error("invalid value for kv key {key}: {source}")]
+    KvValue {
+        key: String,
+        source: serde_json::Error,
+    },
+    #[error("error(s) from graphql: {0:?}")]
+    Graphql(Vec<GraphqlError>),
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[cfg(feature = "stream-signing")]
+    #[error("jwt error: {0}")]
+    Jwt(#[from] jsonwebtoken::errors::Error),
+    #[cfg(feature = "stream-wait")]
+    #[error("video {uid} failed to encode: {error_reason_code:?} {error_reason_text:?}")]
+    VideoFailed {
+        uid: String,
+        error_reason_code: Option<String>,
+        error_reason_text: Option<String>,
+    },
+    #[cfg(feature = "d1-backup")]
+    #[error("d1 {operation} failed: {message}")]
+    D1Task {
+        operation: &'static str,
+        message: String,
+    },
+    #[cfg(feature = "poll")]
+    #[error("timed out after {0:?}")]
+    Timeout(std::time::Duration),
+    #[cfg(feature = "stream-tus")]
+    #[error("tus upload failed: {0}")]
+    Tus(String),
+    #[cfg(any(feature = "stream-signing", feature = "stream-tus"))]
+    #[error("invalid base64: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[cfg(feature = "stream-webhooks")]
+    #[error("invalid webhook signature: {0}")]
+    WebhookSignature(&'static str),
+    #[cfg(feature = "workers-tail")]
+    #[error("websocket error: {0}")]
+    WebSocket(Box<tokio_tungstenite::tungstenite::Error>),
+    #[cfg(feature = "tunnel-secret")]
+    #[error("random generation failed: {0}")]
+    Random(#[from] getrandom::Error),
     #[error("invalid utf8 string: {0}")]
     Utf8(#[from] Utf8Error),
 }
 
+// boxed as tungstenite's error is much larger than the other variants
+#[cfg(feature = "workers-tail")]
+impl From<tokio_tungstenite::tungstenite::Error> for Error {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::WebSocket(Box::new(err))
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+impl Error {
+    /// Whether the error is a 404 from cloudflare,
+    /// useful for treating missing resources as success (e.g when deleting)
+    /// Only requests documenting it return [Error::NotFound] (e.g [crate::api::kv::DeleteKV]),
+    /// wrap other requests in [crate::request::Optional] instead
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Error::NotFound(_))
+    }
+
+    /// Errors returned by cloudflare, empty if the error did not originate from cloudflare
+    pub fn cf_errors(&self) -> &[CfApiErr] {
+        match self {
+            Error::Cloudflare(errors) | Error::NotFound(errors) => errors,
+            _ => &[],
+        }
+    }
+
+    /// Whether cloudflare returned an error with the given code
+    pub fn has_cf_code(&self, code: u32) -> bool {
+        self.cf_errors().iter().any(|err| err.code == code)
+    }
+}
+
 /// Result type for the Cloudflare API client
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -10,8 +10,8 @@ use std::sync::Arc;
 
 use consts::CF_BASE_URL;
 pub use error::*;
-use request::{CfReq, CfReqAuth, CfReqMeta};
-use reqwest::{multipart::Form, IntoUrl, Method, RequestBuilder, Url};
+use request::{check_path, CfReq, CfReqAuth, CfReqMeta, CfReqZone, RawBody};
+use reqwest::{header::CONTENT_TYPE, multipart::Form, IntoUrl, Method, RequestBuilder, Url};
 use serde::Serialize;
 pub use types::*;
 
@@ -51,6 +51,12 @@ impl Cloudflare {
         }
     }
 
+    fn with_headers(reqb: RequestBuilder, req: &impl CfReqMeta) -> RequestBuilder {
+        req.headers()
+            .into_iter()
+            .fold(reqb, |reqb, (name, value)| reqb.header(name, value))
+    }
+
     fn req_builder(
         &self,
         method: Method,
@@ -70,22 +76,28 @@ impl Cloudflare {
         req: Req,
         reqb: RequestBuilder,
     ) -> Result<Req::Response> {
+        let reqb = Self::with_headers(reqb, &req);
         let reqb = if Req::METHOD == Method::GET {
             reqb.query(&req)
         } else {
-            reqb.json(&req)
+            reqb.query(&req.query()).json(&req)
         };
-        self.send_inner::<Req>(reqb).await
+        self.send_inner::<Req>(reqb, move |e| req.deserialize_err(e))
+            .await
     }
 
-    async fn send_inner<Req: CfReqMeta>(&self, reqb: RequestBuilder) -> Result<Req::Response> {
+    async fn send_inner<Req: CfReqMeta>(
+        &self,
+        reqb: RequestBuilder,
+        map_err: impl FnOnce(Error) -> Error,
+    ) -> Result<Req::Response> {
         let resp = reqb.send().await?;
         let status = resp.status();
+        let body = resp.bytes().await?;
         if !status.is_success() {
-            let err: CfErrRes = resp.json().await?;
-            return Err(Error::Cloudflare(err.errors));
+            return Req::handle_error_body(status, body);
         }
-        Req::deserialize_response(resp.bytes().await?)
+        Req::deserialize_response(body).map_err(map_err)
     }
 
     /// Send a request to the Cloudflare API.
@@ -93,6 +105,15 @@ impl Cloudflare {
         let reqb = self.req_builder(Req::METHOD, self.base_url.join(Req::PATH)?, None);
         self.send_json(req, reqb).await
     }
+
+    /// Send a form encoded (`application/x-www-form-urlencoded`) request to the Cloudflare API.
+    pub async fn send_form<Req: CfReq + Serialize>(&self, req: Req) -> Result<Req::Response> {
+        let reqb = self.req_builder(Req::METHOD, self.base_url.join(Req::PATH)?, None);
+        let reqb = Self::with_headers(reqb, &req);
+        let reqb = reqb.query(&req.query()).form(&req);
+        self.send_inner::<Req>(reqb, move |e| req.deserialize_err(e))
+            .await
+    }
 }
 
 /// Client for accessing the Cloudflare API
@@ -123,8 +144,22 @@ impl CloudflareAuth {
         self.inner.send(req).await
     }
 
+    /// Send an unauthenticated form encoded request to the Cloudflare API.
+    pub async fn send_form<Req: CfReq + Serialize>(&self, req: Req) -> Result<Req::Response> {
+        self.inner.send_form(req).await
+    }
+
+    /// Create a client for zone scoped requests of the zone `zone_id`
+    pub fn zone(&self, zone_id: impl Into<String>) -> CloudflareZone {
+        CloudflareZone {
+            inner: self.clone(),
+            zone_id: zone_id.into().into(),
+        }
+    }
+
     fn build_url(&self, req: &impl CfReqAuth) -> Result<Url> {
         let path = req.path(&self.creds.account_id);
+        check_path(path.as_ref())?;
         let url = self.inner.base_url.join(path.as_ref())?;
         Ok(url)
     }
@@ -137,13 +172,87 @@ impl CloudflareAuth {
     }
 
     /// Send an authenticated multipart request to the Cloudflare API.
-    pub async fn send_auth_multipart<Req: CfReqAuth + Into<Form>>(
-        &self,
-        req: Req,
-    ) -> Result<Req::Response> {
+    pub async fn send_auth_multipart<Req>(&self, req: Req) -> Result<Req::Response>
+    where
+        Req: CfReqAuth + TryInto<Form>,
+        Error: From<Req::Error>,
+    {
         let url = self.build_url(&req)?;
         let reqb = self.inner.req_builder(Req::METHOD, url, Some(&self.creds));
-        let reqb = reqb.multipart(req.into());
-        self.inner.send_inner::<Req>(reqb).await
+        let reqb = Cloudflare::with_headers(reqb, &req);
+        let reqb = reqb.query(&req.query()).multipart(req.try_into()?);
+        self.inner.send_inner::<Req>(reqb, |e| e).await
+    }
+
+    /// Send an authenticated request with a raw body (e.g NDJSON) to the Cloudflare API.
+    pub async fn send_auth_raw<Req>(&self, req: Req) -> Result<Req::Response>
+    where
+        Req: CfReqAuth + TryInto<RawBody>,
+        Error: From<Req::Error>,
+    {
+        let url = self.build_url(&req)?;
+        let reqb = self.inner.req_builder(Req::METHOD, url, Some(&self.creds));
+        let reqb = Cloudflare::with_headers(reqb, &req);
+        let query = req.query();
+        let body = req.try_into()?;
+        let reqb = reqb
+            .query(&query)
+            .header(CONTENT_TYPE, body.content_type)
+            .body(body.body);
+        self.inner.send_inner::<Req>(reqb, |e| e).await
+    }
+}
+
+/// Client for accessing the zone scoped Cloudflare API
+/// with authentication.
+/// Use [CloudflareAuth::zone] to create one
+#[derive(Clone, Debug)]
+pub struct CloudflareZone {
+    inner: CloudflareAuth,
+    zone_id: Arc<str>,
+}
+
+impl CloudflareZone {
+    /// Create a new client for the zone `zone_id`
+    pub fn new(client: CloudflareAuth, zone_id: impl Into<String>) -> Self {
+        client.zone(zone_id)
+    }
+
+    /// Zone ID
+    pub fn zone_id(&self) -> &str {
+        &self.zone_id
+    }
+
+    /// Underlying authenticated client, for requests that are not zone scoped
+    pub fn auth(&self) -> &CloudflareAuth {
+        &self.inner
+    }
+
+    fn zone_req_builder(&self, req: &impl CfReqZone, method: Method) -> Result<RequestBuilder> {
+        let path = req.path(&self.zone_id);
+        check_path(path.as_ref())?;
+        let url = self.inner.inner.base_url.join(path.as_ref())?;
+        Ok(self
+            .inner
+            .inner
+            .req_builder(method, url, Some(&self.inner.creds)))
+    }
+
+    /// Send a zone scoped request to the Cloudflare API.
+    pub async fn send_zone<Req: CfReqZone + Serialize>(&self, req: Req) -> Result<Req::Response> {
+        let reqb = self.zone_req_builder(&req, Req::METHOD)?;
+        self.inner.inner.send_json(req, reqb).await
+    }
+
+    /// Send a zone scoped multipart request to the Cloudflare API.
+    pub async fn send_zone_multipart<Req>(&self, req: Req) -> Result<Req::Response>
+    where
+        Req: CfReqZone + TryInto<Form>,
+        Error: From<Req::Error>,
+    {
+        let reqb = self.zone_req_builder(&req, Req::METHOD)?;
+        let reqb = Cloudflare::with_headers(reqb, &req);
+        let reqb = reqb.query(&req.query()).multipart(req.try_into()?);
+        self.inner.inner.send_inner::<Req>(reqb, |e| e).await
     }
 }
@@ -1,10 +1,14 @@
 //! Request traits for Cloudflare API requests
-//! each request must implement [CfReqMeta] and one of [CfReq] or [CfReqAuth]
+//! each request must implement [CfReqMeta] and one of [CfReq], [CfReqAuth] or [CfReqZone]
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use bytes::Bytes;
-use reqwest::Method;
-use serde::de::DeserializeOwned;
+use percent_encoding::{utf8_percent_encode, AsciiSet, PercentEncode, NON_ALPHANUMERIC};
+use reqwest::multipart::Part;
+use reqwest::{Method, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
 
-use crate::Result;
+use crate::{CfApiErr, CfErrRes, Error, Result};
 
 /// Metadata for a Cloudflare API JSON request
 pub trait CfReqMeta: Sized + Send {
@@ -20,6 +24,37 @@ pub trait CfReqMeta: Sized + Send {
         let res: crate::CfSuccessRes<Self::Response> = serde_json::from_slice(&body)?;
         Ok(res.result)
     }
+
+    /// Handle an error response from the API
+    /// `errors` is empty if the response body could not be parsed.
+    /// The default implementation returns [crate::Error::Cloudflare]
+    fn handle_error(_status: StatusCode, errors: Vec<CfApiErr>) -> Result<Self::Response> {
+        Err(Error::Cloudflare(errors))
+    }
+
+    /// Handle an error response from the API given its raw body,
+    /// for APIs with an error format other than cloudflare's (e.g GraphQL)
+    /// The default implementation parses the cloudflare errors and calls [Self::handle_error]
+    fn handle_error_body(status: StatusCode, body: Bytes) -> Result<Self::Response> {
+        Self::handle_error(status, cf_errors(&body))
+    }
+
+    /// Attach request specific context to an error returned by [Self::deserialize_response]
+    /// Not called for multipart requests, as they are consumed when building the form
+    fn deserialize_err(&self, err: Error) -> Error {
+        err
+    }
+
+    /// Query parameters for requests that are not serialized as the query string
+    /// (i.e multipart and non GET requests)
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// Additional headers for the request (e.g alternative credentials)
+    fn headers(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
 }
 
 /// A Cloudflare API request that does not require authentication
@@ -29,6 +64,8 @@ pub trait CfReq: CfReqMeta {
 }
 
 /// A Cloudflare API request that requires authentication
+/// Requests that are authenticated but not account scoped (e.g `user` or `accounts`)
+/// implement this trait too and ignore the account ID in [Self::path]
 pub trait CfReqAuth: CfReqMeta {
     /// Url kind (usually [String])
     type Url: AsRef<str>;
@@ -36,3 +73,170 @@ pub trait CfReqAuth: CfReqMeta {
     /// Path for the request relative to the base URL(i.e [crate::consts::CF_BASE_URL])
     fn path(&self, account_id: &str) -> Self::Url;
 }
+
+/// A Cloudflare API request scoped to a zone, requires authentication
+pub trait CfReqZone: CfReqMeta {
+    /// Url kind (usually [String])
+    type Url: AsRef<str>;
+
+    /// Path for the request relative to the base URL(i.e [crate::consts::CF_BASE_URL])
+    fn path(&self, zone_id: &str) -> Self::Url;
+}
+
+/// Body of a request that is neither JSON nor multipart encoded
+/// See [crate::CloudflareAuth::send_auth_raw]
+#[derive(Debug, Clone)]
+pub struct RawBody {
+    pub(crate) content_type: &'static str,
+    pub(crate) body: Bytes,
+}
+
+impl RawBody {
+    /// Create a body with the given content type
+    pub fn new(content_type: &'static str, body: impl Into<Bytes>) -> Self {
+        Self {
+            content_type,
+            body: body.into(),
+        }
+    }
+}
+
+/// Cloudflare errors of an error response, empty if the body could not be parsed
+pub(crate) fn cf_errors(body: &[u8]) -> Vec<CfApiErr> {
+    // error bodies are not guaranteed to be JSON (e.g some 404s)
+    serde_json::from_slice::<CfErrRes>(body)
+        .map(|err| err.errors)
+        .unwrap_or_default()
+}
+
+/// [CfReqMeta::handle_error] for requests that report 404s as [crate::Error::NotFound]
+pub(crate) fn not_found_error<T>(status: StatusCode, errors: Vec<CfApiErr>) -> Result<T> {
+    if status == StatusCode::NOT_FOUND {
+        Err(Error::NotFound(errors))
+    } else {
+        Err(Error::Cloudflare(errors))
+    }
+}
+
+/// Wrapper around a request that treats a 404 response as `None`
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct Optional<Req>(pub Req);
+
+impl<Req: CfReqMeta> CfReqMeta for Optional<Req> {
+    const METHOD: Method = Req::METHOD;
+    type Response = Option<Req::Response>;
+
+    fn deserialize_response(body: Bytes) -> Result<Self::Response> {
+        Req::deserialize_response(body).map(Some)
+    }
+
+    fn handle_error(status: StatusCode, errors: Vec<CfApiErr>) -> Result<Self::Response> {
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Req::handle_error(status, errors).map(Some)
+    }
+
+    fn handle_error_body(status: StatusCode, body: Bytes) -> Result<Self::Response> {
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Req::handle_error_body(status, body).map(Some)
+    }
+
+    fn deserialize_err(&self, err: Error) -> Error {
+        self.0.deserialize_err(err)
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        self.0.query()
+    }
+
+    fn headers(&self) -> Vec<(&'static str, String)> {
+        self.0.headers()
+    }
+}
+
+impl<Req: CfReq> CfReq for Optional<Req> {
+    const PATH: &'static str = Req::PATH;
+}
+
+impl<Req: CfReqAuth> CfReqAuth for Optional<Req> {
+    type Url = Req::Url;
+
+    fn path(&self, account_id: &str) -> Self::Url {
+        self.0.path(account_id)
+    }
+}
+
+impl<Req: CfReqZone> CfReqZone for Optional<Req> {
+    type Url = Req::Url;
+
+    fn path(&self, zone_id: &str) -> Self::Url {
+        self.0.path(zone_id)
+    }
+}
+
+/// Characters that are percent encoded in a path segment (everything except RFC 3986 unreserved)
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Percent encode a value so that it can be interpolated into a path as a single segment
+/// `.` and `..` stay dot segments even when encoded, they are rejected by [check_path]
+pub(crate) fn encode_segment(segment: &str) -> PercentEncode<'_> {
+    utf8_percent_encode(segment, PATH_SEGMENT)
+}
+
+/// Reject paths with `.` or `..` segments (e.g a KV key named `..`),
+/// [url::Url::join] would resolve them and send the request to another resource
+pub(crate) fn check_path(path: &str) -> Result<()> {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    if path
+        .split('/')
+        .any(|segment| segment == "." || segment == "..")
+    {
+        return Err(Error::InvalidRequest(format!(
+            "`.` and `..` are not valid path segments: {path}"
+        )));
+    }
+    Ok(())
+}
+
+/// Format a time as an RFC3339 UTC timestamp with second precision,
+/// times before the unix epoch are clamped to the epoch
+pub(crate) fn rfc3339(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+    // civil from days (http://howardhinnant.github.io/date_algorithms.html#civil_from_days)
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// Build a multipart file part, guessing the mime type from the file name
+pub(crate) fn file_part(contents: Bytes, file_name: String) -> Result<Part> {
+    let mime = mime_guess::from_path(&file_name).first_or_octet_stream();
+    let len = contents.len() as u64;
+    Ok(Part::stream_with_length(contents, len)
+        .file_name(file_name)
+        .mime_str(mime.as_ref())?)
+}
@@ -1,12 +1,55 @@
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::Result;
 
 /// Cloudflare API error response
 #[derive(Deserialize, Debug)]
 pub struct CfApiErr {
     /// Error code
-    pub code: u16,
+    pub code: u32,
+    /// Error message
+    pub message: String,
+}
+
+/// Error returned by the GraphQL Analytics API
+#[derive(Deserialize, Debug)]
+pub struct GraphqlError {
     /// Error message
     pub message: String,
+    /// Path of the field the error relates to
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+    /// Additional details (e.g the error code)
+    pub extensions: Option<serde_json::Value>,
+}
+
+/// A secret value (e.g a newly created API token), redacted from [Debug] output
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// The secret value
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Take the secret value
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl std::fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("\"<redacted>\"")
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
 }
 
 #[derive(Deserialize)]
@@ -18,3 +61,116 @@ pub(crate) struct CfSuccessRes<T> {
 pub(crate) struct CfErrRes {
     pub errors: Vec<CfApiErr>,
 }
+
+#[derive(Deserialize)]
+pub(crate) struct CfSuccessResWithInfo<T, I> {
+    pub result: T,
+    pub result_info: Option<I>,
+}
+
+impl<T: DeserializeOwned, I: DeserializeOwned + Default> CfSuccessResWithInfo<T, I> {
+    /// Deserialize the `result` along with the `result_info`
+    pub fn from_body(body: &[u8]) -> Result<(T, I)> {
+        let res: Self = serde_json::from_slice(body)?;
+        Ok((res.result, res.result_info.unwrap_or_default()))
+    }
+}
+
+/// Pagination info returned by page based list APIs
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct PageInfo {
+    /// Current page number
+    pub page: u32,
+    /// Number of results per page
+    pub per_page: u32,
+    /// Number of results in the current page
+    pub count: u32,
+    /// Total number of results
+    pub total_count: u32,
+}
+
+#[derive(Deserialize, Default)]
+pub(crate) struct CursorInfo {
+    pub cursor: Option<String>,
+}
+
+/// Options for helpers polling until a long running operation completes
+#[cfg(feature = "poll")]
+#[derive(Clone, Copy, Debug)]
+pub struct PollOptions {
+    /// Delay before the first re-poll, doubled after every poll
+    pub interval: std::time::Duration,
+    /// Upper bound for the delay between polls
+    pub max_interval: std::time::Duration,
+    /// Give up after this much time has passed
+    pub timeout: std::time::Duration,
+}
+
+#[cfg(feature = "poll")]
+impl Default for PollOptions {
+    fn default() -> Self {
+        use std::time::Duration;
+
+        Self {
+            interval: Duration::from_secs(2),
+            max_interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(30 * 60),
+        }
+    }
+}
+
+/// Exponential backoff between polls, bounded by [PollOptions::timeout]
+#[cfg(any(feature = "d1-backup", feature = "stream-wait"))]
+pub(crate) struct Backoff {
+    options: PollOptions,
+    /// `None` if the timeout is too large to be represented (i.e no timeout)
+    deadline: Option<tokio::time::Instant>,
+    interval: std::time::Duration,
+}
+
+#[cfg(any(feature = "d1-backup", feature = "stream-wait"))]
+impl Backoff {
+    pub fn new(options: PollOptions) -> Self {
+        Self {
+            options,
+            deadline: tokio::time::Instant::now().checked_add(options.timeout),
+            interval: options.interval,
+        }
+    }
+
+    /// Sleep until the next poll, failing with [crate::Error::Timeout] once the deadline passed
+    pub async fn wait(&mut self) -> Result<()> {
+        let mut delay = self.interval;
+        if let Some(deadline) = self.deadline {
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(crate::Error::Timeout(self.options.timeout));
+            }
+            delay = delay.min(deadline - now);
+        }
+        tokio::time::sleep(delay).await;
+        self.interval = self
+            .interval
+            .saturating_mul(2)
+            .min(self.options.max_interval);
+        Ok(())
+    }
+}
+
+#[cfg(all(test, any(feature = "d1-backup", feature = "stream-wait")))]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn backoff_without_timeout() {
+        let backoff = Backoff::new(PollOptions {
+            interval: Duration::MAX,
+            max_interval: Duration::MAX,
+            timeout: Duration::MAX,
+        });
+        assert!(backoff.deadline.is_none());
+    }
+}